@@ -1,5 +1,6 @@
-use std::fmt;
+use std::fmt::{self, Write};
 
+use itertools::Itertools;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -191,6 +192,31 @@ impl Buffer {
         self.set_stringn(x, y, string, usize::MAX, style);
     }
 
+    /// Print a string with a shadow, starting at the position (x, y)
+    ///
+    /// A copy of the string is printed one cell to the right and one cell below with the
+    /// `shadow_style` before printing the string itself, which makes text more legible when drawn
+    /// over a busy background such as a heatmap. The shadow style is usually a darker foreground
+    /// color than the background. Parts of the shadow that fall outside the buffer are skipped.
+    pub fn set_string_with_shadow<T, S, SS>(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: T,
+        style: S,
+        shadow_style: SS,
+    ) where
+        T: AsRef<str>,
+        S: Into<Style>,
+        SS: Into<Style>,
+    {
+        let string = string.as_ref();
+        if y.saturating_add(1) < self.area.bottom() {
+            self.set_string(x.saturating_add(1), y + 1, string, shadow_style);
+        }
+        self.set_string(x, y, string, style);
+    }
+
     /// Print at most the first n characters of a string if enough space is available
     /// until the end of the line.
     ///
@@ -271,6 +297,47 @@ impl Buffer {
         }
     }
 
+    /// Fill the given area by repeating a pattern of symbols, and set the style of its cells.
+    ///
+    /// Each string of `pattern` is a row of the pattern and each grapheme of a row fills one cell.
+    /// The rows are repeated horizontally and the pattern is repeated vertically, starting from
+    /// the top left corner of the area, so that it tiles the whole area. Empty rows leave the
+    /// symbols of their cells unchanged. The area is clipped to the buffer.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+    /// buf.fill_pattern(buf.area, &["╲ ", " ╲"], Style::new().dark_gray());
+    /// assert_eq!(
+    ///     buf,
+    ///     Buffer::with_lines(["╲ ╲ ╲".dark_gray(), " ╲ ╲ ".dark_gray()])
+    /// );
+    /// ```
+    pub fn fill_pattern<S: Into<Style>>(&mut self, area: Rect, pattern: &[&str], style: S) {
+        let style = style.into();
+        let clipped = self.area.intersection(area);
+        if pattern.is_empty() {
+            return self.set_style(clipped, style);
+        }
+        for y in clipped.top()..clipped.bottom() {
+            let row = pattern[usize::from(y - area.y) % pattern.len()];
+            let symbols = row.graphemes(true).collect_vec();
+            for x in clipped.left()..clipped.right() {
+                let cell = self.get_mut(x, y);
+                if !symbols.is_empty() {
+                    cell.set_symbol(symbols[usize::from(x - area.x) % symbols.len()]);
+                }
+                cell.set_style(style);
+            }
+        }
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -319,12 +386,130 @@ impl Buffer {
         self.area = area;
     }
 
+    /// Copy the cells of an other buffer on top of this one, skipping its empty cells
+    ///
+    /// The top left corner of `other` is placed at `position` in this buffer, and the cells that
+    /// end up outside of the area of this buffer are clipped. The empty cells of `other` (a space
+    /// with the default style, see [`Cell::EMPTY`]) are skipped so that the content of this buffer
+    /// shows through. This is useful to render a popup or an overlay to its own buffer and then
+    /// composite it on top of the main buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::with_lines(["abcd", "efgh"]);
+    /// let popup = Buffer::with_lines(["X ", "YZ"]);
+    /// buf.overlay(&popup, Position::new(1, 0));
+    /// assert_eq!(buf, Buffer::with_lines(["aXcd", "eYZh"]));
+    /// ```
+    pub fn overlay(&mut self, other: &Self, position: Position) {
+        for source in other.area.positions() {
+            let cell = other.get(source.x, source.y);
+            if *cell == Cell::EMPTY {
+                continue;
+            }
+            let x = u32::from(position.x) + u32::from(source.x - other.area.x);
+            let y = u32::from(position.y) + u32::from(source.y - other.area.y);
+            let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) else {
+                continue;
+            };
+            if self.area.contains(Position::new(x, y)) {
+                *self.get_mut(x, y) = cell.clone();
+            }
+        }
+    }
+
+    /// Returns an independent buffer with a copy of the cells in the given area
+    ///
+    /// The area is first clipped to the area of this buffer, and the returned buffer covers the
+    /// clipped area, keeping its coordinates. A widget can then be rendered to the returned
+    /// buffer without affecting this one, and the result inspected or written back with
+    /// [`Buffer::blit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let buf = Buffer::with_lines(["abc", "def"]);
+    /// let sub = buf.sub_buffer(Rect::new(1, 1, 5, 5));
+    /// assert_eq!(sub.area, Rect::new(1, 1, 2, 1));
+    /// assert_eq!(sub.get(1, 1).symbol(), "e");
+    /// ```
+    #[must_use]
+    pub fn sub_buffer(&self, area: Rect) -> Self {
+        let area = self.area.intersection(area);
+        let content = area
+            .positions()
+            .map(|position| self.get(position.x, position.y).clone())
+            .collect();
+        Self { area, content }
+    }
+
+    /// Copy the cells of an other buffer to the given area of this buffer
+    ///
+    /// The top left corner of `other` is placed at the top left corner of `area`, and its cells
+    /// are clipped to `area` and to the area of this buffer. Unlike [`Buffer::overlay`], all the
+    /// cells are copied, including the empty ones. This is the counterpart of
+    /// [`Buffer::sub_buffer`]: blitting a sub-buffer to its own area writes it back unchanged,
+    /// while blitting it to a smaller area shows only a part of it, like a viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::with_lines(["abc", "def"]);
+    /// let mut sub = buf.sub_buffer(Rect::new(1, 0, 2, 2));
+    /// sub.set_string(1, 0, "XY", Style::new());
+    /// buf.blit(&sub, sub.area);
+    /// assert_eq!(buf, Buffer::with_lines(["aXY", "def"]));
+    /// ```
+    pub fn blit(&mut self, other: &Self, area: Rect) {
+        let target = Rect {
+            width: area.width.min(other.area.width),
+            height: area.height.min(other.area.height),
+            ..area
+        };
+        let target = self.area.intersection(target);
+        for position in target.positions() {
+            let source_x = other.area.x + (position.x - area.x);
+            let source_y = other.area.y + (position.y - area.y);
+            *self.get_mut(position.x, position.y) = other.get(source_x, source_y).clone();
+        }
+    }
+
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
     /// self to other.
     ///
+    /// Each update is the `x` and `y` position of a cell and the cell of `other` to draw there, in
+    /// row-major order. A cell is updated when it differs from the cell of `self` at the same
+    /// position, including when only its style changed. Cells hidden by a preceding multi-width
+    /// symbol of `other` and cells marked with [`Cell::skip`] are never returned. This is what the
+    /// [`Terminal`](crate::Terminal) sends to the [`Backend`](crate::backend::Backend) on each
+    /// draw, and can be used to implement a custom renderer.
+    ///
+    /// Both buffers are expected to have the same area. The cells are compared in the order of
+    /// their index, and the positions are computed from the area of `self`. When the areas differ,
+    /// only as many cells as the smaller buffer contains are compared, so the result is only
+    /// meaningful if the buffers have the same size. Resize `self` to the area of `other` first
+    /// to get a full redraw of the new area.
+    ///
     /// We're assuming that buffers are well-formed, that is no double-width cell is followed by
     /// a non-blank cell.
     ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{buffer::Cell, prelude::*};
+    ///
+    /// let previous = Buffer::with_lines(["abc"]);
+    /// let next = Buffer::with_lines(["aXc"]);
+    /// assert_eq!(previous.diff(&next), [(1, 0, &Cell::new("X"))]);
+    /// ```
+    ///
     /// # Multi-width characters handling:
     ///
     /// ```text
@@ -370,6 +555,108 @@ impl Buffer {
         }
         updates
     }
+
+    /// Returns the content of the buffer as a string with ANSI escape sequences for the styles
+    ///
+    /// This is useful to print a rendered buffer to a terminal, e.g. for logging or to compare
+    /// renders in golden files. Each row of the buffer is written on its own line. An SGR sequence
+    /// is only written when the style changes from one cell to the next, and the style is reset at
+    /// the end of each line so that lines can be printed independently. The cells hidden by
+    /// multi-width symbols are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+    /// buf.set_string(0, 0, "ab", Style::new().red().bold());
+    /// assert_eq!(buf.to_ansi_string(), "\x1b[0;1;31mab\x1b[0m  ");
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        let mut output = String::new();
+        let reset = Cell::EMPTY.style();
+        for (y, line) in self
+            .content
+            .chunks(self.area.width.max(1) as usize)
+            .enumerate()
+        {
+            if y > 0 {
+                output.push('\n');
+            }
+            let mut current = reset;
+            let mut skip: usize = 0;
+            for cell in line {
+                if skip == 0 {
+                    let style = cell.style();
+                    if style != current {
+                        write_sgr(&mut output, cell);
+                        current = style;
+                    }
+                    output.push_str(cell.symbol());
+                }
+                skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+            if current != reset {
+                output.push_str("\x1b[0m");
+            }
+        }
+        output
+    }
+
+    /// Returns the content of the buffer as an HTML `<pre>` block
+    ///
+    /// This is useful to embed snapshots of a rendered buffer in web pages or documentation. Each
+    /// row of the buffer is written on its own line, and each run of cells with the same style is
+    /// wrapped in a `<span>` with an inline CSS style for its colors and modifiers. Cells with the
+    /// default style are written without a `<span>`. Named and indexed colors are converted to the
+    /// colors of the default xterm palette. The cells hidden by multi-width symbols are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+    /// buf.set_string(0, 0, "Hi", Style::new().red().bold());
+    /// assert_eq!(
+    ///     buf.to_html(),
+    ///     r#"<pre><span style="color:#800000;font-weight:bold">Hi</span>  </pre>"#
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut output = String::from("<pre>");
+        for (y, line) in self
+            .content
+            .chunks(self.area.width.max(1) as usize)
+            .enumerate()
+        {
+            if y > 0 {
+                output.push('\n');
+            }
+            let mut skip: usize = 0;
+            let cells = line.iter().filter(|cell| {
+                let is_hidden = skip > 0;
+                skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+                !is_hidden
+            });
+            for (_, run) in &cells.chunk_by(|cell| cell.style()) {
+                let run = run.collect_vec();
+                let css = css_style(run[0]);
+                if !css.is_empty() {
+                    write!(output, "<span style=\"{css}\">").unwrap();
+                }
+                for cell in run {
+                    write_html_escaped(&mut output, cell.symbol());
+                }
+                if !css.is_empty() {
+                    output.push_str("</span>");
+                }
+            }
+        }
+        output.push_str("</pre>");
+        output
+    }
 }
 
 impl fmt::Debug for Buffer {
@@ -444,6 +731,185 @@ impl fmt::Debug for Buffer {
     }
 }
 
+/// Writes an SGR escape sequence that resets the style and then sets the style of the cell
+fn write_sgr(output: &mut String, cell: &Cell) {
+    const MODIFIERS: [(Modifier, u8); 9] = [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ];
+    output.push_str("\x1b[0");
+    for (modifier, code) in MODIFIERS {
+        if cell.modifier.contains(modifier) {
+            write!(output, ";{code}").unwrap();
+        }
+    }
+    write_sgr_color(output, cell.fg, 30, 90, 38);
+    write_sgr_color(output, cell.bg, 40, 100, 48);
+    #[cfg(feature = "underline-color")]
+    write_sgr_underline_color(output, cell.underline_color);
+    output.push('m');
+}
+
+/// Writes the SGR parameters of a color
+///
+/// `normal` and `bright` are the base codes of the 8 normal and 8 bright named colors and
+/// `extended` is the code used for indexed and rgb colors.
+fn write_sgr_color(output: &mut String, color: Color, normal: u8, bright: u8, extended: u8) {
+    match color {
+        Color::Reset => {}
+        Color::Rgb(r, g, b) => write!(output, ";{extended};2;{r};{g};{b}").unwrap(),
+        Color::Indexed(i) => write!(output, ";{extended};5;{i}").unwrap(),
+        named => {
+            let index = ansi_color_index(named);
+            if index < 8 {
+                write!(output, ";{}", normal + index).unwrap();
+            } else {
+                write!(output, ";{}", bright + index - 8).unwrap();
+            }
+        }
+    }
+}
+
+/// Writes the SGR parameters of an underline color
+///
+/// There are no codes for named underline colors, so they are written as indexed colors.
+#[cfg(feature = "underline-color")]
+fn write_sgr_underline_color(output: &mut String, color: Color) {
+    match color {
+        Color::Reset => {}
+        Color::Rgb(r, g, b) => write!(output, ";58;2;{r};{g};{b}").unwrap(),
+        Color::Indexed(i) => write!(output, ";58;5;{i}").unwrap(),
+        named => write!(output, ";58;5;{}", ansi_color_index(named)).unwrap(),
+    }
+}
+
+/// The inline CSS style of a cell, or an empty string for the default style
+fn css_style(cell: &Cell) -> String {
+    let (mut fg, mut bg) = (css_color(cell.fg), css_color(cell.bg));
+    if cell.modifier.contains(Modifier::REVERSED) {
+        (fg, bg) = (
+            bg.or_else(|| Some("Canvas".to_string())),
+            fg.or_else(|| Some("CanvasText".to_string())),
+        );
+    }
+    let mut properties = vec![];
+    if let Some(fg) = fg {
+        properties.push(format!("color:{fg}"));
+    }
+    if let Some(bg) = bg {
+        properties.push(format!("background:{bg}"));
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        properties.push("font-weight:bold".to_string());
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        properties.push("opacity:0.5".to_string());
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        properties.push("font-style:italic".to_string());
+    }
+    let decorations = [
+        (Modifier::UNDERLINED, "underline"),
+        (Modifier::CROSSED_OUT, "line-through"),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| cell.modifier.contains(*modifier))
+    .map(|(_, decoration)| decoration)
+    .join(" ");
+    if !decorations.is_empty() {
+        properties.push(format!("text-decoration:{decorations}"));
+    }
+    if cell.modifier.contains(Modifier::HIDDEN) {
+        properties.push("visibility:hidden".to_string());
+    }
+    properties.join(";")
+}
+
+/// The CSS hex color of a color, or `None` for [`Color::Reset`]
+fn css_color(color: Color) -> Option<String> {
+    /// The colors of the 16 named colors in the default xterm palette
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0x00, 0x00, 0x00),
+        (0x80, 0x00, 0x00),
+        (0x00, 0x80, 0x00),
+        (0x80, 0x80, 0x00),
+        (0x00, 0x00, 0x80),
+        (0x80, 0x00, 0x80),
+        (0x00, 0x80, 0x80),
+        (0xc0, 0xc0, 0xc0),
+        (0x80, 0x80, 0x80),
+        (0xff, 0x00, 0x00),
+        (0x00, 0xff, 0x00),
+        (0xff, 0xff, 0x00),
+        (0x00, 0x00, 0xff),
+        (0xff, 0x00, 0xff),
+        (0x00, 0xff, 0xff),
+        (0xff, 0xff, 0xff),
+    ];
+    /// The levels of each channel in the 6x6x6 color cube of the indexed colors
+    const CUBE_LEVELS: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i @ 0..=15) => PALETTE[i as usize],
+        Color::Indexed(i @ 16..=231) => {
+            let i = (i - 16) as usize;
+            (
+                CUBE_LEVELS[i / 36],
+                CUBE_LEVELS[i / 6 % 6],
+                CUBE_LEVELS[i % 6],
+            )
+        }
+        Color::Indexed(i) => {
+            let level = 8 + (i - 232) * 10;
+            (level, level, level)
+        }
+        named => PALETTE[ansi_color_index(named) as usize],
+    };
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+/// Writes the symbol with the characters that have a special meaning in HTML escaped
+fn write_html_escaped(output: &mut String, symbol: &str) {
+    for c in symbol.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            _ => output.push(c),
+        }
+    }
+}
+
+/// The index of a named color in the 16 color ANSI palette
+const fn ansi_color_index(color: Color) -> u8 {
+    match color {
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        _ => 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter;
@@ -637,6 +1103,29 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["コン "]));
     }
 
+    #[test]
+    fn set_string_with_shadow() {
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buffer = Buffer::empty(area);
+        buffer.set_string_with_shadow(1, 0, "Hot", Color::White, Color::DarkGray);
+
+        let mut expected = Buffer::with_lines([" Hot  ", "  Hot ", "      "]);
+        expected.set_style(Rect::new(1, 0, 3, 1), Color::White);
+        expected.set_style(Rect::new(2, 1, 3, 1), Color::DarkGray);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn set_string_with_shadow_clips_shadow_to_buffer() {
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buffer = Buffer::empty(area);
+        buffer.set_string_with_shadow(0, 0, "abcd", Color::White, Color::DarkGray);
+
+        let mut expected = Buffer::with_lines(["abcd"]);
+        expected.set_style(area, Color::White);
+        assert_eq!(buffer, expected);
+    }
+
     #[fixture]
     fn small_one_line_buffer() -> Buffer {
         Buffer::empty(Rect::new(0, 0, 5, 1))
@@ -724,6 +1213,109 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn fill_pattern() {
+        let mut buffer = Buffer::with_lines(["aaaaaa", "bbbbbb", "cccccc"]);
+        buffer.fill_pattern(Rect::new(1, 1, 4, 2), &["╱ "], Style::new().red());
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            Line::from("aaaaaa"),
+            Line::from(vec!["b".into(), "╱ ╱ ".red(), "b".into()]),
+            Line::from(vec!["c".into(), "╱ ╱ ".red(), "c".into()]),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn fill_pattern_wraps_rows_and_clips_to_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        buffer.fill_pattern(Rect::new(1, 0, 10, 10), &["abc", "", "x"], Style::new());
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            " abc",
+            "    ",
+            " xxx",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn overlay_at_offset() {
+        let mut buffer = Buffer::with_lines(["aaaa", "bbbb", "cccc"]);
+        let mut popup = Buffer::empty(Rect::new(10, 10, 2, 2));
+        popup.set_string(10, 10, "XY", Style::new().red());
+        popup.set_string(10, 11, "Z", Style::new());
+        buffer.overlay(&popup, Position::new(1, 1));
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            Line::from("aaaa"),
+            Line::from(vec!["b".into(), "XY".red(), "b".into()]),
+            Line::from("cZcc"),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn overlay_skips_empty_cells() {
+        let mut buffer = Buffer::with_lines(["abc"]);
+        let mut popup = Buffer::empty(Rect::new(0, 0, 3, 1));
+        popup.set_style(Rect::new(2, 0, 1, 1), Style::new().on_blue());
+        buffer.overlay(&popup, Position::new(0, 0));
+        let mut expected = Buffer::with_lines(["ab "]);
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().on_blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn overlay_clips_to_area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let popup = Buffer::with_lines(["1234", "5678"]);
+        buffer.overlay(&popup, Position::new(1, 1));
+        assert_eq!(buffer, Buffer::with_lines(["   ", " 12"]));
+        buffer.overlay(&popup, Position::new(u16::MAX, u16::MAX));
+        assert_eq!(buffer, Buffer::with_lines(["   ", " 12"]));
+    }
+
+    #[test]
+    fn sub_buffer_round_trip() {
+        let mut buffer = Buffer::with_lines(["abcd", "efgh", "ijkl"]);
+        let mut sub = buffer.sub_buffer(Rect::new(1, 1, 2, 2));
+        assert_eq!(sub.area, Rect::new(1, 1, 2, 2));
+        assert_eq!(sub.content, Buffer::with_lines(["fg", "jk"]).content);
+
+        sub.set_string(1, 1, "X", Style::new().red());
+        sub.set_string(2, 2, "Y", Style::new());
+        buffer.blit(&sub, sub.area);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            Line::from("abcd"),
+            Line::from(vec!["e".into(), "X".red(), "gh".into()]),
+            Line::from("ijYl"),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn sub_buffer_partially_outside() {
+        let buffer = Buffer::with_lines(["abc", "def"]);
+        let sub = buffer.sub_buffer(Rect::new(2, 1, 5, 5));
+        assert_eq!(sub.area, Rect::new(2, 1, 1, 1));
+        assert_eq!(sub.get(2, 1).symbol(), "f");
+        let sub = buffer.sub_buffer(Rect::new(10, 10, 5, 5));
+        assert!(sub.area.is_empty());
+        assert!(sub.content.is_empty());
+    }
+
+    #[test]
+    fn blit_clips_to_area() {
+        let mut buffer = Buffer::with_lines(["....", "....", "...."]);
+        let mut sub = Buffer::empty(Rect::new(0, 0, 10, 10));
+        sub.set_string(0, 0, "abcdefghij", Style::new());
+        sub.set_string(0, 1, "klmnopqrst", Style::new());
+        buffer.blit(&sub, Rect::new(2, 1, 3, 1));
+        assert_eq!(buffer, Buffer::with_lines(["....", "..ab", "...."]));
+    }
+
     #[test]
     fn with_lines() {
         #[rustfmt::skip]
@@ -739,6 +1331,75 @@ mod tests {
         assert_eq!(buffer.area.height, 4);
     }
 
+    #[test]
+    fn to_ansi_string() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        buf.set_string(0, 0, "ab", Style::new().red());
+        buf.set_string(2, 0, "c", Style::new().red().bold());
+        buf.set_string(3, 0, "d", Style::new().bg(Color::Indexed(42)));
+        buf.set_string(
+            0,
+            1,
+            "コ",
+            Style::new().fg(Color::Rgb(1, 2, 3)).on_light_blue(),
+        );
+        buf.set_string(2, 1, "x", Style::new());
+        buf.set_string(0, 2, "plain", Style::new());
+        assert_eq!(
+            buf.to_ansi_string(),
+            [
+                "\x1b[0;31mab\x1b[0;1;31mc\x1b[0;48;5;42md\x1b[0m ",
+                "\x1b[0;38;2;1;2;3;104mコ\x1b[0mx  ",
+                "plain",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn to_ansi_string_empty_buffer() {
+        assert_eq!(Buffer::empty(Rect::ZERO).to_ansi_string(), "");
+    }
+
+    #[test]
+    fn to_html() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf.set_string(0, 0, "Hi", Style::new().red().bold());
+        assert_eq!(
+            buf.to_html(),
+            r#"<pre><span style="color:#800000;font-weight:bold">Hi</span></pre>"#
+        );
+    }
+
+    #[test]
+    fn to_html_styles_and_escapes() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        buf.set_string(0, 0, "<a>", Style::new().bg(Color::Indexed(67)));
+        buf.set_string(0, 1, "コ", Style::new().fg(Color::Rgb(1, 2, 3)).italic());
+        buf.set_string(2, 1, "&", Style::new().underlined().crossed_out());
+        assert_eq!(
+            buf.to_html(),
+            [
+                r#"<pre><span style="background:#5f87af">&lt;a&gt;</span>  "#,
+                "\n",
+                r#"<span style="color:#010203;font-style:italic">コ</span>"#,
+                r#"<span style="text-decoration:underline line-through">&amp;</span>  </pre>"#,
+            ]
+            .concat()
+        );
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset, None)]
+    #[case::named(Color::LightBlue, Some("#0000ff"))]
+    #[case::indexed_named(Color::Indexed(9), Some("#ff0000"))]
+    #[case::indexed_cube(Color::Indexed(67), Some("#5f87af"))]
+    #[case::indexed_gray(Color::Indexed(244), Some("#808080"))]
+    #[case::rgb(Color::Rgb(255, 128, 0), Some("#ff8000"))]
+    fn css_color(#[case] color: Color, #[case] expected: Option<&str>) {
+        assert_eq!(super::css_color(color).as_deref(), expected);
+    }
+
     #[test]
     fn diff_empty_empty() {
         let area = Rect::new(0, 0, 40, 40);
@@ -847,6 +1508,41 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[test]
+    fn diff_style_only() {
+        let prev = Buffer::with_lines(["abc"]);
+        let mut next = Buffer::with_lines(["abc"]);
+        next.get_mut(1, 0).set_style(Style::new().red());
+
+        let diff = prev.diff(&next);
+        assert_eq!(
+            diff,
+            [(1, 0, &Cell::new("b").set_style(Style::new().red()).clone())]
+        );
+    }
+
+    #[test]
+    fn diff_multi_width_style_only_skips_hidden_cell() {
+        let prev = Buffer::with_lines(["a称"]);
+        let mut next = Buffer::with_lines(["a称"]);
+        next.set_style(next.area, Style::new().red());
+
+        let diff = prev.diff(&next);
+        let red = |symbol| Cell::new(symbol).set_style(Style::new().red()).clone();
+        assert_eq!(diff, [(0, 0, &red("a")), (1, 0, &red("称"))]);
+    }
+
+    #[test]
+    fn diff_multi_width_replaced_by_single_width() {
+        let prev = Buffer::with_lines(["称x"]);
+        let next = Buffer::with_lines(["a x"]);
+
+        let diff = prev.diff(&next);
+        // the cell hidden by the previous multi-width symbol is redrawn even though it is blank in
+        // both buffers
+        assert_eq!(diff, [(0, 0, &Cell::new("a")), (1, 0, &Cell::new(" "))]);
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
@@ -335,6 +335,41 @@ impl Rect {
     }
 }
 
+/// Returns the largest `Rect` with the given `ratio` of width to height that fits in `area`,
+/// centered in `area`.
+///
+/// The ratio is expressed in cells, e.g. `2.0` for a `Rect` twice as wide as it is tall. Note that
+/// terminal cells are usually about twice as tall as they are wide, so a ratio of `2.0` looks
+/// roughly square on screen. An empty `Rect` centered in `area` is returned if `ratio` is not a
+/// positive number.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::layout::{centered_aspect, Rect};
+///
+/// let area = Rect::new(0, 0, 20, 20);
+/// assert_eq!(centered_aspect(area, 2.0), Rect::new(0, 5, 20, 10));
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn centered_aspect(area: Rect, ratio: f32) -> Rect {
+    let (width, height) = if ratio.is_nan() || ratio <= 0.0 {
+        (0, 0)
+    } else if f32::from(area.width) > f32::from(area.height) * ratio {
+        let width = (f32::from(area.height) * ratio).round() as u16;
+        (width.min(area.width), area.height)
+    } else {
+        let height = (f32::from(area.width) / ratio).round() as u16;
+        (area.width, height.min(area.height))
+    };
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
 impl From<(Position, Size)> for Rect {
     fn from((position, size): (Position, Size)) -> Self {
         Self {
@@ -352,6 +387,18 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case::wide(Rect::new(0, 0, 20, 20), 2.0, Rect::new(0, 5, 20, 10))]
+    #[case::tall(Rect::new(0, 0, 20, 20), 0.5, Rect::new(5, 0, 10, 20))]
+    #[case::square(Rect::new(2, 3, 30, 10), 1.0, Rect::new(12, 3, 10, 10))]
+    #[case::widescreen(Rect::new(0, 0, 32, 32), 16.0 / 9.0, Rect::new(0, 7, 32, 18))]
+    #[case::empty_area(Rect::new(5, 5, 0, 0), 2.0, Rect::new(5, 5, 0, 0))]
+    #[case::zero_ratio(Rect::new(0, 0, 20, 10), 0.0, Rect::new(10, 5, 0, 0))]
+    #[case::nan_ratio(Rect::new(0, 0, 20, 10), f32::NAN, Rect::new(10, 5, 0, 0))]
+    fn centered_aspect(#[case] area: Rect, #[case] ratio: f32, #[case] expected: Rect) {
+        assert_eq!(super::centered_aspect(area, ratio), expected);
+    }
+
     #[test]
     fn to_string() {
         assert_eq!(Rect::new(1, 2, 3, 4).to_string(), "3x4+1+2");
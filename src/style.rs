@@ -195,6 +195,15 @@ impl fmt::Debug for Modifier {
 /// );
 /// ```
 ///
+/// With the `serde` feature, styles can be serialized and deserialized, e.g. to store themes in
+/// configuration files. Colors use a human-friendly representation (a name like `"light-red"`, a
+/// hex code like `"#00FF00"` or an index like `"42"`, see [`Color`]) and modifiers are written as
+/// their names separated by `|`:
+///
+/// ```json
+/// { "fg": "light-red", "bg": "#00FF00", "add_modifier": "BOLD | ITALIC", "sub_modifier": "" }
+/// ```
+///
 /// The default implementation returns a `Style` that does not modify anything. If you wish to
 /// reset all properties until that point use [`Style::reset`].
 ///
@@ -798,4 +807,37 @@ mod tests {
                 .remove_modifier(Modifier::DIM)
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let style = Style::new()
+            .fg(Color::Rgb(255, 0, 255))
+            .bg(Color::Indexed(10))
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+            .remove_modifier(Modifier::DIM);
+        let json = serde_json::to_string(&style)?;
+        assert_eq!(serde_json::from_str::<Style>(&json)?, style);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_human_friendly_colors() -> Result<(), serde_json::Error> {
+        let json = r##"{
+            "fg": "light-red",
+            "bg": "#00FF00",
+            "add_modifier": "BOLD | UNDERLINED",
+            "sub_modifier": ""
+        }"##;
+        assert_eq!(
+            serde_json::from_str::<Style>(json)?,
+            Style::new()
+                .light_red()
+                .bg(Color::Rgb(0, 255, 0))
+                .bold()
+                .underlined()
+        );
+        Ok(())
+    }
 }
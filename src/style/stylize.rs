@@ -140,6 +140,20 @@ pub trait Stylize<'a, T>: Sized {
     fn bg<C: Into<Color>>(self, color: C) -> T;
     #[must_use = "`fg` returns the modified style without modifying the original"]
     fn fg<C: Into<Color>>(self, color: C) -> T;
+    /// Sets both the foreground and background colors.
+    ///
+    /// Other attributes of the style (e.g. modifiers) are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let span = "hello".colors(Color::Red, Color::Black);
+    /// assert_eq!(span, "hello".red().on_black());
+    /// ```
+    #[must_use = "`colors` returns the modified style without modifying the original"]
+    fn colors<F: Into<Color>, B: Into<Color>>(self, fg: F, bg: B) -> T;
     #[must_use = "`reset` returns the modified style without modifying the original"]
     fn reset(self) -> T;
     #[must_use = "`add_modifier` returns the modified style without modifying the original"]
@@ -189,6 +203,11 @@ where
         self.set_style(style)
     }
 
+    fn colors<F: Into<Color>, B: Into<Color>>(self, fg: F, bg: B) -> T {
+        let style = self.style().fg(fg.into()).bg(bg.into());
+        self.set_style(style)
+    }
+
     fn add_modifier(self, modifier: Modifier) -> T {
         let style = self.style().add_modifier(modifier);
         self.set_style(style)
@@ -387,6 +406,20 @@ mod tests {
         assert_eq!("hello".cyan().on_cyan(), Span::styled("hello", cyan_fg_bg));
     }
 
+    #[test]
+    fn colors() {
+        let red_on_black = Style::default().fg(Color::Red).bg(Color::Black);
+
+        assert_eq!(
+            "x".colors(Color::Red, Color::Black),
+            Span::styled("x", red_on_black)
+        );
+        assert_eq!(
+            "x".bold().colors(Color::Red, Color::Black),
+            Span::styled("x", red_on_black.add_modifier(Modifier::BOLD))
+        );
+    }
+
     #[test]
     fn repeated_attributes() {
         let bg = Style::default().bg(Color::Cyan);
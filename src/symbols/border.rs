@@ -94,6 +94,38 @@ pub const THICK: Set = Set {
     horizontal_bottom: line::THICK.horizontal,
 };
 
+/// Border Set with a single dashed line width
+///
+/// ```text
+/// ┌┄┄┄┄┄┐
+/// ┆xxxxx┆
+/// ┆xxxxx┆
+/// └┄┄┄┄┄┘
+/// ```
+pub const DASHED: Set = Set {
+    vertical_left: line::DASHED_VERTICAL,
+    vertical_right: line::DASHED_VERTICAL,
+    horizontal_top: line::DASHED_HORIZONTAL,
+    horizontal_bottom: line::DASHED_HORIZONTAL,
+    ..PLAIN
+};
+
+/// Border Set with a thick dashed line width
+///
+/// ```text
+/// ┏┅┅┅┅┅┓
+/// ┇xxxxx┇
+/// ┇xxxxx┇
+/// ┗┅┅┅┅┅┛
+/// ```
+pub const THICK_DASHED: Set = Set {
+    vertical_left: line::THICK_DASHED_VERTICAL,
+    vertical_right: line::THICK_DASHED_VERTICAL,
+    horizontal_top: line::THICK_DASHED_HORIZONTAL,
+    horizontal_bottom: line::THICK_DASHED_HORIZONTAL,
+    ..THICK
+};
+
 pub const QUADRANT_TOP_LEFT: &str = "▘";
 pub const QUADRANT_TOP_RIGHT: &str = "▝";
 pub const QUADRANT_BOTTOM_LEFT: &str = "▖";
@@ -387,6 +419,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dashed() {
+        assert_eq!(
+            render(DASHED),
+            indoc!(
+                "░░░░░░
+                 ░┌┄┄┐░
+                 ░┆░░┆░
+                 ░┆░░┆░
+                 ░└┄┄┘░
+                 ░░░░░░"
+            )
+        );
+    }
+
+    #[test]
+    fn thick_dashed() {
+        assert_eq!(
+            render(THICK_DASHED),
+            indoc!(
+                "░░░░░░
+                 ░┏┅┅┓░
+                 ░┇░░┇░
+                 ░┇░░┇░
+                 ░┗┅┅┛░
+                 ░░░░░░"
+            )
+        );
+    }
+
     #[test]
     fn quadrant_outside() {
         assert_eq!(
@@ -1,10 +1,14 @@
 pub const VERTICAL: &str = "│";
 pub const DOUBLE_VERTICAL: &str = "║";
 pub const THICK_VERTICAL: &str = "┃";
+pub const DASHED_VERTICAL: &str = "┆";
+pub const THICK_DASHED_VERTICAL: &str = "┇";
 
 pub const HORIZONTAL: &str = "─";
 pub const DOUBLE_HORIZONTAL: &str = "═";
 pub const THICK_HORIZONTAL: &str = "━";
+pub const DASHED_HORIZONTAL: &str = "┄";
+pub const THICK_DASHED_HORIZONTAL: &str = "┅";
 
 pub const TOP_RIGHT: &str = "┐";
 pub const ROUNDED_TOP_RIGHT: &str = "╮";
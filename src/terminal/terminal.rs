@@ -1,6 +1,10 @@
 use std::io;
 
-use crate::{backend::ClearType, prelude::*, CompletedFrame, TerminalOptions, Viewport};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    backend::ClearType, buffer::Cell, prelude::*, CompletedFrame, TerminalOptions, Viewport,
+};
 
 /// An interface to interact and draw [`Frame`]s on the user's terminal.
 ///
@@ -73,6 +77,8 @@ where
     last_known_cursor_pos: (u16, u16),
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// Whether the next flush writes every cell instead of the difference between the buffers
+    full_redraw: bool,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -152,6 +158,7 @@ where
             last_known_size: size,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            full_redraw: false,
         })
     }
 
@@ -183,10 +190,18 @@ where
 
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
+    ///
+    /// After a call to [`Terminal::clear`], every cell of the current buffer is passed to the
+    /// backend instead.
     pub fn flush(&mut self) -> io::Result<()> {
+        let full_redraw = std::mem::take(&mut self.full_redraw);
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
-        let updates = previous_buffer.diff(current_buffer);
+        let updates = if full_redraw {
+            all_cells(current_buffer)
+        } else {
+            previous_buffer.diff(current_buffer)
+        };
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = (*col, *row);
         }
@@ -330,6 +345,10 @@ where
     }
 
     /// Clear the terminal and force a full redraw on the next draw call.
+    ///
+    /// The next draw call writes every cell of the viewport to the backend, not only the cells that
+    /// changed since the previous frame. This is useful to recover from external output that
+    /// corrupted the screen.
     pub fn clear(&mut self) -> io::Result<()> {
         match self.viewport {
             Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
@@ -345,8 +364,9 @@ where
                 }
             }
         }
-        // Reset the back buffer to make sure the next update will redraw everything.
+        // Reset the back buffer and make sure the next update will redraw everything.
         self.buffers[1 - self.current].reset();
+        self.full_redraw = true;
         Ok(())
     }
 
@@ -462,6 +482,21 @@ where
     }
 }
 
+/// Returns every cell of the buffer, except the cells hidden by preceding multi-width characters
+/// and the cells marked to be skipped.
+fn all_cells(buffer: &Buffer) -> Vec<(u16, u16, &Cell)> {
+    let mut updates = vec![];
+    let mut to_skip: usize = 0;
+    for (i, cell) in buffer.content.iter().enumerate() {
+        if !cell.skip && to_skip == 0 {
+            let (x, y) = buffer.pos_of(i);
+            updates.push((x, y, cell));
+        }
+        to_skip = cell.symbol().width().saturating_sub(1);
+    }
+    updates
+}
+
 fn compute_inline_size<B: Backend>(
     backend: &mut B,
     height: u16,
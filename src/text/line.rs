@@ -591,6 +591,26 @@ impl WidgetRef for Line<'_> {
     }
 }
 
+/// Renders each line on its own row, from the top of the area
+///
+/// This is a lightweight alternative to [`Text`] and [`Paragraph`](crate::widgets::Paragraph) for
+/// simple multi-line output. Each line is rendered with its own style and alignment, and the lines
+/// that don't fit in the area are not rendered.
+impl Widget for Vec<Line<'_>> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Vec<Line<'_>> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for (line, row) in self.iter().zip(area.rows()) {
+            line.render_ref(row, buf);
+        }
+    }
+}
+
 /// Renders all the spans of the line that should be visible.
 fn render_spans(spans: &[Span], mut area: Rect, buf: &mut Buffer, span_skip_width: usize) {
     for (span, span_width, offset) in spans_after_width(spans, span_skip_width) {
@@ -1310,6 +1330,29 @@ mod tests {
             line.render_ref(buf.area, &mut buf);
             assert_eq!(buf, Buffer::with_lines([expected]));
         }
+
+        #[test]
+        fn render_vec_of_lines() {
+            let lines = vec![
+                hello_world(),
+                Line::from("right").alignment(Alignment::Right),
+            ];
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            lines.render(buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["Hello world!   ", "          right", ""]);
+            expected.set_style(Rect::new(0, 0, 15, 1), ITALIC);
+            expected.set_style(Rect::new(0, 0, 6, 1), BLUE);
+            expected.set_style(Rect::new(6, 0, 6, 1), GREEN);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_vec_of_lines_clips_vertically() {
+            let lines = vec![Line::from("one"), Line::from("two"), Line::from("three")];
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            lines.render(Rect::new(0, 1, 5, 2), &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["", "one  ", "two  "]));
+        }
     }
 
     mod iterators {
@@ -230,6 +230,45 @@ impl<'a> Span<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Converts the content of the span to superscript.
+    ///
+    /// Digits, `+`, `-`, `=`, parentheses and the letters `i` and `n` are replaced by their
+    /// unicode superscript forms. Other characters are left unchanged. This is a text transform
+    /// rather than true typesetting, so the result depends on the font of the terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let span = Span::raw("x2").superscript();
+    /// assert_eq!(span.content, "x²");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn superscript(self) -> Self {
+        let content: String = self.content.chars().map(superscript_char).collect();
+        self.content(content)
+    }
+
+    /// Converts the content of the span to subscript.
+    ///
+    /// Digits, `+`, `-`, `=`, parentheses and the letters `a`, `e`, `h`, `i`, `k`, `l`, `m`, `n`,
+    /// `o`, `p`, `s`, `t` and `x` are replaced by their unicode subscript forms. Other characters
+    /// are left unchanged. This is a text transform rather than true typesetting, so the result
+    /// depends on the font of the terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let span = Span::raw("H2O").subscript();
+    /// assert_eq!(span.content, "H₂O");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn subscript(self) -> Self {
+        let content: String = self.content.chars().map(subscript_char).collect();
+        self.content(content)
+    }
+
     /// Returns the unicode width of the content held by this span.
     pub fn width(&self) -> usize {
         self.content.width()
@@ -406,6 +445,65 @@ impl WidgetRef for Span<'_> {
     }
 }
 
+/// Returns the unicode superscript form of a character, or the character itself if there is none
+const fn superscript_char(c: char) -> char {
+    match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '+' => '⁺',
+        '-' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'i' => 'ⁱ',
+        'n' => 'ⁿ',
+        _ => c,
+    }
+}
+
+/// Returns the unicode subscript form of a character, or the character itself if there is none
+const fn subscript_char(c: char) -> char {
+    match c {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        '+' => '₊',
+        '-' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'i' => 'ᵢ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'x' => 'ₓ',
+        _ => c,
+    }
+}
+
 /// A trait for converting a value to a [`Span`].
 ///
 /// This trait is automatically implemented for any type that implements the [`Display`] trait. As
@@ -602,6 +700,28 @@ mod tests {
         assert_eq!(line.alignment, Some(Alignment::Right));
     }
 
+    #[test]
+    fn superscript() {
+        let span = Span::styled("2", Style::new().green()).superscript();
+        assert_eq!(span, Span::styled("²", Style::new().green()));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+        Span::raw("2").superscript().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["²"]));
+    }
+
+    #[test]
+    fn superscript_leaves_unsupported_chars_unchanged() {
+        let span = Span::raw("x(n+1)ab").superscript();
+        assert_eq!(span.content, "x⁽ⁿ⁺¹⁾ab");
+    }
+
+    #[test]
+    fn subscript() {
+        let span = Span::raw("H2O a_(n-1) ?").subscript();
+        assert_eq!(span.content, "H₂O ₐ_₍ₙ₋₁₎ ?");
+    }
+
     mod widget {
         use rstest::rstest;
 
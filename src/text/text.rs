@@ -1013,6 +1013,20 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["foo  "]));
         }
 
+        #[test]
+        fn render_two_lines() {
+            let text = Text::from(vec![
+                Line::from("foo".red()),
+                Line::from("bar").alignment(Alignment::Center),
+            ]);
+            let area = Rect::new(0, 0, 5, 3);
+            let mut buf = Buffer::empty(area);
+            text.render(area, &mut buf);
+            let mut expected = Buffer::with_lines(["foo  ", " bar ", "     "]);
+            expected.set_style(Rect::new(0, 0, 3, 1), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
         #[rstest]
         fn render_out_of_bounds(mut small_buf: Buffer) {
             let out_of_bounds_area = Rect::new(20, 20, 10, 1);
@@ -5,22 +5,33 @@
 //! They are not meant to be stored but used as *commands* to draw common figures in the UI.
 //!
 //! The available widgets are:
+//! - `Accordion`: displays collapsible sections with one open at a time.
+//!   `(feature: unstable-widget-ref)`
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`CommandPalette`]: displays a searchable list of commands as an overlay.
+//! - [`DataTable`]: displays rows of data in a [`Table`] that can be sorted and filtered.
+//! - [`Diagram`]: draws boxes connected by lines.
 //! - [`Gauge`]: displays progress percentage using block characters.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`Outline`]: displays collapsible nested headings, e.g. a table of contents.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`RingGrid`]: displays the progress of several tasks as rings arranged in a grid.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
+//! - [`StatusBar`]: displays left, center and right groups of segments in a bar.
+//! - [`Suggestions`]: displays a popup of suggestions to complete a query.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
+#[cfg(feature = "unstable-widget-ref")]
+mod accordion;
 mod barchart;
 pub mod block;
 mod borders;
@@ -29,28 +40,49 @@ pub mod calendar;
 pub mod canvas;
 mod chart;
 mod clear;
+mod command_palette;
+mod diagram;
+mod empty_text;
 mod gauge;
 mod list;
+mod outline;
 mod paragraph;
+mod popup;
 mod reflow;
+mod ring_grid;
 mod scrollbar;
 mod sparkline;
+mod status_bar;
+mod suggestions;
 mod table;
 mod tabs;
+pub mod transition;
 
+#[cfg(feature = "unstable-widget-ref")]
+pub use self::accordion::{Accordion, AccordionState};
 pub use self::{
-    barchart::{Bar, BarChart, BarGroup},
+    barchart::{Bar, BarChart, BarChartGrid, BarGroup, LabelOverflow, LabelPosition},
     block::{Block, BorderType, Padding},
     borders::*,
-    chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    chart::{Axis, Chart, Dataset, GraphType, LegendPosition, XLabelsLayout},
     clear::Clear,
-    gauge::{Gauge, LineGauge},
-    list::{List, ListDirection, ListItem, ListState},
-    paragraph::{Paragraph, Wrap},
+    command_palette::{CommandPalette, CommandPaletteState},
+    diagram::{Anchor, Diagram, Edge},
+    gauge::{Gauge, LabelFormat, LineGauge},
+    list::{HighlightScope, List, ListDirection, ListItem, ListState},
+    outline::{Outline, OutlineEntry, OutlineState},
+    paragraph::{Paragraph, TextDirection, Wrap},
+    reflow::wrap_line,
+    ring_grid::RingGrid,
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
     sparkline::{RenderDirection, Sparkline},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
-    tabs::Tabs,
+    status_bar::StatusBar,
+    suggestions::{Suggestions, SuggestionsState},
+    table::{
+        Cell, DataColumn, DataTable, DataTableState, HighlightSpacing, Row, SortDirection, Table,
+        TableState,
+    },
+    tabs::{Tab, Tabs},
 };
 use crate::{buffer::Buffer, layout::Rect, style::Style};
 
@@ -353,6 +385,64 @@ impl<W: WidgetRef> WidgetRef for Option<W> {
     }
 }
 
+/// Renders a pair of widgets in the same area, one after the other.
+///
+/// This is useful to draw several widgets on top of each other in a single call, e.g. a background
+/// [`Block`] and the content rendered over it. The second widget is rendered over the first one and
+/// only replaces the cells it draws, so the styles set by the first widget are kept where the
+/// second widget doesn't draw anything.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let background = Block::new().on_blue();
+/// let content = Paragraph::new("Hello").centered();
+/// frame.render_widget((background, content), area);
+/// # }
+/// ```
+impl<A: Widget, B: Widget> Widget for (A, B) {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.0.render(area, buf);
+        self.1.render(area, buf);
+    }
+}
+
+/// Renders three widgets in the same area, one after the other.
+///
+/// See the implementation for pairs of widgets for more information.
+impl<A: Widget, B: Widget, C: Widget> Widget for (A, B, C) {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.0.render(area, buf);
+        self.1.render(area, buf);
+        self.2.render(area, buf);
+    }
+}
+
+/// Renders a pair of widgets by reference in the same area, one after the other.
+///
+/// The second widget is rendered over the first one and only replaces the cells it draws.
+impl<A: WidgetRef, B: WidgetRef> WidgetRef for (A, B) {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.0.render_ref(area, buf);
+        self.1.render_ref(area, buf);
+    }
+}
+
+/// Renders three widgets by reference in the same area, one after the other.
+///
+/// Each widget is rendered over the previous ones and only replaces the cells it draws.
+impl<A: WidgetRef, B: WidgetRef, C: WidgetRef> WidgetRef for (A, B, C) {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.0.render_ref(area, buf);
+        self.1.render_ref(area, buf);
+        self.2.render_ref(area, buf);
+    }
+}
+
 /// A `StatefulWidgetRef` is a trait that allows rendering a stateful widget by reference.
 ///
 /// This is the stateful equivalent of `WidgetRef`. It is useful when you want to store a reference
@@ -364,7 +454,11 @@ impl<W: WidgetRef> WidgetRef for Option<W> {
 /// compatibility is required.
 ///
 /// A blanket implementation of `StatefulWidget` for `&W` where `W` implements `StatefulWidgetRef`
-/// is provided.
+/// is provided. This means that a reference to a stateful widget can be rendered with
+/// `StatefulWidget::render` (e.g. by [`Frame::render_stateful_widget`]) without calling
+/// `render_ref` manually, and the widget can be used again afterwards.
+///
+/// [`Frame::render_stateful_widget`]: crate::Frame::render_stateful_widget
 ///
 /// See the documentation for [`WidgetRef`] for more information on boxed widgets.
 /// See the documentation for [`StatefulWidget`] for more information on stateful widgets.
@@ -394,10 +488,49 @@ impl<W: WidgetRef> WidgetRef for Option<W> {
 /// fn render(area: Rect, buf: &mut Buffer) {
 ///     let widget = PersonalGreeting;
 ///     let mut state = "world".to_string();
+///     // render by reference with the blanket implementation of `StatefulWidget` for `&W`
+///     (&widget).render(area, buf, &mut state);
 ///     widget.render(area, buf, &mut state);
 /// }
 /// # }
 /// ```
+///
+/// `StatefulWidgetRef` is object safe, so stateful widgets of different types can be boxed and
+/// stored in a collection as long as they share the same `State` type:
+///
+/// ```rust
+/// # #[cfg(feature = "unstable-widget-ref")] {
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// struct Greeting;
+///
+/// impl StatefulWidgetRef for Greeting {
+///     type State = String;
+///     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+///         Line::raw(format!("Hello {state}")).render(area, buf);
+///     }
+/// }
+///
+/// struct Farewell;
+///
+/// impl StatefulWidgetRef for Farewell {
+///     type State = String;
+///     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+///         Line::raw(format!("Goodbye {state}")).render(area, buf);
+///     }
+/// }
+///
+/// fn render(area: Rect, buf: &mut Buffer) {
+///     let widgets: Vec<Box<dyn StatefulWidgetRef<State = String>>> =
+///         vec![Box::new(Greeting), Box::new(Farewell)];
+///     let mut state = "world".to_string();
+///     let areas = Layout::vertical([Constraint::Length(1); 2]).split(area);
+///     for (widget, area) in widgets.iter().zip(areas.iter()) {
+///         widget.render_ref(*area, buf, &mut state);
+///     }
+/// }
+/// # }
+/// ```
 #[stability::unstable(feature = "widget-ref")]
 pub trait StatefulWidgetRef {
     /// State associated with the stateful widget.
@@ -410,19 +543,33 @@ pub trait StatefulWidgetRef {
 }
 
 // Note: while StatefulWidgetRef is marked as unstable, the blanket implementation of StatefulWidget
-// cannot be implemented as W::State is effectively pub(crate) and not accessible from outside the
-// crate. Once stabilized, this blanket implementation can be added and the specific implementations
-// on Table and List can be removed.
-//
-// /// Blanket implementation of `StatefulWidget` for `&W` where `W` implements `StatefulWidgetRef`.
-// ///
-// /// This allows you to render a stateful widget by reference.
-// impl<W: StatefulWidgetRef> StatefulWidget for &W {
-//     type State = W::State;
-//     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-//         StatefulWidgetRef::render_ref(self, area, buf, state);
-//     }
-// }
+// is only available with the unstable-widget-ref feature, as W::State is otherwise pub(crate) and
+// not accessible from outside the crate. Without the feature, the internal stateful widgets
+// implement StatefulWidget for references to themselves instead. Once stabilized, the feature gate
+// and these specific implementations can be removed.
+
+/// Blanket implementation of `StatefulWidget` for `&W` where `W` implements `StatefulWidgetRef`.
+///
+/// This allows you to render a stateful widget by reference.
+#[cfg(feature = "unstable-widget-ref")]
+impl<W: StatefulWidgetRef + ?Sized> StatefulWidget for &W {
+    type State = W::State;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+/// Implementation of `StatefulWidgetRef` for boxed stateful widgets.
+///
+/// This allows a `Box<dyn StatefulWidgetRef<State = S>>` to be rendered like the widget it
+/// contains, so that stateful widgets of different types sharing the same state can be stored
+/// together.
+impl<W: StatefulWidgetRef + ?Sized> StatefulWidgetRef for Box<W> {
+    type State = W::State;
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        (**self).render_ref(area, buf, state);
+    }
+}
 
 /// Renders a string slice as a widget.
 ///
@@ -595,17 +742,15 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
         }
 
-        // Note this cannot be tested until the blanket implementation of StatefulWidget for &W
-        // where W implements StatefulWidgetRef is added. (see the comment in the blanket
-        // implementation for more).
-        // /// This test is to ensure that the blanket implementation of `StatefulWidget` for `&W`
-        // where /// `W` implements `StatefulWidgetRef` works as expected.
-        // #[rstest]
-        // fn stateful_widget_blanket_render(mut buf: Buffer, mut state: String) {
-        //     let widget = &PersonalGreeting;
-        //     widget.render(buf.area, &mut buf, &mut state);
-        //     assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
-        // }
+        /// This test is to ensure that the blanket implementation of `StatefulWidget` for `&W`
+        /// where `W` implements `StatefulWidgetRef` works as expected.
+        #[rstest]
+        #[cfg(feature = "unstable-widget-ref")]
+        fn stateful_widget_blanket_render(mut buf: Buffer, mut state: String) {
+            let widget = &PersonalGreeting;
+            widget.render(buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
+        }
 
         #[rstest]
         fn box_render_render(mut buf: Buffer, mut state: String) {
@@ -613,6 +758,40 @@ mod tests {
             widget.render_ref(buf.area, &mut buf, &mut state);
             assert_eq!(buf, Buffer::with_lines(["Hello world         "]));
         }
+
+        struct PersonalFarewell;
+
+        impl StatefulWidgetRef for PersonalFarewell {
+            type State = String;
+            fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+                Line::from(format!("Goodbye {state}")).render(area, buf);
+            }
+        }
+
+        #[test]
+        fn render_vec_of_boxed_widgets() {
+            let widgets: Vec<Box<dyn StatefulWidgetRef<State = String>>> =
+                vec![Box::new(PersonalGreeting), Box::new(PersonalFarewell)];
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+            let mut state = "world".to_string();
+            for (y, widget) in (0..).zip(widgets.iter()) {
+                widget.render_ref(Rect::new(0, y, 20, 1), &mut buf, &mut state);
+            }
+            assert_eq!(
+                buf,
+                Buffer::with_lines(["Hello world         ", "Goodbye world       "])
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "unstable-widget-ref")]
+        fn render_boxed_widget_with_blanket_impl() {
+            let widget: Box<dyn StatefulWidgetRef<State = String>> = Box::new(PersonalFarewell);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+            let mut state = "world".to_string();
+            (&widget).render(buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Goodbye world       "]));
+        }
     }
 
     mod option_widget_ref {
@@ -709,4 +888,38 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(["hello world         "]));
         }
     }
+
+    mod tuple {
+        use super::*;
+        use crate::{prelude::*, widgets::Block};
+
+        #[rstest]
+        fn render_pair(mut buf: Buffer) {
+            (Block::new().on_blue(), "hello".red()).render(buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["hello               "]);
+            expected.set_style(expected.area, Style::new().on_blue());
+            expected.set_style(Rect::new(0, 0, 5, 1), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        fn render_triple(mut buf: Buffer) {
+            let area = Rect::new(0, 0, 3, 1);
+            (Block::new().on_blue(), "hello", Span::raw("HI").bold()).render(area, &mut buf);
+            let mut expected = Buffer::with_lines(["HIl                 "]);
+            expected.set_style(area, Style::new().on_blue());
+            expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        fn render_ref_pair(mut buf: Buffer) {
+            let widgets = (Block::new().on_blue(), String::from("hello"));
+            widgets.render_ref(buf.area, &mut buf);
+            widgets.render_ref(buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["hello               "]);
+            expected.set_style(expected.area, Style::new().on_blue());
+            assert_eq!(buf, expected);
+        }
+    }
 }
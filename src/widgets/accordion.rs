@@ -0,0 +1,444 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use itertools::Itertools;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{prelude::*, style::Styled, widgets::Block};
+
+/// The title and the content of a section of an [`Accordion`]
+type Section<'a> = (Line<'a>, Box<dyn WidgetRef + 'a>);
+
+/// State of the [`Accordion`] widget
+///
+/// This state holds the index of the open section, if any. The areas of the section titles are
+/// only known once the accordion is rendered, so [`AccordionState::section_at`] maps a position
+/// (e.g. a mouse click) to the section whose title was at that position during the last render.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = AccordionState::default().with_opened(Some(0));
+/// state.toggle(1);
+/// assert_eq!(state.opened(), Some(1));
+/// state.toggle(1);
+/// assert_eq!(state.opened(), None);
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccordionState {
+    opened: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    title_areas: Vec<Rect>,
+}
+
+impl PartialEq for AccordionState {
+    fn eq(&self, other: &Self) -> bool {
+        self.opened == other.opened
+    }
+}
+
+impl Eq for AccordionState {}
+
+impl Hash for AccordionState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.opened.hash(state);
+    }
+}
+
+impl AccordionState {
+    /// Sets the index of the open section
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_opened(mut self, opened: Option<usize>) -> Self {
+        self.opened = opened;
+        self
+    }
+
+    /// Index of the open section
+    ///
+    /// Returns `None` if all the sections are collapsed
+    pub const fn opened(&self) -> Option<usize> {
+        self.opened
+    }
+
+    /// Opens the section at `index`, collapsing the section that was open
+    pub fn open(&mut self, index: usize) {
+        self.opened = Some(index);
+    }
+
+    /// Collapses all the sections
+    pub fn close(&mut self) {
+        self.opened = None;
+    }
+
+    /// Collapses the section at `index` if it is open, and opens it otherwise
+    pub fn toggle(&mut self, index: usize) {
+        if self.opened == Some(index) {
+            self.close();
+        } else {
+            self.open(index);
+        }
+    }
+
+    /// Index of the section whose title was at the given position during the last render
+    ///
+    /// Returns `None` if there was no title at that position. This is typically used to toggle
+    /// the section that was clicked.
+    pub fn section_at(&self, position: Position) -> Option<usize> {
+        self.title_areas
+            .iter()
+            .position(|area| area.contains(position))
+    }
+
+    /// Areas of the section titles during the last render, in order
+    ///
+    /// The titles that didn't fit in the area are not included.
+    pub fn title_areas(&self) -> &[Rect] {
+        &self.title_areas
+    }
+}
+
+/// A vertical list of collapsible sections, where at most one section is open at a time.
+/// `(feature: unstable-widget-ref)`
+///
+/// Each section has a title, rendered on its own row and prefixed with an
+/// [open](Accordion::open_symbol) or [closed](Accordion::closed_symbol) symbol, and a content,
+/// which is any widget that implements [`WidgetRef`]. The content of the open section is rendered
+/// below its title, in all the rows that are not used by the titles.
+///
+/// The open section is stored in the [`AccordionState`], which is updated by the application in
+/// response to key presses or mouse clicks on the titles (see [`AccordionState::section_at`]).
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let accordion = Accordion::new([
+///     ("General", Box::new(Paragraph::new("Name: ratatui")) as Box<dyn WidgetRef>),
+///     ("Display", Box::new(Paragraph::new("Theme: dark"))),
+/// ])
+/// .block(Block::bordered().title("Settings"))
+/// .open_title_style(Style::new().bold());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = AccordionState::default().with_opened(Some(0));
+///
+/// frame.render_stateful_widget(&accordion, area, &mut state);
+/// # }
+/// ```
+pub struct Accordion<'a> {
+    /// The titles and contents of the sections
+    sections: Vec<Section<'a>>,
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Base style of the widget
+    style: Style,
+    /// Style of the section titles
+    title_style: Style,
+    /// Style patched on top of `title_style` for the title of the open section
+    open_title_style: Style,
+    /// Symbol in front of the title of the open section
+    open_symbol: &'a str,
+    /// Symbol in front of the titles of the collapsed sections
+    closed_symbol: &'a str,
+}
+
+impl<'a> Accordion<'a> {
+    /// Creates a new accordion with the given sections
+    ///
+    /// Each section is a title, which accepts any type that can be converted into a [`Line`]
+    /// (e.g. `&str`, `String`, [`Span`], ...), and a boxed widget for its content.
+    pub fn new<I, T>(sections: I) -> Self
+    where
+        I: IntoIterator<Item = (T, Box<dyn WidgetRef + 'a>)>,
+        T: Into<Line<'a>>,
+    {
+        Self {
+            sections: sections
+                .into_iter()
+                .map(|(title, content)| (title.into(), content))
+                .collect(),
+            block: None,
+            style: Style::new(),
+            title_style: Style::new(),
+            open_title_style: Style::new(),
+            open_symbol: "▾ ",
+            closed_symbol: "▸ ",
+        }
+    }
+
+    /// Wraps the accordion with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the section titles
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.title_style = style.into();
+        self
+    }
+
+    /// Sets the style of the title of the open section
+    ///
+    /// This style is patched on top of the [title style](Accordion::title_style).
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn open_title_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.open_title_style = style.into();
+        self
+    }
+
+    /// Sets the symbol in front of the title of the open section
+    ///
+    /// Defaults to `"▾ "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn open_symbol(mut self, open_symbol: &'a str) -> Self {
+        self.open_symbol = open_symbol;
+        self
+    }
+
+    /// Sets the symbol in front of the titles of the collapsed sections
+    ///
+    /// Defaults to `"▸ "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn closed_symbol(mut self, closed_symbol: &'a str) -> Self {
+        self.closed_symbol = closed_symbol;
+        self
+    }
+}
+
+impl fmt::Debug for Accordion<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Accordion")
+            .field(
+                "titles",
+                &self.sections.iter().map(|(title, _)| title).collect_vec(),
+            )
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("title_style", &self.title_style)
+            .field("open_title_style", &self.open_title_style)
+            .field("open_symbol", &self.open_symbol)
+            .field("closed_symbol", &self.closed_symbol)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Widget for Accordion<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl WidgetRef for Accordion<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = AccordionState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Accordion<'_> {
+    type State = AccordionState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Accordion<'_> {
+    type State = AccordionState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.title_areas.clear();
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        // The open section gets all the rows that are not used by the titles
+        let content_height = inner.height.saturating_sub(self.sections.len() as u16);
+        let mut rows = inner.rows();
+        for (index, (title, content)) in self.sections.iter().enumerate() {
+            let Some(row) = rows.next() else {
+                break;
+            };
+            let is_open = state.opened == Some(index);
+            let (symbol, title_style) = if is_open {
+                (
+                    self.open_symbol,
+                    self.title_style.patch(self.open_title_style),
+                )
+            } else {
+                (self.closed_symbol, self.title_style)
+            };
+            buf.set_style(row, title_style);
+            let (x, _) = buf.set_stringn(row.x, row.y, symbol, row.width as usize, title_style);
+            let symbol_width = (x - row.x).min(symbol.width() as u16);
+            title.render_ref(row.indent_x(symbol_width), buf);
+            state.title_areas.push(row);
+
+            if is_open && content_height > 0 {
+                let content_area = Rect {
+                    y: row.bottom(),
+                    height: content_height,
+                    ..row
+                };
+                content.render_ref(content_area, buf);
+                rows.nth(usize::from(content_height) - 1);
+            }
+        }
+    }
+}
+
+impl Styled for Accordion<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::Paragraph;
+
+    fn accordion() -> Accordion<'static> {
+        Accordion::new([
+            ("One", Box::new(Line::from("first")) as Box<dyn WidgetRef>),
+            ("Two", Box::new(Paragraph::new("second\ncontent"))),
+            ("Three", Box::new(Line::from("third"))),
+        ])
+    }
+
+    #[test]
+    fn render_collapsed() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        let mut state = AccordionState::default();
+        StatefulWidget::render(&accordion(), buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "▸ One     ",
+            "▸ Two     ",
+            "▸ Three   ",
+            "          ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_open_section() {
+        let accordion = accordion().open_title_style(Style::new().bold());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let mut state = AccordionState::default().with_opened(Some(0));
+        StatefulWidget::render(&accordion, buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "▾ One     ",
+            "first     ",
+            "          ",
+            "▸ Two     ",
+            "▸ Three   ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 10, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+
+        // opening the second section collapses the first one
+        state.open(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        StatefulWidget::render(&accordion, buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "▸ One     ",
+            "▾ Two     ",
+            "second    ",
+            "content   ",
+            "▸ Three   ",
+        ]);
+        expected.set_style(Rect::new(0, 1, 10, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_truncates_titles() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let mut state = AccordionState::default().with_opened(Some(0));
+        StatefulWidget::render(&accordion(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["▾ One", "▸ Two"]));
+        assert_eq!(state.title_areas().len(), 2);
+    }
+
+    #[test]
+    fn section_at_maps_clicks_to_titles() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 7));
+        let mut state = AccordionState::default().with_opened(Some(0));
+        let accordion = accordion().block(Block::bordered());
+        StatefulWidget::render(&accordion, buf.area, &mut buf, &mut state);
+        assert_eq!(state.section_at(Position::new(3, 1)), Some(0));
+        assert_eq!(state.section_at(Position::new(3, 2)), None);
+        assert_eq!(state.section_at(Position::new(3, 4)), Some(1));
+        assert_eq!(state.section_at(Position::new(10, 5)), Some(2));
+        assert_eq!(state.section_at(Position::new(0, 5)), None);
+    }
+
+    #[test]
+    fn toggle() {
+        let mut state = AccordionState::default();
+        state.toggle(1);
+        assert_eq!(state.opened(), Some(1));
+        state.toggle(2);
+        assert_eq!(state.opened(), Some(2));
+        state.toggle(2);
+        assert_eq!(state.opened(), None);
+    }
+
+    #[test]
+    fn title_areas_are_not_compared() {
+        let mut state = AccordionState::default().with_opened(Some(1));
+        state.title_areas.push(Rect::new(0, 0, 10, 1));
+        assert_eq!(state, AccordionState::default().with_opened(Some(1)));
+    }
+}
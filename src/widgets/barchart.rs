@@ -83,6 +83,83 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// where the values are printed relative to the bars
+    label_position: LabelPosition,
+    /// how the labels wider than the bars are truncated
+    label_overflow: LabelOverflow,
+    /// lines drawn behind the bars at regular value intervals
+    grid: Option<BarChartGrid>,
+}
+
+/// The position of the values of the bars in a [`BarChart`]
+///
+/// See [`BarChart::label_position`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LabelPosition {
+    /// Values are printed in the bottom row of the bars. This is the **default**.
+    #[default]
+    Inside,
+    /// Values are printed in the row just above the bars
+    Above,
+    /// Values are printed inside the bars that are at least one row high and above the shorter
+    /// bars
+    Auto,
+}
+
+/// How the labels of the bars of a [`BarChart`] are truncated when they are wider than the bars
+///
+/// See [`BarChart::label_overflow`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LabelOverflow {
+    /// The end of the label is cut at the width of the bar. This is the **default**.
+    #[default]
+    Clip,
+    /// The end of the label is replaced with an ellipsis (`…`) so that it fits in the bar
+    Ellipsis,
+}
+
+/// Lines drawn behind the bars of a [`BarChart`] at regular value intervals
+///
+/// A line is drawn at each multiple of the interval up to the maximum value of the chart, at the
+/// height (or length for horizontal bars) a bar with that value would reach. The lines are `─` for
+/// vertical bars and `│` for horizontal bars.
+///
+/// See [`BarChart::grid`]
+///
+/// # Example
+///
+/// ```
+/// # use ratatui::{prelude::*, widgets::*};
+/// let grid = BarChartGrid::new(25).style(Style::new().dark_gray());
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BarChartGrid {
+    /// The value between two lines
+    interval: u64,
+    /// Style of the lines
+    style: Style,
+}
+
+impl BarChartGrid {
+    /// Creates a grid with a line every `interval` of value
+    ///
+    /// No lines are drawn when the interval is 0.
+    pub const fn new(interval: u64) -> Self {
+        Self {
+            interval,
+            style: Style::new(),
+        }
+    }
+
+    /// Set the style of the lines
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
 }
 
 impl<'a> Default for BarChart<'a> {
@@ -100,6 +177,9 @@ impl<'a> Default for BarChart<'a> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            label_position: LabelPosition::Inside,
+            label_overflow: LabelOverflow::Clip,
+            grid: None,
         }
     }
 }
@@ -305,6 +385,87 @@ impl<'a> BarChart<'a> {
         self.direction = direction;
         self
     }
+
+    /// Set where the values are printed relative to vertical bars.
+    ///
+    /// By default the values are printed [`Inside`](LabelPosition::Inside) the bars, which hides
+    /// the values of bars that are shorter than a row. [`Above`](LabelPosition::Above) prints all
+    /// the values just above the bars and [`Auto`](LabelPosition::Auto) only the values of the
+    /// short bars. Values that are wider than the bars are not printed above them, so that they
+    /// never overlap the neighbouring bars. The values of bars that reach the top of the chart are
+    /// always printed inside.
+    ///
+    /// This has no effect on [`Horizontal`](Direction::Horizontal) bars.
+    ///
+    /// # Examples
+    ///
+    /// ```plain
+    ///   █
+    ///   █
+    /// 1 █
+    /// ▃ 9
+    /// A B
+    /// ```
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = BarChart::default()
+    ///     .data(&[("A", 1), ("B", 9)])
+    ///     .label_position(LabelPosition::Auto);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Set how the labels under the bars are truncated when they are wider than the bars.
+    ///
+    /// By default, labels are clipped at the width of the bar ([`LabelOverflow::Clip`]). With
+    /// [`LabelOverflow::Ellipsis`], the end of the label is replaced with `…`. Labels are centered
+    /// under their bar in both cases. This only applies to [vertical](Direction::Vertical) bars,
+    /// as horizontal bars reserve enough room for the longest label.
+    ///
+    /// # Example
+    ///
+    /// This example shows `Ja…` and `Fe…` under the bars.
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = BarChart::default()
+    ///     .data(&[("January", 1), ("February", 9)])
+    ///     .bar_width(3)
+    ///     .label_overflow(LabelOverflow::Ellipsis);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_overflow(mut self, overflow: LabelOverflow) -> Self {
+        self.label_overflow = overflow;
+        self
+    }
+
+    /// Draw lines behind the bars at regular value intervals.
+    ///
+    /// The lines make the values of the bars easier to read. They are behind the bars: they are
+    /// only drawn in the empty cells of the bars area, and the values and labels are drawn over
+    /// them. The position of the lines depends on the
+    /// maximum value of the chart (see [`BarChart::max`]).
+    ///
+    /// # Example
+    ///
+    /// This example draws a line every 25% of the maximum value.
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = BarChart::default()
+    ///     .data(&[("A", 40), ("B", 90)])
+    ///     .max(100)
+    ///     .grid(BarChartGrid::new(25).style(Style::new().dark_gray()));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn grid(mut self, grid: BarChartGrid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -470,6 +631,7 @@ impl BarChart<'_> {
                 bar_y += self.group_gap;
             }
         }
+        self.render_grid_lines(bars_area, buf);
     }
 
     fn render_vertical(&self, buf: &mut Buffer, area: Rect) {
@@ -482,9 +644,28 @@ impl BarChart<'_> {
 
         let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
         self.render_vertical_bars(bars_area, buf, &group_ticks);
+        self.render_grid_lines(bars_area, buf);
         self.render_labels_and_values(area, buf, label_info, &group_ticks);
     }
 
+    /// Returns the row where the value of a vertical bar is printed and the ticks used to decide
+    /// whether the value fits, given the bottom row of the bars and the height of the bars area.
+    fn value_position(&self, ticks: u64, bar_y: u16, bars_height: u16) -> (u16, u64) {
+        const TICKS_PER_LINE: u64 = 8;
+        let above = match self.label_position {
+            LabelPosition::Inside => false,
+            LabelPosition::Above => true,
+            LabelPosition::Auto => ticks < TICKS_PER_LINE,
+        };
+        let bar_rows = ticks.div_ceil(TICKS_PER_LINE) as u16;
+        if above && bar_rows < bars_height {
+            // the value doesn't hide any part of the bar, so it can be as wide as the bar
+            (bar_y - bar_rows, ticks.max(TICKS_PER_LINE))
+        } else {
+            (bar_y, ticks)
+        }
+    }
+
     fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
         // print all visible bars (without labels and values)
         let mut bar_x = area.left();
@@ -520,6 +701,67 @@ impl BarChart<'_> {
         }
     }
 
+    /// Returns the distances from the base of the bars of the grid lines, given the maximal length
+    /// of the bars. A distance of 1 is the cell next to the base.
+    ///
+    /// Each cell holds at most one line, even when the interval is smaller than a cell.
+    fn grid_offsets(&self, bar_max_length: u16) -> Vec<u16> {
+        let Some(grid) = self.grid.filter(|grid| grid.interval > 0) else {
+            return Vec::new();
+        };
+        let max = u128::from(self.maximum_data_value());
+        if max == 0 {
+            return Vec::new();
+        }
+        let interval = u128::from(grid.interval);
+        let eighths = u128::from(bar_max_length) * 8;
+        let offset = |value: u128| (value * eighths / max).div_ceil(8);
+        (1..=bar_max_length)
+            .filter(|&distance| {
+                // the first multiple of the interval from the smallest value drawn in this cell
+                let lowest = ((u128::from(distance) - 1) * 8 + 1) * max;
+                let line = lowest.div_ceil(eighths).div_ceil(interval) * interval;
+                line <= max && offset(line) == u128::from(distance)
+            })
+            .collect()
+    }
+
+    /// Draws the grid lines in the empty cells of the bars area
+    ///
+    /// This is done after drawing the bars and before drawing the values and labels, so that the
+    /// lines are only visible behind the bars.
+    fn render_grid_lines(&self, area: Rect, buf: &mut Buffer) {
+        let Some(grid) = self.grid else {
+            return;
+        };
+        let (lines, symbol) = match self.direction {
+            Direction::Vertical => {
+                let rows = self.grid_offsets(area.height).into_iter();
+                let lines = rows.map(|offset| Rect {
+                    y: area.bottom() - offset,
+                    height: 1,
+                    ..area
+                });
+                (lines.collect::<Vec<_>>(), symbols::line::HORIZONTAL)
+            }
+            Direction::Horizontal => {
+                let columns = self.grid_offsets(area.width).into_iter();
+                let lines = columns.map(|offset| Rect {
+                    x: area.left() + offset - 1,
+                    width: 1,
+                    ..area
+                });
+                (lines.collect(), symbols::line::VERTICAL)
+            }
+        };
+        for position in lines.into_iter().flat_map(Rect::positions) {
+            let cell = buf.get_mut(position.x, position.y);
+            if cell.symbol() == " " || cell.symbol() == self.bar_set.empty {
+                cell.set_symbol(symbol).set_style(grid.style);
+            }
+        }
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
         self.max
@@ -563,10 +805,19 @@ impl BarChart<'_> {
             // print the bar values and numbers
             for (bar, ticks) in group.bars.iter().zip(ticks_vec) {
                 if label_info.bar_label_visible {
-                    bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
+                    bar.render_label(
+                        buf,
+                        self.bar_width,
+                        bar_x,
+                        bar_y + 1,
+                        self.label_style,
+                        self.label_overflow,
+                    );
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                let bars_height = area.height - label_info.height;
+                let (value_y, ticks) = self.value_position(*ticks, bar_y, bars_height);
+                bar.render_value(buf, self.bar_width, bar_x, value_y, self.value_style, ticks);
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -613,6 +864,7 @@ impl<'a> Styled for BarChart<'a> {
 #[cfg(test)]
 mod tests {
     use itertools::iproduct;
+    use rstest::rstest;
 
     use super::*;
     use crate::widgets::BorderType;
@@ -843,6 +1095,103 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::inside(LabelPosition::Inside, ["  █  ", "  █ ▁", "  █ █", "▃ 9 5", "a b c"])]
+    #[case::above(LabelPosition::Above, ["  █ 5", "  █ ▁", "1 █ █", "▃ 9 █", "a b c"])]
+    #[case::auto(LabelPosition::Auto, ["  █  ", "  █ ▁", "1 █ █", "▃ 9 5", "a b c"])]
+    fn label_position(#[case] position: LabelPosition, #[case] expected: [&str; 5]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        BarChart::default()
+            .data(&[("a", 1), ("b", 9), ("c", 5)])
+            .label_position(position)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::clip(LabelOverflow::Clip, "Jan 日 ")]
+    #[case::ellipsis(LabelOverflow::Ellipsis, "Ja… 日…")]
+    fn label_overflow(#[case] overflow: LabelOverflow, #[case] labels: &str) {
+        let chart = BarChart::default()
+            .data(&[("January", 1), ("日本語", 2)])
+            .bar_width(3)
+            .label_overflow(overflow);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    ███", "█1█ █2█", labels]));
+    }
+
+    #[test]
+    fn label_overflow_keeps_short_labels() {
+        let chart = BarChart::default()
+            .data(&[("a", 1), ("abc", 2)])
+            .bar_width(3)
+            .label_overflow(LabelOverflow::Ellipsis);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["    ███", "█1█ █2█", " a  abc"])
+        );
+    }
+
+    #[test]
+    fn grid_lines_behind_vertical_bars() {
+        let chart = BarChart::default()
+            .data(&[("a", 40), ("b", 100)])
+            .max(100)
+            .bar_width(2)
+            .grid(BarChartGrid::new(25).style(Style::new().dark_gray()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 9));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "───██─",
+            "   ██ ",
+            "───██─",
+            "   ██ ",
+            "▁▁─██─",
+            "██ ██ ",
+            "██─██─",
+            "40 ██ ",
+            "a  b  ",
+        ]);
+        // every 25% of the maximum value, except where the bars are
+        for y in [0, 2, 4, 6] {
+            expected.set_style(Rect::new(2, y, 1, 1), Style::new().dark_gray());
+            expected.set_style(Rect::new(5, y, 1, 1), Style::new().dark_gray());
+        }
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().dark_gray());
+        expected.set_style(Rect::new(0, 2, 2, 1), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn grid_lines_behind_horizontal_bars() {
+        let chart = BarChart::default()
+            .data(&[("a", 50), ("b", 100)])
+            .max(100)
+            .direction(Direction::Horizontal)
+            .grid(BarChartGrid::new(25));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["a 50██ │ │", "   │ │ │ │", "b 100█████"])
+        );
+    }
+
+    #[test]
+    fn grid_offsets_are_capped_to_the_bar_length() {
+        let chart = BarChart::default()
+            .data(&[("a", u64::MAX)])
+            .grid(BarChartGrid::new(1));
+        assert_eq!(chart.grid_offsets(4), [1, 2, 3, 4]);
+        let chart = BarChart::default()
+            .data(&[("a", 100)])
+            .grid(BarChartGrid::new(30));
+        assert_eq!(chart.grid_offsets(10), [3, 6, 9]);
+    }
+
     #[test]
     fn test_empty_group() {
         let chart = BarChart::default()
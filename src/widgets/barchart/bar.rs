@@ -1,6 +1,7 @@
 use unicode_width::UnicodeWidthStr;
 
-use crate::prelude::*;
+use super::LabelOverflow;
+use crate::{prelude::*, widgets::reflow::truncate_with_ellipsis};
 
 /// A bar to be shown by the [`BarChart`](crate::widgets::BarChart) widget.
 ///
@@ -180,14 +181,18 @@ impl<'a> Bar<'a> {
         x: u16,
         y: u16,
         default_label_style: Style,
+        overflow: LabelOverflow,
     ) {
+        let truncated = match (&self.label, overflow) {
+            (Some(label), LabelOverflow::Ellipsis) if label.width() > usize::from(max_width) => {
+                Some(truncate_with_ellipsis(label, max_width))
+            }
+            _ => None,
+        };
+        let label = truncated.as_ref().or(self.label.as_ref());
         // center the label. Necessary to do it this way as we don't want to set the style
         // of the whole area, just the label area
-        let width = self
-            .label
-            .as_ref()
-            .map_or(0, Line::width)
-            .min(max_width as usize) as u16;
+        let width = label.map_or(0, Line::width).min(max_width as usize) as u16;
         let area = Rect {
             x: x + (max_width.saturating_sub(width)) / 2,
             y,
@@ -195,7 +200,7 @@ impl<'a> Bar<'a> {
             height: 1,
         };
         buf.set_style(area, default_label_style);
-        if let Some(label) = &self.label {
+        if let Some(label) = label {
             label.render(area, buf);
         }
     }
@@ -8,7 +8,12 @@
 use itertools::Itertools;
 use strum::{Display, EnumString};
 
-use crate::{prelude::*, style::Styled, symbols::border, widgets::Borders};
+use crate::{
+    prelude::*,
+    style::Styled,
+    symbols::{border, line},
+    widgets::{Borders, RoundedCorners},
+};
 
 mod padding;
 pub mod title;
@@ -28,7 +33,9 @@ pub use title::{Position, Title};
 /// the full width of the block, rather than the leftover width.
 ///
 /// Titles are not rendered in the corners of the block unless there is no border on that edge.
-/// If the block is too small and multiple titles overlap, the border may get cut off at a corner.
+/// If the block is too small to fit all the titles, they never overlap: the left titles are
+/// rendered first, then the right titles in the space that remains, and the centered titles are
+/// moved and truncated to fit in the space between them.
 ///
 /// ```plain
 /// ┌With at least a left border───
@@ -77,13 +84,25 @@ pub struct Block<'a> {
     borders: Borders,
     /// Border style
     border_style: Style,
+    /// Styles of the top, right, bottom and left borders, patched on top of `border_style`
+    side_border_styles: [Option<Style>; 4],
     /// The symbols used to render the border. The default is plain lines but one can choose to
     /// have rounded or doubled lines instead or a custom set of symbols
     border_set: border::Set,
+    /// Corners rendered with rounded symbols regardless of the border set
+    rounded_corners: RoundedCorners,
     /// Widget style
     style: Style,
     /// Block padding
     padding: Padding,
+    /// Horizontal and vertical padding as a percentage of the size of the block
+    padding_percentage: (u16, u16),
+    /// Style of the shadow drawn along the right and bottom edges of the block
+    shadow: Option<Style>,
+    /// Number of cells between the border edge and the left and right aligned titles
+    title_padding: u16,
+    /// Rendered between adjacent titles instead of a single border cell
+    title_separator: Option<Span<'a>>,
 }
 
 /// The type of border of a [`Block`].
@@ -136,6 +155,26 @@ pub enum BorderType {
     /// ┗━━━━━━━┛
     /// ```
     Thick,
+    /// A plain border drawn with dashed lines.
+    ///
+    /// # Example
+    ///
+    /// ```plain
+    /// ┌┄┄┄┄┄┄┄┐
+    /// ┆       ┆
+    /// └┄┄┄┄┄┄┄┘
+    /// ```
+    Dashed,
+    /// A thick border drawn with dashed lines.
+    ///
+    /// # Example
+    ///
+    /// ```plain
+    /// ┏┅┅┅┅┅┅┅┓
+    /// ┇       ┇
+    /// ┗┅┅┅┅┅┅┅┛
+    /// ```
+    DashedThick,
     /// A border with a single line on the inside of a half block.
     ///
     /// # Example
@@ -169,9 +208,15 @@ impl<'a> Block<'a> {
             titles_position: Position::Top,
             borders: Borders::NONE,
             border_style: Style::new(),
+            side_border_styles: [None; 4],
             border_set: BorderType::Plain.to_border_set(),
+            rounded_corners: RoundedCorners::NONE,
             style: Style::new(),
             padding: Padding::ZERO,
+            padding_percentage: (0, 0),
+            shadow: None,
+            title_padding: 0,
+            title_separator: None,
         }
     }
 
@@ -211,8 +256,8 @@ impl<'a> Block<'a> {
     /// Without left border───
     /// ```
     ///
-    /// Note: If the block is too small and multiple titles overlap, the border might get cut off at
-    /// a corner.
+    /// Note: If the block is too small to fit all the titles, the left titles take precedence over
+    /// the right titles, and the centered titles are truncated to fit between them.
     ///
     /// # Example
     ///
@@ -346,6 +391,53 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets the number of cells between the border edge and the titles.
+    ///
+    /// Left aligned titles are moved away from the left edge and right aligned titles away from
+    /// the right edge, so that they don't touch the corners of the border. Centered titles are not
+    /// affected. Defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// // ┌──Title───┐
+    /// Block::bordered().title("Title").title_padding(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn title_padding(mut self, padding: u16) -> Self {
+        self.title_padding = padding;
+        self
+    }
+
+    /// Sets the separator rendered between adjacent titles.
+    ///
+    /// Titles with the same position and alignment are normally separated by a single cell of the
+    /// border. With a separator, they are joined by the given span instead, which takes as many
+    /// cells as its width when the titles are laid out and truncated. The separator is not rendered
+    /// before the first or after the last title of each group.
+    ///
+    /// `separator` accepts any type that is convertible to [`Span`] (e.g. `&str`, `String` or a
+    /// styled [`Span`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// // ┌Files • Edit───┐
+    /// Block::bordered()
+    ///     .title("Files")
+    ///     .title("Edit")
+    ///     .title_separator(" • ".dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_separator<T: Into<Span<'a>>>(mut self, separator: T) -> Self {
+        self.title_separator = Some(separator.into());
+        self
+    }
+
     /// Sets the default [`Position`] for all block [titles](Title).
     ///
     /// Titles that explicitly set a [`Position`] will ignore this.
@@ -396,6 +488,39 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Defines the style of some sides of the borders.
+    ///
+    /// The style is applied to each side contained in `borders` and patched on top of
+    /// [`Block::border_style`], which remains the style of the sides that are not set. This can be
+    /// called multiple times to style different sides, e.g. to highlight only the top border of
+    /// a focused pane.
+    ///
+    /// The corners use the style of the top or bottom side they belong to if it is set, and the
+    /// style of the left or right side otherwise.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// This example shows a `Block` with a red top border and white borders on the other sides.
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// Block::bordered()
+    ///     .border_style(Style::new().white())
+    ///     .border_style_for(Borders::TOP, Style::new().red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn border_style_for<S: Into<Style>>(mut self, borders: Borders, style: S) -> Self {
+        let style = style.into();
+        for (i, side) in Self::SIDES.into_iter().enumerate() {
+            if borders.contains(side) {
+                self.side_border_styles[i] = Some(style);
+            }
+        }
+        self
+    }
+
     /// Defines the block style.
     ///
     /// This is the most generic [`Style`] a block can receive, it will be merged with any other
@@ -475,6 +600,33 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Rounds the given corners of the border, independently of the [border type].
+    ///
+    /// This allows rounding only some of the corners of a block drawn with plain lines, e.g. the
+    /// top corners of a panel attached to tabs. Rounded corners only join plain lines, so a corner
+    /// is only rounded when both of the sides that meet at that corner use the plain horizontal and
+    /// vertical symbols. The corners of thick or double borders are left unchanged.
+    ///
+    /// [border type]: Block::border_type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// Block::bordered()
+    ///     .rounded_corners(RoundedCorners::TOP)
+    ///     .title("Block");
+    /// // Renders
+    /// // ╭Block╮
+    /// // │     │
+    /// // └─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rounded_corners(mut self, corners: RoundedCorners) -> Self {
+        self.rounded_corners = corners;
+        self
+    }
+
     /// Defines the padding inside a `Block`.
     ///
     /// See [`Padding`] for more information.
@@ -507,6 +659,58 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Defines the padding inside a `Block` as a percentage of its size.
+    ///
+    /// `horizontal` is the percentage of the width of the block used on the left and the right of
+    /// the content, and `vertical` the percentage of its height used on the top and the bottom.
+    /// The padding is resolved against the area of the block each time it is rendered (see
+    /// [`Padding::percentage`] for the rounding), and is added to the padding set with
+    /// [`Block::padding`].
+    ///
+    /// # Example
+    ///
+    /// This example shows a `Block` with 10% of its width as padding on each side.
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let block = Block::bordered().padding_percentage(10, 0);
+    /// assert_eq!(block.inner(Rect::new(0, 0, 40, 3)), Rect::new(5, 1, 30, 1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn padding_percentage(mut self, horizontal: u16, vertical: u16) -> Self {
+        self.padding_percentage = (horizontal, vertical);
+        self
+    }
+
+    /// Draws a shadow along the right and bottom edges of the block.
+    ///
+    /// The shadow is one column to the right and one row below the block, and is drawn by applying
+    /// `style` to the cells already there. It is usually a dimmed or dark background. The shadow
+    /// stays within the rendered area: the block is drawn one column narrower and one row shorter
+    /// than the area to leave room for it, and the [inner area](Block::inner) shrinks accordingly.
+    /// This pairs with a [`Clear`] area when rendering popups.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Clear`]: crate::widgets::Clear
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// Block::bordered().shadow(Style::new().on_dark_gray());
+    /// // Renders (the cells styled by the shadow are marked with ▒)
+    /// // ┌───────┐
+    /// // │       │▒
+    /// // └───────┘▒
+    /// //  ▒▒▒▒▒▒▒▒▒
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow<S: Into<Style>>(mut self, style: S) -> Self {
+        self.shadow = Some(style.into());
+        self
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     ///
     /// # Examples
@@ -532,6 +736,7 @@ impl<'a> Block<'a> {
     /// // └─────────────┘
     /// ```
     pub fn inner(&self, area: Rect) -> Rect {
+        let area = self.area_without_shadow(area);
         let mut inner = area;
         if self.borders.intersects(Borders::LEFT) {
             inner.x = inner.x.saturating_add(1).min(inner.right());
@@ -559,9 +764,58 @@ impl<'a> Block<'a> {
             .height
             .saturating_sub(self.padding.top + self.padding.bottom);
 
+        let (horizontal, vertical) = self.padding_percentage;
+        let padding = Padding::percentage(horizontal, vertical, area);
+        inner.x = inner.x.saturating_add(padding.left).min(inner.right());
+        inner.y = inner.y.saturating_add(padding.top).min(inner.bottom());
+        inner.width = inner.width.saturating_sub(padding.left + padding.right);
+        inner.height = inner.height.saturating_sub(padding.top + padding.bottom);
+
         inner
     }
 
+    /// Returns the size of the smallest area whose [inner area](Block::inner) fits content of the
+    /// given size
+    ///
+    /// This is used by widgets that size themselves to their content, such as popups.
+    pub(crate) fn outer_size(&self, width: u16, height: u16) -> (u16, u16) {
+        let left = u16::from(self.borders.intersects(Borders::LEFT));
+        let right = u16::from(self.borders.intersects(Borders::RIGHT));
+        let top = u16::from(
+            self.borders.intersects(Borders::TOP) || self.has_title_at_position(Position::Top),
+        );
+        let bottom = u16::from(
+            self.borders.intersects(Borders::BOTTOM)
+                || self.has_title_at_position(Position::Bottom),
+        );
+        let width = width
+            .saturating_add(left + right)
+            .saturating_add(self.padding.left)
+            .saturating_add(self.padding.right);
+        let height = height
+            .saturating_add(top + bottom)
+            .saturating_add(self.padding.top)
+            .saturating_add(self.padding.bottom);
+        let (horizontal, vertical) = self.padding_percentage;
+        let shadow = u16::from(self.shadow.is_some());
+        (
+            add_percentage_padding(width, horizontal).saturating_add(shadow),
+            add_percentage_padding(height, vertical).saturating_add(shadow),
+        )
+    }
+
+    /// The area of the block itself, leaving room for the shadow along the right and bottom edges
+    const fn area_without_shadow(&self, area: Rect) -> Rect {
+        if self.shadow.is_none() {
+            return area;
+        }
+        Rect {
+            width: area.width.saturating_sub(1),
+            height: area.height.saturating_sub(1),
+            ..area
+        }
+    }
+
     fn has_title_at_position(&self, position: Position) -> bool {
         self.titles
             .iter()
@@ -569,6 +823,19 @@ impl<'a> Block<'a> {
     }
 }
 
+/// Returns the smallest length which keeps at least `length` once `percent` percent of it is
+/// removed on both sides (see [`Padding::percentage`])
+///
+/// Nothing can fit in a padding of 50% or more, so the length is returned unchanged in that case.
+fn add_percentage_padding(length: u16, percent: u16) -> u16 {
+    if percent == 0 || percent >= 50 {
+        return length;
+    }
+    let remaining = 100 - 2 * u32::from(percent);
+    let outer = (u32::from(length) * 100).div_ceil(remaining);
+    u16::try_from(outer).unwrap_or(u16::MAX)
+}
+
 impl BorderType {
     /// Convert this `BorderType` into the corresponding [`Set`](border::Set) of border symbols.
     pub const fn border_symbols(border_type: Self) -> border::Set {
@@ -577,6 +844,8 @@ impl BorderType {
             Self::Rounded => border::ROUNDED,
             Self::Double => border::DOUBLE,
             Self::Thick => border::THICK,
+            Self::Dashed => border::DASHED,
+            Self::DashedThick => border::THICK_DASHED,
             Self::QuadrantInside => border::QUADRANT_INSIDE,
             Self::QuadrantOutside => border::QUADRANT_OUTSIDE,
         }
@@ -596,7 +865,8 @@ impl Widget for Block<'_> {
 
 impl WidgetRef for Block<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
-        let area = area.intersection(buf.area);
+        self.render_shadow(area, buf);
+        let area = self.area_without_shadow(area).intersection(buf.area);
         if area.is_empty() {
             return;
         }
@@ -607,6 +877,47 @@ impl WidgetRef for Block<'_> {
 }
 
 impl Block<'_> {
+    /// The sides of the borders, in the order of `side_border_styles`
+    const SIDES: [Borders; 4] = [Borders::TOP, Borders::RIGHT, Borders::BOTTOM, Borders::LEFT];
+
+    /// The style set for a side of the borders with [`Block::border_style_for`], if any
+    fn side_style(&self, side: Borders) -> Option<Style> {
+        Self::SIDES
+            .iter()
+            .position(|s| *s == side)
+            .and_then(|i| self.side_border_styles[i])
+    }
+
+    /// The style of a side of the borders
+    fn side_border_style(&self, side: Borders) -> Style {
+        self.side_style(side)
+            .map_or(self.border_style, |style| self.border_style.patch(style))
+    }
+
+    /// The style of the corner between a horizontal and a vertical side of the borders
+    fn corner_border_style(&self, horizontal: Borders, vertical: Borders) -> Style {
+        self.side_style(horizontal)
+            .or_else(|| self.side_style(vertical))
+            .map_or(self.border_style, |style| self.border_style.patch(style))
+    }
+
+    /// Styles the cells of the area one column to the right and one row below the block
+    fn render_shadow(&self, area: Rect, buf: &mut Buffer) {
+        let Some(style) = self.shadow else {
+            return;
+        };
+        let block = self.area_without_shadow(area);
+        if block.is_empty() {
+            return;
+        }
+        let right = Rect::new(block.right(), block.top() + 1, 1, block.height);
+        let bottom = Rect::new(block.left() + 1, block.bottom(), block.width - 1, 1);
+        let area = area.intersection(buf.area);
+        for shadow in [right, bottom] {
+            buf.set_style(shadow.intersection(area), style);
+        }
+    }
+
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
         self.render_left_side(area, buf);
         self.render_top_side(area, buf);
@@ -625,10 +936,12 @@ impl Block<'_> {
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
-        // NOTE: the order in which these functions are called defines the overlapping behavior
-        self.render_right_titles(position, area, buf);
-        self.render_center_titles(position, area, buf);
-        self.render_left_titles(position, area, buf);
+        // NOTE: the order in which these functions are called defines how titles that don't fit
+        // are truncated: each one only renders in the area left over by the previous ones
+        let titles_area = self.titles_area(area, position);
+        let remaining_area = self.render_left_titles(position, titles_area, buf);
+        let remaining_area = self.render_right_titles(position, remaining_area, buf);
+        self.render_center_titles(position, titles_area, remaining_area, buf);
     }
 
     fn render_left_side(&self, area: Rect, buf: &mut Buffer) {
@@ -636,7 +949,7 @@ impl Block<'_> {
             for y in area.top()..area.bottom() {
                 buf.get_mut(area.left(), y)
                     .set_symbol(self.border_set.vertical_left)
-                    .set_style(self.border_style);
+                    .set_style(self.side_border_style(Borders::LEFT));
             }
         }
     }
@@ -646,7 +959,7 @@ impl Block<'_> {
             for x in area.left()..area.right() {
                 buf.get_mut(x, area.top())
                     .set_symbol(self.border_set.horizontal_top)
-                    .set_style(self.border_style);
+                    .set_style(self.side_border_style(Borders::TOP));
             }
         }
     }
@@ -657,7 +970,7 @@ impl Block<'_> {
             for y in area.top()..area.bottom() {
                 buf.get_mut(x, y)
                     .set_symbol(self.border_set.vertical_right)
-                    .set_style(self.border_style);
+                    .set_style(self.side_border_style(Borders::RIGHT));
             }
         }
     }
@@ -668,56 +981,112 @@ impl Block<'_> {
             for x in area.left()..area.right() {
                 buf.get_mut(x, y)
                     .set_symbol(self.border_set.horizontal_bottom)
-                    .set_style(self.border_style);
+                    .set_style(self.side_border_style(Borders::BOTTOM));
             }
         }
     }
 
+    /// Returns the symbol of a corner, which is rounded if the corner is in the rounded corners and
+    /// both sides meeting at the corner are plain lines
+    fn corner_symbol(
+        &self,
+        corner: RoundedCorners,
+        symbol: &'static str,
+        rounded_symbol: &'static str,
+        horizontal: &str,
+        vertical: &str,
+    ) -> &'static str {
+        if self.rounded_corners.contains(corner)
+            && horizontal == line::HORIZONTAL
+            && vertical == line::VERTICAL
+        {
+            rounded_symbol
+        } else {
+            symbol
+        }
+    }
+
     fn render_bottom_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
+            let symbol = self.corner_symbol(
+                RoundedCorners::BOTTOM_RIGHT,
+                self.border_set.bottom_right,
+                line::ROUNDED_BOTTOM_RIGHT,
+                self.border_set.horizontal_bottom,
+                self.border_set.vertical_right,
+            );
             buf.get_mut(area.right() - 1, area.bottom() - 1)
-                .set_symbol(self.border_set.bottom_right)
-                .set_style(self.border_style);
+                .set_symbol(symbol)
+                .set_style(self.corner_border_style(Borders::BOTTOM, Borders::RIGHT));
         }
     }
 
     fn render_top_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
+            let symbol = self.corner_symbol(
+                RoundedCorners::TOP_RIGHT,
+                self.border_set.top_right,
+                line::ROUNDED_TOP_RIGHT,
+                self.border_set.horizontal_top,
+                self.border_set.vertical_right,
+            );
             buf.get_mut(area.right() - 1, area.top())
-                .set_symbol(self.border_set.top_right)
-                .set_style(self.border_style);
+                .set_symbol(symbol)
+                .set_style(self.corner_border_style(Borders::TOP, Borders::RIGHT));
         }
     }
 
     fn render_bottom_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
+            let symbol = self.corner_symbol(
+                RoundedCorners::BOTTOM_LEFT,
+                self.border_set.bottom_left,
+                line::ROUNDED_BOTTOM_LEFT,
+                self.border_set.horizontal_bottom,
+                self.border_set.vertical_left,
+            );
             buf.get_mut(area.left(), area.bottom() - 1)
-                .set_symbol(self.border_set.bottom_left)
-                .set_style(self.border_style);
+                .set_symbol(symbol)
+                .set_style(self.corner_border_style(Borders::BOTTOM, Borders::LEFT));
         }
     }
 
     fn render_top_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
+            let symbol = self.corner_symbol(
+                RoundedCorners::TOP_LEFT,
+                self.border_set.top_left,
+                line::ROUNDED_TOP_LEFT,
+                self.border_set.horizontal_top,
+                self.border_set.vertical_left,
+            );
             buf.get_mut(area.left(), area.top())
-                .set_symbol(self.border_set.top_left)
-                .set_style(self.border_style);
+                .set_symbol(symbol)
+                .set_style(self.corner_border_style(Borders::TOP, Borders::LEFT));
         }
     }
 
-    /// Render titles aligned to the right of the block
+    /// Render titles aligned to the right of the given area and return the area left of them
     ///
     /// Currently (due to the way lines are truncated), the right side of the leftmost title will
     /// be cut off if the block is too small to fit all titles. This is not ideal and should be
     /// the left side of that leftmost that is cut off. This is due to the line being truncated
     /// incorrectly. See <https://github.com/ratatui-org/ratatui/issues/932>
     #[allow(clippy::similar_names)]
-    fn render_right_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
-        let titles = self.filtered_titles(position, Alignment::Right);
-        let mut titles_area = self.titles_area(area, position);
+    fn render_right_titles(&self, position: Position, area: Rect, buf: &mut Buffer) -> Rect {
+        let mut titles = self.filtered_titles(position, Alignment::Right).peekable();
+        if titles.peek().is_none() {
+            return area;
+        }
+        let mut titles_area = Rect {
+            width: area.width.saturating_sub(self.title_padding),
+            ..area
+        };
 
+        let gap = self.title_gap();
         // render titles in reverse order to align them to the right
-        for title in titles.rev() {
+        let mut titles = titles.rev().peekable();
+        while let Some(title) = titles.next() {
             if titles_area.is_empty() {
                 break;
             }
@@ -732,77 +1101,148 @@ impl Block<'_> {
             };
             buf.set_style(title_area, self.titles_style);
             title.content.render_ref(title_area, buf);
+            if titles.peek().is_some() {
+                let separator_x = title_area.x.saturating_sub(gap).max(titles_area.left());
+                let separator_area = Rect {
+                    x: separator_x,
+                    width: title_area.x - separator_x,
+                    ..titles_area
+                };
+                self.render_title_separator(separator_area, buf);
+            }
 
             // bump the width of the titles area to the left
             titles_area.width = titles_area
                 .width
                 .saturating_sub(title_width)
-                .saturating_sub(1); // space between titles
+                .saturating_sub(gap); // space between titles
         }
+        titles_area
     }
 
     /// Render titles in the center of the block
     ///
+    /// The titles are centered in `area` (the full width of the block), but moved and truncated to
+    /// stay inside `available_area` (the space not used by the left and right titles).
+    ///
     /// Currently this method aligns the titles to the left inside a centered area. This is not
     /// ideal and should be fixed in the future to align the titles to the center of the block and
     /// truncate both sides of the titles if the block is too small to fit all titles.
     #[allow(clippy::similar_names)]
-    fn render_center_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+    fn render_center_titles(
+        &self,
+        position: Position,
+        area: Rect,
+        available_area: Rect,
+        buf: &mut Buffer,
+    ) {
         let titles = self
             .filtered_titles(position, Alignment::Center)
             .collect_vec();
+        let gap = self.title_gap();
         let total_width = titles
             .iter()
-            .map(|title| title.content.width() as u16 + 1) // space between titles
+            .map(|title| title.content.width() as u16 + gap) // space between titles
             .sum::<u16>()
-            .saturating_sub(1); // no space for the last title
+            .saturating_sub(gap); // no space for the last title
 
-        let titles_area = self.titles_area(area, position);
+        let x = area.left() + (area.width.saturating_sub(total_width) / 2);
+        let x = x
+            .min(available_area.right().saturating_sub(total_width))
+            .max(available_area.left());
         let mut titles_area = Rect {
-            x: titles_area.left() + (titles_area.width.saturating_sub(total_width) / 2),
-            ..titles_area
+            x,
+            width: available_area.right().saturating_sub(x),
+            ..available_area
         };
-        for title in titles {
+        let mut titles = titles.into_iter().peekable();
+        while let Some(title) = titles.next() {
             if titles_area.is_empty() {
                 break;
             }
-            let title_width = title.content.width() as u16;
-            let title_area = Rect {
-                width: title_width.min(titles_area.width),
-                ..titles_area
-            };
-            buf.set_style(title_area, self.titles_style);
-            title.content.render_ref(title_area, buf);
+            self.render_left_aligned_title(title, titles_area, titles.peek().is_some(), buf);
 
             // bump the titles area to the right and reduce its width
-            titles_area.x = titles_area.x.saturating_add(title_width + 1);
-            titles_area.width = titles_area.width.saturating_sub(title_width + 1);
+            let title_width = title.content.width() as u16;
+            titles_area.x = titles_area.x.saturating_add(title_width + gap);
+            titles_area.width = titles_area.width.saturating_sub(title_width + gap);
         }
     }
 
-    /// Render titles aligned to the left of the block
+    /// Render titles aligned to the left of the given area and return the area right of them
     #[allow(clippy::similar_names)]
-    fn render_left_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
-        let titles = self.filtered_titles(position, Alignment::Left);
-        let mut titles_area = self.titles_area(area, position);
-        for title in titles {
+    fn render_left_titles(&self, position: Position, area: Rect, buf: &mut Buffer) -> Rect {
+        let mut titles = self.filtered_titles(position, Alignment::Left).peekable();
+        if titles.peek().is_none() {
+            return area;
+        }
+        let padding = self.title_padding.min(area.width);
+        let mut titles_area = Rect {
+            x: area.x + padding,
+            width: area.width - padding,
+            ..area
+        };
+        let gap = self.title_gap();
+        while let Some(title) = titles.next() {
             if titles_area.is_empty() {
                 break;
             }
+            self.render_left_aligned_title(title, titles_area, titles.peek().is_some(), buf);
+
+            // bump the titles area to the right and reduce its width
             let title_width = title.content.width() as u16;
-            let title_area = Rect {
-                width: title_width.min(titles_area.width),
+            titles_area.x = titles_area.x.saturating_add(title_width + gap);
+            titles_area.width = titles_area.width.saturating_sub(title_width + gap);
+        }
+        titles_area
+    }
+
+    /// Render a title at the left of the given area, followed by the separator if `separated`
+    #[allow(clippy::similar_names)]
+    fn render_left_aligned_title(
+        &self,
+        title: &Title,
+        titles_area: Rect,
+        separated: bool,
+        buf: &mut Buffer,
+    ) {
+        let title_width = title.content.width() as u16;
+        let title_area = Rect {
+            width: title_width.min(titles_area.width),
+            ..titles_area
+        };
+        buf.set_style(title_area, self.titles_style);
+        title.content.render_ref(title_area, buf);
+        if separated {
+            let separator_area = Rect {
+                x: title_area.right(),
+                width: titles_area.width - title_area.width,
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
-            title.content.render_ref(title_area, buf);
-
-            // bump the titles area to the right and reduce its width
-            titles_area.x = titles_area.x.saturating_add(title_width + 1);
-            titles_area.width = titles_area.width.saturating_sub(title_width + 1);
+            self.render_title_separator(separator_area, buf);
         }
     }
 
+    /// The number of cells between adjacent titles
+    fn title_gap(&self) -> u16 {
+        self.title_separator
+            .as_ref()
+            .map_or(1, |separator| separator.width() as u16)
+    }
+
+    /// Render the title separator, truncated to the given area
+    fn render_title_separator(&self, area: Rect, buf: &mut Buffer) {
+        let Some(separator) = &self.title_separator else {
+            return;
+        };
+        let area = Rect {
+            width: area.width.min(separator.width() as u16),
+            ..area
+        };
+        buf.set_style(area, self.titles_style);
+        separator.render_ref(area, buf);
+    }
+
     /// An iterator over the titles that match the position and alignment
     fn filtered_titles(
         &self,
@@ -938,6 +1378,82 @@ mod tests {
         assert_eq!(block.inner(area), expected);
     }
 
+    #[rstest]
+    #[case::top(Position::Top, Rect::new(2, 4, 10, 4))]
+    #[case::bottom(Position::Bottom, Rect::new(2, 3, 10, 4))]
+    fn inner_takes_into_account_title_without_borders(
+        #[case] position: Position,
+        #[case] expected: Rect,
+    ) {
+        let area = Rect::new(2, 3, 10, 5);
+        let block = Block::new().title(Title::from("Test").position(position));
+        assert_eq!(block.inner(area), expected);
+
+        // the default position of the titles is also taken into account
+        let block = Block::new().title_position(position).title("Test");
+        assert_eq!(block.inner(area), expected);
+    }
+
+    #[test]
+    fn inner_area_does_not_overlap_bottom_title() {
+        let block = Block::new()
+            .title("Top")
+            .title(Title::from("Bottom").position(Position::Bottom));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        let inner = block.inner(buffer.area);
+        block.render(buffer.area, &mut buffer);
+        Text::from(vec![Line::from("xxxxxxxx"); 4]).render(inner, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Top     ", "xxxxxxxx", "xxxxxxxx", "Bottom  "])
+        );
+    }
+
+    #[rstest]
+    #[case::narrow(Rect::new(0, 0, 9, 5), Rect::new(1, 1, 7, 3))]
+    #[case::ten(Rect::new(0, 0, 10, 5), Rect::new(2, 1, 6, 3))]
+    #[case::wide(Rect::new(0, 0, 45, 20), Rect::new(5, 3, 35, 14))]
+    #[case::offset(Rect::new(3, 2, 100, 10), Rect::new(14, 4, 78, 6))]
+    #[case::empty(Rect::new(0, 0, 0, 0), Rect::new(0, 0, 0, 0))]
+    fn inner_takes_into_account_padding_percentage(#[case] area: Rect, #[case] expected: Rect) {
+        let block = Block::bordered().padding_percentage(10, 10);
+        assert_eq!(block.inner(area), expected);
+    }
+
+    #[rstest]
+    #[case::none(Block::new(), (5, 3))]
+    #[case::bordered(Block::bordered(), (7, 5))]
+    #[case::title(Block::new().title("Title"), (5, 4))]
+    #[case::padding(Block::bordered().padding(Padding::new(1, 2, 3, 4)), (10, 12))]
+    #[case::padding_percentage(Block::bordered().padding_percentage(10, 20), (9, 9))]
+    #[case::shadow(Block::bordered().shadow(Style::new().on_dark_gray()), (8, 6))]
+    fn outer_size(#[case] block: Block, #[case] expected: (u16, u16)) {
+        let (width, height) = block.outer_size(5, 3);
+        assert_eq!((width, height), expected);
+        let inner = block.inner(Rect::new(0, 0, width, height));
+        assert!(inner.width >= 5 && inner.height >= 3, "{inner:?}");
+    }
+
+    #[test]
+    fn padding_percentage_never_exceeds_the_area() {
+        let block = Block::bordered().padding_percentage(100, 100);
+        for width in 0..20 {
+            let inner = block.inner(Rect::new(0, 0, width, 5));
+            assert!(inner.right() <= width, "width {width}: {inner:?}");
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        Block::bordered()
+            .padding_percentage(20, 0)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌────┐",
+            "│    │",
+            "└────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn has_title_at_position_takes_into_account_all_positioning_declarations() {
         let block = Block::new();
@@ -1001,9 +1517,15 @@ mod tests {
                 titles_position: Position::Top,
                 borders: Borders::NONE,
                 border_style: Style::new(),
+                side_border_styles: [None; 4],
                 border_set: BorderType::Plain.to_border_set(),
+                rounded_corners: RoundedCorners::NONE,
                 style: Style::new(),
                 padding: Padding::ZERO,
+                padding_percentage: (0, 0),
+                shadow: None,
+                title_padding: 0,
+                title_separator: None,
             }
         );
     }
@@ -1155,6 +1677,154 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::fit(25, "┌Left─Center title─Right┐")]
+    #[case::center_truncated(22, "┌Left─Center ti─Right┐")]
+    #[case::center_truncated_more(19, "┌Left─Center─Right┐")]
+    #[case::center_hidden(13, "┌Left──Right┐")]
+    #[case::right_truncated(8, "┌Left─R┐")]
+    fn render_colliding_titles(#[case] width: u16, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 1));
+        Block::bordered()
+            .title(Title::from("Center title").alignment(Alignment::Center))
+            .title(Title::from("Right").alignment(Alignment::Right))
+            .title(Title::from("Left").alignment(Alignment::Left))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_center_title_moved_between_left_and_right_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 1));
+        Block::new()
+            .title(Title::from("Long left").alignment(Alignment::Left))
+            .title(Title::from("C").alignment(Alignment::Center))
+            .title(Title::from("R").alignment(Alignment::Right))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Long left C   R"]));
+    }
+
+    #[test]
+    fn render_title_padding() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 3));
+        Block::bordered()
+            .title(Title::from("L").alignment(Alignment::Left))
+            .title(Title::from("C").alignment(Alignment::Center))
+            .title(Title::from("R").alignment(Alignment::Right))
+            .title_padding(2)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──L───C────R──┐",
+            "│              │",
+            "└──────────────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_title_padding_truncates_colliding_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Block::new()
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .title(Title::from("Left").alignment(Alignment::Left))
+            .title(Title::from("Right").alignment(Alignment::Right))
+            .title_padding(2)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌──Left─R──┐"]));
+    }
+
+    #[test]
+    fn render_rounded_top_corners() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered()
+            .rounded_corners(RoundedCorners::TOP)
+            .title("Block")
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "╭Block╮",
+            "│     │",
+            "└─────┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::top_left(RoundedCorners::TOP_LEFT, ["╭──┐", "└──┘"])]
+    #[case::bottom_right(RoundedCorners::BOTTOM_RIGHT, ["┌──┐", "└──╯"])]
+    #[case::diagonal(RoundedCorners::TOP_RIGHT | RoundedCorners::BOTTOM_LEFT, ["┌──╮", "╰──┘"])]
+    #[case::all(RoundedCorners::ALL, ["╭──╮", "╰──╯"])]
+    fn render_rounded_corners(#[case] corners: RoundedCorners, #[case] expected: [&str; 2]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Block::bordered()
+            .rounded_corners(corners)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::thick(BorderType::Thick, ["┏━━┓", "┗━━┛"])]
+    #[case::double(BorderType::Double, ["╔══╗", "╚══╝"])]
+    fn render_rounded_corners_keeps_other_line_weights(
+        #[case] border_type: BorderType,
+        #[case] expected: [&str; 2],
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Block::bordered()
+            .border_type(border_type)
+            .rounded_corners(RoundedCorners::ALL)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_title_separator() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 3));
+        Block::bordered()
+            .title("Files")
+            .title("Edit")
+            .title_separator(" • ".red())
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Files • Edit─┐",
+            "│             │",
+            "└─────────────┘",
+        ]);
+        expected.set_style(Rect::new(6, 0, 3, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::left(Alignment::Left, "┌A|B|C───────┐")]
+    #[case::center(Alignment::Center, "┌───A|B|C────┐")]
+    #[case::right(Alignment::Right, "┌───────A|B|C┐")]
+    fn render_title_separator_alignments(#[case] alignment: Alignment, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        Block::new()
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .title_alignment(alignment)
+            .title("A")
+            .title("B")
+            .title("C")
+            .title_separator("|")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_title_separator_truncates_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Block::new()
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .title("Files")
+            .title("Edit")
+            .title_separator(" • ")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌Files • Ed┐"]));
+    }
+
     /// This is a regression test for bug <https://github.com/ratatui-org/ratatui/issues/929>
     #[test]
     fn render_right_aligned_empty_title() {
@@ -1232,12 +1902,116 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn border_style_for_top() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_style(Style::new().white())
+            .border_style_for(Borders::TOP, Style::new().red())
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌────────┐",
+            "│        │",
+            "└────────┘",
+        ]);
+        expected.set_style(Rect::new(0, 0, 10, 3), Style::new().white());
+        expected.set_style(Rect::new(1, 1, 8, 1), Style::reset());
+        // the top corners use the style of the top side
+        expected.set_style(Rect::new(0, 0, 10, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn border_style_for_multiple_sides() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_style(Style::new().bold())
+            .border_style_for(Borders::LEFT | Borders::RIGHT, Style::new().blue())
+            .border_style_for(Borders::BOTTOM, Style::new().green())
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌────────┐",
+            "│        │",
+            "└────────┘",
+        ]);
+        // the side styles are patched on top of the border style
+        expected.set_style(Rect::new(0, 0, 10, 3), Style::new().bold());
+        expected.set_style(Rect::new(1, 1, 8, 1), Style::reset());
+        // the top side isn't set, so the top corners use the style of the left and right sides
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().blue());
+        expected.set_style(Rect::new(9, 0, 1, 2), Style::new().blue());
+        expected.set_style(Rect::new(0, 2, 10, 1), Style::new().green());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+        Block::bordered()
+            .shadow(Style::new().on_dark_gray())
+            .render(Rect::new(1, 1, 5, 4), &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "       ",
+            " ┌──┐  ",
+            " │  │  ",
+            " └──┘  ",
+            "       ",
+        ]);
+        expected.set_style(Rect::new(5, 2, 1, 3), Style::new().on_dark_gray());
+        expected.set_style(Rect::new(2, 4, 3, 1), Style::new().on_dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow_within_area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        Block::bordered()
+            .shadow(Style::new().on_dark_gray())
+            .render(Rect::new(0, 0, 5, 3), &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌──┐  ",
+            "└──┘  ",
+            "      ",
+            "      ",
+        ]);
+        expected.set_style(Rect::new(4, 1, 1, 2), Style::new().on_dark_gray());
+        expected.set_style(Rect::new(1, 2, 3, 1), Style::new().on_dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_shadow_clipped_to_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Block::bordered()
+            .shadow(Style::new().on_dark_gray())
+            .render(Rect::new(0, 0, 6, 4), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┐",
+            "│   │",
+            "└───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn inner_leaves_room_for_shadow() {
+        let block = Block::bordered().shadow(Style::new().on_dark_gray());
+        assert_eq!(block.inner(Rect::new(0, 0, 6, 4)), Rect::new(1, 1, 3, 1));
+    }
+
     #[test]
     fn border_type_to_string() {
         assert_eq!(format!("{}", BorderType::Plain), "Plain");
         assert_eq!(format!("{}", BorderType::Rounded), "Rounded");
         assert_eq!(format!("{}", BorderType::Double), "Double");
         assert_eq!(format!("{}", BorderType::Thick), "Thick");
+        assert_eq!(format!("{}", BorderType::Dashed), "Dashed");
+        assert_eq!(format!("{}", BorderType::DashedThick), "DashedThick");
     }
 
     #[test]
@@ -1246,6 +2020,8 @@ mod tests {
         assert_eq!("Rounded".parse(), Ok(BorderType::Rounded));
         assert_eq!("Double".parse(), Ok(BorderType::Double));
         assert_eq!("Thick".parse(), Ok(BorderType::Thick));
+        assert_eq!("Dashed".parse(), Ok(BorderType::Dashed));
+        assert_eq!("DashedThick".parse(), Ok(BorderType::DashedThick));
         assert_eq!("".parse::<BorderType>(), Err(ParseError::VariantNotFound));
     }
 
@@ -1339,6 +2115,36 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_dashed_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_type(BorderType::Dashed)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌┄┄┄┄┄┄┄┄┐",
+            "┆        ┆",
+            "└┄┄┄┄┄┄┄┄┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_dashed_thick_border() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Block::bordered()
+            .border_type(BorderType::DashedThick)
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┏┅┅┅┅┅┅┅┅┓",
+            "┇        ┇",
+            "┗┅┅┅┅┅┅┅┅┛",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_custom_border_set() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1,3 +1,5 @@
+use crate::layout::Rect;
+
 /// Defines the padding for a [`Block`].
 ///
 /// See the [`padding`] method of [`Block`] to configure its padding.
@@ -104,6 +106,39 @@ impl Padding {
         }
     }
 
+    /// Creates a `Padding` that is a percentage of the size of `area`.
+    ///
+    /// `horizontal` is the percentage of the width of `area` used for `left` and `right`, and
+    /// `vertical` the percentage of its height used for `top` and `bottom`. The values are rounded
+    /// down and the percentages are clamped to 50%, so that the padding on both sides never
+    /// exceeds the size of the area.
+    ///
+    /// To resolve the padding against the area of a [`Block`] when it is rendered, use
+    /// [`Block::padding_percentage`].
+    ///
+    /// [`Block`]: crate::widgets::Block
+    /// [`Block::padding_percentage`]: crate::widgets::Block::padding_percentage
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let padding = Padding::percentage(10, 25, Rect::new(0, 0, 45, 10));
+    /// assert_eq!(padding, Padding::new(4, 4, 2, 2));
+    /// ```
+    pub const fn percentage(horizontal: u16, vertical: u16, area: Rect) -> Self {
+        let x = Self::percent_of(horizontal, area.width);
+        let y = Self::percent_of(vertical, area.height);
+        Self::symmetric(x, y)
+    }
+
+    /// Returns `percent` percent of `value`, rounded down, with `percent` clamped to 50
+    #[allow(clippy::cast_possible_truncation)]
+    const fn percent_of(percent: u16, value: u16) -> u16 {
+        let percent = if percent > 50 { 50 } else { percent };
+        (value as u32 * percent as u32 / 100) as u16
+    }
+
     /// Creates a `Padding` that is symmetric.
     ///
     /// The `x` value is used for `left` and `right` and the `y` value is used for `top` and
@@ -188,6 +223,26 @@ mod tests {
         assert_eq!(Padding::bottom(1), Padding::new(0, 0, 0, 1));
     }
 
+    #[test]
+    fn percentage() {
+        let area = Rect::new(0, 0, 45, 10);
+        assert_eq!(Padding::percentage(10, 25, area), Padding::new(4, 4, 2, 2));
+        assert_eq!(Padding::percentage(0, 0, area), Padding::ZERO);
+        // the percentages are clamped so that both sides fit in the area
+        assert_eq!(
+            Padding::percentage(50, 50, area),
+            Padding::new(22, 22, 5, 5)
+        );
+        assert_eq!(
+            Padding::percentage(80, 100, area),
+            Padding::new(22, 22, 5, 5)
+        );
+        assert_eq!(
+            Padding::percentage(50, 50, Rect::new(0, 0, u16::MAX, 1)),
+            Padding::new(32767, 32767, 0, 0)
+        );
+    }
+
     #[test]
     const fn can_be_const() {
         const _PADDING: Padding = Padding::new(1, 1, 1, 1);
@@ -200,5 +255,6 @@ mod tests {
         const _RIGHT: Padding = Padding::right(1);
         const _TOP: Padding = Padding::top(1);
         const _BOTTOM: Padding = Padding::bottom(1);
+        const _PERCENTAGE: Padding = Padding::percentage(10, 10, Rect::ZERO);
     }
 }
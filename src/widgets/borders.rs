@@ -21,6 +21,31 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Bitflags that can be composed to set the corners of a block that are rounded.
+    ///
+    /// See [`Block::rounded_corners`](crate::widgets::Block::rounded_corners).
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct RoundedCorners: u8 {
+        /// No rounded corner (default)
+        const NONE         = 0b0000;
+        /// Round the top left corner
+        const TOP_LEFT     = 0b0001;
+        /// Round the top right corner
+        const TOP_RIGHT    = 0b0010;
+        /// Round the bottom right corner
+        const BOTTOM_RIGHT = 0b0100;
+        /// Round the bottom left corner
+        const BOTTOM_LEFT  = 0b1000;
+        /// Round both top corners
+        const TOP = Self::TOP_LEFT.bits() | Self::TOP_RIGHT.bits();
+        /// Round both bottom corners
+        const BOTTOM = Self::BOTTOM_LEFT.bits() | Self::BOTTOM_RIGHT.bits();
+        /// Round all corners
+        const ALL = Self::TOP.bits() | Self::BOTTOM.bits();
+    }
+}
+
 /// Implement the `Debug` trait for the `Borders` bitflags. This is a manual implementation to
 /// display the flags in a more readable way. The default implementation would display the
 /// flags as 'Border(0x0)' for `Borders::NONE` for example.
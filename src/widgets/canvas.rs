@@ -5,33 +5,73 @@
 //!
 //! The available shapes are:
 //!
+//! - [`Axes`]: The `x = 0` and `y = 0` lines
 //! - [`Circle`]: A basic circle
+//! - [`Grid`]: Gridlines at regular intervals
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
+//! - [`Pixmap`]: A small image
 //! - [`Points`]: A scatter of points
+//! - [`Polyline`]: A path of connected lines
 //! - [`Rectangle`]: A basic rectangle
 //!
 //! You can also implement your own custom [`Shape`]s.
 mod circle;
+mod grid;
 mod line;
 mod map;
+mod pixmap;
 mod points;
+mod polyline;
 mod rectangle;
 mod world;
 
 use std::{fmt, iter::zip};
 
 use itertools::Itertools;
+use strum::{Display, EnumString};
 
 pub use self::{
     circle::Circle,
+    grid::{Axes, Grid},
     line::Line,
     map::{Map, MapResolution},
+    pixmap::Pixmap,
     points::Points,
+    polyline::Polyline,
     rectangle::Rectangle,
 };
 use crate::{prelude::*, symbols::Marker, text::Line as TextLine, widgets::Block};
 
+/// Defines how finely the shapes drawn on a [`Canvas`] are sampled.
+///
+/// Shapes are normally rasterized at the resolution of the grid of the [marker](Canvas::marker),
+/// e.g. 2x4 dots per cell for [`Marker::Braille`]. Higher resolutions rasterize the shapes on a
+/// finer virtual grid and downsample each point to the dot it falls in. Lines and curves then
+/// touch every dot they pass through, instead of only one dot per step, which makes them look
+/// smoother and more continuous, especially with [`Marker::Braille`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ResolutionHint {
+    /// Sample the shapes at the resolution of the grid
+    #[default]
+    Normal,
+    /// Sample the shapes at twice the resolution of the grid in each direction
+    Double,
+    /// Sample the shapes at four times the resolution of the grid in each direction
+    Quadruple,
+}
+
+impl ResolutionHint {
+    /// Returns the number of samples per dot of the grid in each direction
+    pub const fn factor(self) -> usize {
+        match self {
+            Self::Normal => 1,
+            Self::Double => 2,
+            Self::Quadruple => 4,
+        }
+    }
+}
+
 /// Something that can be drawn on a [`Canvas`].
 ///
 /// You may implement your own canvas custom widgets by implementing this trait.
@@ -64,13 +104,25 @@ struct Layer {
     colors: Vec<(Color, Color)>,
 }
 
+impl Layer {
+    /// Returns the index, character and colors of each cell that was painted on this layer
+    fn painted_cells(&self) -> impl Iterator<Item = (usize, char, (Color, Color))> + '_ {
+        self.string
+            .chars()
+            .zip(self.colors.iter().copied())
+            .enumerate()
+            .filter(|(_, (ch, _))| *ch != ' ' && *ch != '\u{2800}')
+            .map(|(index, (ch, colors))| (index, ch, colors))
+    }
+}
+
 /// A grid of cells that can be painted on.
 ///
 /// The grid represents a particular screen region measured in rows and columns. The underlying
 /// resolution of the grid might exceed the number of rows and columns. For example, a grid of
 /// Braille patterns will have a resolution of 2x4 dots per cell. This means that a grid of 10x10
 /// cells will have a resolution of 20x40 dots.
-trait Grid: fmt::Debug {
+trait MarkerGrid: fmt::Debug {
     /// Get the resolution of the grid in number of dots.
     ///
     /// This doesn't have to be the same as the number of rows and columns of the grid. For example,
@@ -82,7 +134,7 @@ trait Grid: fmt::Debug {
     /// The point is expressed in number of dots starting at the origin of the grid in the top left
     /// corner. Note that this is not the same as the `(x, y)` coordinates of the canvas.
     fn paint(&mut self, x: usize, y: usize, color: Color);
-    /// Save the current state of the [`Grid`] as a layer to be rendered
+    /// Save the current state of the [`MarkerGrid`] as a layer to be rendered
     fn save(&self) -> Layer;
     /// Reset the grid to its initial state
     fn reset(&mut self);
@@ -126,7 +178,7 @@ impl BrailleGrid {
     }
 }
 
-impl Grid for BrailleGrid {
+impl MarkerGrid for BrailleGrid {
     fn resolution(&self) -> (f64, f64) {
         (f64::from(self.width) * 2.0, f64::from(self.height) * 4.0)
     }
@@ -189,7 +241,7 @@ impl CharGrid {
     }
 }
 
-impl Grid for CharGrid {
+impl MarkerGrid for CharGrid {
     fn resolution(&self) -> (f64, f64) {
         (f64::from(self.width), f64::from(self.height))
     }
@@ -254,7 +306,7 @@ impl HalfBlockGrid {
     }
 }
 
-impl Grid for HalfBlockGrid {
+impl MarkerGrid for HalfBlockGrid {
     fn resolution(&self) -> (f64, f64) {
         (f64::from(self.width), f64::from(self.height) * 2.0)
     }
@@ -337,6 +389,8 @@ impl Grid for HalfBlockGrid {
 pub struct Painter<'a, 'b> {
     context: &'a mut Context<'b>,
     resolution: (f64, f64),
+    /// Number of samples per dot of the grid in each direction
+    sampling: usize,
 }
 
 impl<'a, 'b> Painter<'a, 'b> {
@@ -377,6 +431,21 @@ impl<'a, 'b> Painter<'a, 'b> {
     /// assert_eq!(point, Some((0, 0)));
     /// ```
     pub fn get_point(&self, x: f64, y: f64) -> Option<(usize, usize)> {
+        self.locate(x, y, self.resolution)
+    }
+
+    /// Convert the `(x, y)` coordinates to the location of a sample on the finer grid that shapes
+    /// are rasterized on at the [resolution](Canvas::resolution) of the canvas
+    ///
+    /// Samples are painted with [`Painter::paint_sample`].
+    pub(crate) fn get_sample(&self, x: f64, y: f64) -> Option<(usize, usize)> {
+        let sampling = self.sampling as f64;
+        let resolution = (self.resolution.0 * sampling, self.resolution.1 * sampling);
+        self.locate(x, y, resolution)
+    }
+
+    /// Convert the `(x, y)` coordinates to a location on a grid of the given resolution
+    fn locate(&self, x: f64, y: f64, resolution: (f64, f64)) -> Option<(usize, usize)> {
         let left = self.context.x_bounds[0];
         let right = self.context.x_bounds[1];
         let top = self.context.y_bounds[1];
@@ -389,11 +458,41 @@ impl<'a, 'b> Painter<'a, 'b> {
         if width == 0.0 || height == 0.0 {
             return None;
         }
-        let x = ((x - left) * (self.resolution.0 - 1.0) / width) as usize;
-        let y = ((top - y) * (self.resolution.1 - 1.0) / height) as usize;
+        let x = ((x - left) * (resolution.0 - 1.0) / width) as usize;
+        let y = ((top - y) * (resolution.1 - 1.0) / height) as usize;
         Some((x, y))
     }
 
+    /// The `x` bounds of the canvas, as left and right
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// let mut ctx = Context::new(1, 1, [0.0, 2.0], [-1.0, 1.0], symbols::Marker::Braille);
+    /// let painter = Painter::from(&mut ctx);
+    /// assert_eq!(painter.x_bounds(), [0.0, 2.0]);
+    /// ```
+    pub fn x_bounds(&self) -> [f64; 2] {
+        self.context.x_bounds
+    }
+
+    /// The `y` bounds of the canvas, as bottom and top
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// let mut ctx = Context::new(1, 1, [0.0, 2.0], [-1.0, 1.0], symbols::Marker::Braille);
+    /// let painter = Painter::from(&mut ctx);
+    /// assert_eq!(painter.y_bounds(), [-1.0, 1.0]);
+    /// ```
+    pub fn y_bounds(&self) -> [f64; 2] {
+        self.context.y_bounds
+    }
+
     /// Paint a point of the grid
     ///
     /// # Example
@@ -408,14 +507,21 @@ impl<'a, 'b> Painter<'a, 'b> {
     pub fn paint(&mut self, x: usize, y: usize, color: Color) {
         self.context.grid.paint(x, y, color);
     }
+
+    /// Paint the point of the grid that a sample returned by [`Painter::get_sample`] falls in
+    pub(crate) fn paint_sample(&mut self, x: usize, y: usize, color: Color) {
+        self.paint(x / self.sampling, y / self.sampling, color);
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
     fn from(context: &'a mut Context<'b>) -> Self {
+        let sampling = context.resolution.factor();
         let resolution = context.grid.resolution();
         Self {
             context,
             resolution,
+            sampling,
         }
     }
 }
@@ -428,9 +534,12 @@ impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
 /// [`Frame`]: crate::prelude::Frame
 #[derive(Debug)]
 pub struct Context<'a> {
+    width: u16,
+    height: u16,
     x_bounds: [f64; 2],
     y_bounds: [f64; 2],
-    grid: Box<dyn Grid>,
+    grid: Box<dyn MarkerGrid>,
+    resolution: ResolutionHint,
     dirty: bool,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
@@ -469,7 +578,7 @@ impl<'a> Context<'a> {
         let dot = symbols::DOT.chars().next().unwrap();
         let block = symbols::block::FULL.chars().next().unwrap();
         let bar = symbols::bar::HALF.chars().next().unwrap();
-        let grid: Box<dyn Grid> = match marker {
+        let grid: Box<dyn MarkerGrid> = match marker {
             Marker::Dot => Box::new(CharGrid::new(width, height, dot)),
             Marker::Block => Box::new(CharGrid::new(width, height, block)),
             Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
@@ -477,9 +586,12 @@ impl<'a> Context<'a> {
             Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
         };
         Self {
+            width,
+            height,
             x_bounds,
             y_bounds,
             grid,
+            resolution: ResolutionHint::Normal,
             dirty: false,
             layers: Vec::new(),
             labels: Vec::new(),
@@ -524,6 +636,36 @@ impl<'a> Context<'a> {
         });
     }
 
+    /// Returns the characters and foreground colors painted so far as a matrix of rows.
+    ///
+    /// All layers are resolved in order, including the one currently being drawn, so each cell
+    /// holds what the [`Canvas`] would render there. Cells that were not painted are `' '` with
+    /// [`Color::Reset`]. Labels added with [`Context::print`] are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// let mut ctx = Context::new(2, 1, [0.0, 1.0], [0.0, 1.0], symbols::Marker::Block);
+    /// ctx.draw(&Points {
+    ///     coords: &[(0.0, 0.0)],
+    ///     color: Color::Red,
+    /// });
+    /// assert_eq!(ctx.snapshot(), vec![vec![('█', Color::Red), (' ', Color::Reset)]]);
+    /// ```
+    pub fn snapshot(&self) -> Vec<Vec<(char, Color)>> {
+        let width = usize::from(self.width);
+        let mut snapshot = vec![vec![(' ', Color::Reset); width]; usize::from(self.height)];
+        let current = self.dirty.then(|| self.grid.save());
+        for layer in self.layers.iter().chain(current.as_ref()) {
+            for (index, ch, (fg, _)) in layer.painted_cells() {
+                snapshot[index / width][index % width] = (ch, fg);
+            }
+        }
+        snapshot
+    }
+
     /// Save the last layer if necessary
     fn finish(&mut self) {
         if self.dirty {
@@ -605,6 +747,7 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: Marker,
+    resolution: ResolutionHint,
 }
 
 impl<'a, F> Default for Canvas<'a, F>
@@ -619,6 +762,7 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: Marker::Braille,
+            resolution: ResolutionHint::Normal,
         }
     }
 }
@@ -721,6 +865,49 @@ where
         self.marker = marker;
         self
     }
+
+    /// Change how finely the shapes are sampled before being drawn on the grid of the marker.
+    ///
+    /// The default is [`ResolutionHint::Normal`]. Higher resolutions make lines and curves
+    /// smoother, at the cost of more points to compute. See [`ResolutionHint`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// Canvas::default()
+    ///     .marker(symbols::Marker::Braille)
+    ///     .resolution(ResolutionHint::Quadruple)
+    ///     .paint(|ctx| {});
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn resolution(mut self, resolution: ResolutionHint) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+impl<F> Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    /// Paints the canvas on a grid of the given size and returns the resolved cells.
+    ///
+    /// This runs the paint closure the same way rendering does, without needing a [`Buffer`], and
+    /// returns the result of [`Context::snapshot`]. This is useful to assert the exact plotted
+    /// output in tests or to draw the canvas with a different renderer. The block, the background
+    /// color and the labels are not included.
+    pub fn snapshot(&self, width: u16, height: u16) -> Vec<Vec<(char, Color)>> {
+        let mut ctx = Context::new(width, height, self.x_bounds, self.y_bounds, self.marker);
+        ctx.resolution = self.resolution;
+        if let Some(ref painter) = self.paint_func {
+            painter(&mut ctx);
+        }
+        ctx.snapshot()
+    }
 }
 
 impl<F> Widget for Canvas<'_, F>
@@ -759,25 +946,24 @@ where
             self.y_bounds,
             self.marker,
         );
+        ctx.resolution = self.resolution;
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
 
         // Retrieve painted points for each layer
-        for layer in ctx.layers {
-            for (index, (ch, colors)) in layer.string.chars().zip(layer.colors).enumerate() {
-                if ch != ' ' && ch != '\u{2800}' {
-                    let (x, y) = (
-                        (index % width) as u16 + canvas_area.left(),
-                        (index / width) as u16 + canvas_area.top(),
-                    );
-                    let cell = buf.get_mut(x, y).set_char(ch);
-                    if colors.0 != Color::Reset {
-                        cell.set_fg(colors.0);
-                    }
-                    if colors.1 != Color::Reset {
-                        cell.set_bg(colors.1);
-                    }
+        for layer in &ctx.layers {
+            for (index, ch, colors) in layer.painted_cells() {
+                let (x, y) = (
+                    (index % width) as u16 + canvas_area.left(),
+                    (index / width) as u16 + canvas_area.top(),
+                );
+                let cell = buf.get_mut(x, y).set_char(ch);
+                if colors.0 != Color::Reset {
+                    cell.set_fg(colors.0);
+                }
+                if colors.1 != Color::Reset {
+                    cell.set_bg(colors.1);
                 }
             }
         }
@@ -903,4 +1089,101 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn snapshot_of_painted_line() {
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 2.0])
+            .paint(|ctx| {
+                ctx.draw(&Line::new(0.0, 0.0, 2.0, 2.0, Color::Red));
+                ctx.layer();
+                ctx.draw(&Line::new(0.0, 2.0, 1.0, 2.0, Color::Blue));
+            });
+        let blank = (' ', Color::Reset);
+        let red = ('█', Color::Red);
+        let blue = ('█', Color::Blue);
+        assert_eq!(
+            canvas.snapshot(3, 3),
+            vec![
+                vec![blue, blue, red],
+                vec![blank, red, blank],
+                vec![red, blank, blank],
+            ]
+        );
+    }
+
+    #[test]
+    fn snapshot_of_braille_grid() {
+        let mut ctx = Context::new(2, 1, [0.0, 3.0], [0.0, 3.0], Marker::Braille);
+        assert_eq!(ctx.snapshot(), vec![vec![(' ', Color::Reset); 2]]);
+        ctx.draw(&Line::new(0.0, 0.0, 1.0, 3.0, Color::Green));
+        assert_eq!(
+            ctx.snapshot(),
+            vec![vec![('⡜', Color::Green), (' ', Color::Reset)]]
+        );
+    }
+
+    #[test]
+    fn higher_resolution_sets_more_dots_on_diagonal_line() {
+        let dots = |resolution| {
+            Canvas::default()
+                .x_bounds([0.0, 10.0])
+                .y_bounds([0.0, 10.0])
+                .resolution(resolution)
+                .paint(|ctx| ctx.draw(&Line::new(0.0, 0.0, 10.0, 10.0, Color::Red)))
+                .snapshot(5, 2)
+                .into_iter()
+                .flatten()
+                .map(|(c, _)| u32::from(c).saturating_sub(0x2800).count_ones())
+                .sum::<u32>()
+        };
+        // the line crosses 10 columns of dots, at a higher resolution it also touches the dots it
+        // passes through between two steps
+        assert_eq!(dots(ResolutionHint::Normal), 10);
+        assert_eq!(dots(ResolutionHint::Double), 14);
+        assert_eq!(dots(ResolutionHint::Quadruple), 16);
+    }
+
+    #[test]
+    fn higher_resolution_paints_in_grid_coordinates() {
+        struct Dot;
+        impl Shape for Dot {
+            fn draw(&self, painter: &mut Painter) {
+                painter.paint(1, 3, Color::Red);
+            }
+        }
+        let snapshot = |resolution| {
+            Canvas::default()
+                .resolution(resolution)
+                .paint(|ctx| ctx.draw(&Dot))
+                .snapshot(1, 1)
+        };
+        assert_eq!(
+            snapshot(ResolutionHint::Normal),
+            vec![vec![('⢀', Color::Red)]]
+        );
+        assert_eq!(
+            snapshot(ResolutionHint::Quadruple),
+            vec![vec![('⢀', Color::Red)]]
+        );
+    }
+
+    #[test]
+    fn higher_resolution_keeps_line_end_points() {
+        let snapshot = |resolution| {
+            Canvas::default()
+                .x_bounds([0.0, 1.0])
+                .y_bounds([0.0, 1.0])
+                .marker(Marker::Block)
+                .resolution(resolution)
+                .paint(|ctx| ctx.draw(&Line::new(0.0, 0.0, 1.0, 0.0, Color::Red)))
+                .snapshot(3, 2)
+        };
+        assert_eq!(
+            snapshot(ResolutionHint::Quadruple),
+            snapshot(ResolutionHint::Normal)
+        );
+    }
 }
@@ -0,0 +1,218 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Line, Painter, Shape},
+};
+
+/// Gridlines drawn across a [`Canvas`](super::Canvas) at regular intervals
+///
+/// Vertical lines are drawn at every multiple of `x_step` and horizontal lines at every multiple
+/// of `y_step` that lies within the bounds of the canvas. The steps are expressed in the coordinate
+/// system of the canvas, not in terminal cells. A step that is not strictly positive draws no
+/// lines in that direction. A step smaller than the distance between two points of the canvas is
+/// rounded up to a multiple of itself, so that at most one line is drawn per point.
+///
+/// Draw the grid before the other shapes, or in a previous [layer](super::Context::layer), so
+/// that the data is drawn over it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Grid {
+    /// The interval between two vertical lines
+    pub x_step: f64,
+    /// The interval between two horizontal lines
+    pub y_step: f64,
+    /// The color of the lines
+    pub color: Color,
+}
+
+impl Grid {
+    /// Create a new grid with the given intervals and color
+    pub const fn new(x_step: f64, y_step: f64, color: Color) -> Self {
+        Self {
+            x_step,
+            y_step,
+            color,
+        }
+    }
+}
+
+impl Shape for Grid {
+    fn draw(&self, painter: &mut Painter) {
+        let [left, right] = painter.x_bounds();
+        let [bottom, top] = painter.y_bounds();
+        let (columns, rows) = painter.resolution;
+        for x in multiples(self.x_step, left, right, columns) {
+            Line::new(x, bottom, x, top, self.color).draw(painter);
+        }
+        for y in multiples(self.y_step, bottom, top, rows) {
+            Line::new(left, y, right, y, self.color).draw(painter);
+        }
+    }
+}
+
+/// The `x = 0` and `y = 0` lines of a [`Canvas`](super::Canvas)
+///
+/// Each line is only drawn when it lies within the bounds of the canvas.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Axes {
+    /// The color of the lines
+    pub color: Color,
+}
+
+impl Axes {
+    /// Create new axes with the given color
+    pub const fn new(color: Color) -> Self {
+        Self { color }
+    }
+}
+
+impl Shape for Axes {
+    fn draw(&self, painter: &mut Painter) {
+        let [left, right] = painter.x_bounds();
+        let [bottom, top] = painter.y_bounds();
+        if (left..=right).contains(&0.0) {
+            Line::new(0.0, bottom, 0.0, top, self.color).draw(painter);
+        }
+        if (bottom..=top).contains(&0.0) {
+            Line::new(left, 0.0, right, 0.0, self.color).draw(painter);
+        }
+    }
+}
+
+/// Returns the multiples of `step` between `min` and `max`, both included
+///
+/// `points` is the number of points of the canvas between `min` and `max`. Steps smaller than
+/// the distance between two points are skipped, so at most `points` values are returned.
+fn multiples(step: f64, min: f64, max: f64, points: f64) -> impl Iterator<Item = f64> {
+    let point = (max - min) / (points - 1.0).max(1.0);
+    let step = if step > 0.0 && step < point {
+        // the rounded step can overflow when the bounds are huge compared to the step
+        Some(step * (point / step).ceil())
+            .filter(|step| step.is_finite())
+            .unwrap_or(point)
+    } else {
+        step
+    };
+    let (first, last) = if step > 0.0 && step.is_finite() && min <= max {
+        ((min / step).ceil() as i64, (max / step).floor() as i64)
+    } else {
+        (1, 0)
+    };
+    (first..=last)
+        .map(move |i| i as f64 * step)
+        .take(points.max(1.0) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, symbols::Marker, widgets::canvas::Canvas};
+
+    #[test]
+    fn draw_grid() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|context| {
+                context.draw(&Grid::new(3.0, 2.0, Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "██████████",
+            "█  █  █  █",
+            "██████████",
+            "█  █  █  █",
+            "██████████",
+        ]);
+        expected.set_style(buffer.area, Style::new().red());
+        for y in [1, 3] {
+            for x in [1, 2, 4, 5, 7, 8] {
+                expected.get_mut(x, y).set_style(Style::reset());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_grid_without_steps() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 3.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| {
+                context.draw(&Grid::new(0.0, -1.0, Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["    "; 2]);
+        expected.set_style(buffer.area, Style::reset());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_grid_with_steps_smaller_than_a_cell() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| {
+                context.draw(&Grid::new(f64::MIN_POSITIVE, 0.0, Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["██████████"]);
+        expected.set_style(buffer.area, Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn multiples_are_capped_to_the_resolution() {
+        assert_eq!(
+            multiples(0.5, 0.0, 4.0, 5.0).collect::<Vec<_>>(),
+            [0.0, 1.0, 2.0, 3.0, 4.0]
+        );
+        assert_eq!(multiples(1e-300, -1e300, 1e300, 3.0).count(), 3);
+        assert_eq!(multiples(f64::INFINITY, 0.0, 1.0, 3.0).count(), 0);
+    }
+
+    #[test]
+    fn draw_axes() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([-4.0, 5.0])
+            .y_bounds([-2.0, 2.0])
+            .paint(|context| {
+                context.draw(&Axes::new(Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "    █     ",
+            "    █     ",
+            "██████████",
+            "    █     ",
+            "    █     ",
+        ]);
+        expected.set_style(buffer.area, Style::reset());
+        expected.set_style(Rect::new(4, 0, 1, 5), Style::new().red());
+        expected.set_style(Rect::new(0, 2, 10, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_axes_outside_of_bounds() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([1.0, 4.0])
+            .y_bounds([-1.0, 0.0])
+            .paint(|context| {
+                context.draw(&Axes::new(Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["████", "    "]);
+        expected.set_style(buffer.area, Style::reset());
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+}
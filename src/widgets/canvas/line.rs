@@ -33,42 +33,52 @@ impl Line {
 
 impl Shape for Line {
     fn draw(&self, painter: &mut Painter) {
-        let Some((x1, y1)) = painter.get_point(self.x1, self.y1) else {
+        let Some((x1, y1)) = painter.get_sample(self.x1, self.y1) else {
             return;
         };
-        let Some((x2, y2)) = painter.get_point(self.x2, self.y2) else {
+        let Some((x2, y2)) = painter.get_sample(self.x2, self.y2) else {
             return;
         };
-        let (dx, x_range) = if x2 >= x1 {
-            (x2 - x1, x1..=x2)
-        } else {
-            (x1 - x2, x2..=x1)
-        };
-        let (dy, y_range) = if y2 >= y1 {
-            (y2 - y1, y1..=y2)
-        } else {
-            (y1 - y2, y2..=y1)
-        };
+        draw_grid_line(painter, (x1, y1), (x2, y2), self.color);
+    }
+}
 
-        if dx == 0 {
-            for y in y_range {
-                painter.paint(x1, y, self.color);
-            }
-        } else if dy == 0 {
-            for x in x_range {
-                painter.paint(x, y1, self.color);
-            }
-        } else if dy < dx {
-            if x1 > x2 {
-                draw_line_low(painter, x2, y2, x1, y1, self.color);
-            } else {
-                draw_line_low(painter, x1, y1, x2, y2, self.color);
-            }
-        } else if y1 > y2 {
-            draw_line_high(painter, x2, y2, x1, y1, self.color);
+/// Draws a line between two samples of the painter
+pub(super) fn draw_grid_line(
+    painter: &mut Painter,
+    (x1, y1): (usize, usize),
+    (x2, y2): (usize, usize),
+    color: Color,
+) {
+    let (dx, x_range) = if x2 >= x1 {
+        (x2 - x1, x1..=x2)
+    } else {
+        (x1 - x2, x2..=x1)
+    };
+    let (dy, y_range) = if y2 >= y1 {
+        (y2 - y1, y1..=y2)
+    } else {
+        (y1 - y2, y2..=y1)
+    };
+
+    if dx == 0 {
+        for y in y_range {
+            painter.paint_sample(x1, y, color);
+        }
+    } else if dy == 0 {
+        for x in x_range {
+            painter.paint_sample(x, y1, color);
+        }
+    } else if dy < dx {
+        if x1 > x2 {
+            draw_line_low(painter, x2, y2, x1, y1, color);
         } else {
-            draw_line_high(painter, x1, y1, x2, y2, self.color);
+            draw_line_low(painter, x1, y1, x2, y2, color);
         }
+    } else if y1 > y2 {
+        draw_line_high(painter, x2, y2, x1, y1, color);
+    } else {
+        draw_line_high(painter, x1, y1, x2, y2, color);
     }
 }
 
@@ -78,7 +88,7 @@ fn draw_line_low(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usi
     let mut d = 2 * dy - dx;
     let mut y = y1;
     for x in x1..=x2 {
-        painter.paint(x, y, color);
+        painter.paint_sample(x, y, color);
         if d > 0 {
             y = if y1 > y2 {
                 y.saturating_sub(1)
@@ -97,7 +107,7 @@ fn draw_line_high(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: us
     let mut d = 2 * dx - dy;
     let mut x = x1;
     for y in y1..=y2 {
-        painter.paint(x, y, color);
+        painter.paint_sample(x, y, color);
         if d > 0 {
             x = if x1 > x2 {
                 x.saturating_sub(1)
@@ -0,0 +1,120 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Painter, Shape},
+};
+
+/// A small image to draw on a [`Canvas`](super::Canvas)
+///
+/// Each pixel of the image is painted on one point of the grid of the canvas, starting from the
+/// point where `top_left` falls. The image is therefore best drawn with
+/// [`Marker::HalfBlock`](crate::symbols::Marker::HalfBlock), which paints two pixels arranged
+/// vertically in each cell using the `▀` symbol with the upper pixel as foreground and the lower
+/// pixel as background color. When the image has an odd height, the lower half of the last row of
+/// cells keeps the background color.
+///
+/// The pixels are stored row by row, from the top left corner. [`Color::Reset`] pixels are
+/// transparent. Pixels that fall outside of the canvas are not drawn, and nothing is drawn when
+/// `top_left` is outside of the bounds of the canvas.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Pixmap {
+    /// The number of pixels in each row
+    pub width: usize,
+    /// The number of rows of pixels
+    pub height: usize,
+    /// The colors of the pixels, row by row
+    pub pixels: Vec<Color>,
+    /// The position of the top left corner of the image in the coordinate system of the canvas
+    pub top_left: (f64, f64),
+}
+
+impl Pixmap {
+    /// Create a new image of the given size placed at `top_left`
+    pub const fn new(
+        width: usize,
+        height: usize,
+        pixels: Vec<Color>,
+        top_left: (f64, f64),
+    ) -> Self {
+        Self {
+            width,
+            height,
+            pixels,
+            top_left,
+        }
+    }
+}
+
+impl Shape for Pixmap {
+    fn draw(&self, painter: &mut Painter) {
+        if self.width == 0 {
+            return;
+        }
+        let Some((left, top)) = painter.get_point(self.top_left.0, self.top_left.1) else {
+            return;
+        };
+        let (columns, rows) = (painter.resolution.0 as usize, painter.resolution.1 as usize);
+        for (row, pixels) in self.pixels.chunks(self.width).take(self.height).enumerate() {
+            let y = top + row;
+            if y >= rows {
+                break;
+            }
+            for (column, &color) in pixels.iter().enumerate() {
+                let x = left + column;
+                if x >= columns {
+                    break;
+                }
+                if color != Color::Reset {
+                    painter.paint(x, y, color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, symbols::Marker, widgets::canvas::Canvas};
+
+    fn render(pixmap: &Pixmap, area: Rect) -> Buffer {
+        let mut buffer = Buffer::empty(area);
+        Canvas::default()
+            .marker(Marker::HalfBlock)
+            .x_bounds([0.0, f64::from(area.width - 1)])
+            .y_bounds([0.0, f64::from(area.height * 2 - 1)])
+            .paint(|context| context.draw(pixmap))
+            .render(area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn draw_checkerboard() {
+        let pixels = vec![Color::Red, Color::Blue, Color::Blue, Color::Red];
+        let pixmap = Pixmap::new(2, 2, pixels, (0.0, 1.0));
+        let buffer = render(&pixmap, Rect::new(0, 0, 2, 1));
+        let mut expected = Buffer::with_lines(["▀▀"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().on_blue());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().blue().on_red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_odd_height() {
+        let pixmap = Pixmap::new(2, 1, vec![Color::Red, Color::Blue], (0.0, 1.0));
+        let buffer = render(&pixmap, Rect::new(0, 0, 2, 1));
+        let mut expected = Buffer::with_lines(["▀▀"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::reset().red());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::reset().blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_clipped_to_canvas() {
+        let pixmap = Pixmap::new(3, 3, vec![Color::Red; 9], (1.0, 0.0));
+        let buffer = render(&pixmap, Rect::new(0, 0, 3, 1));
+        let mut expected = Buffer::with_lines([" ▄▄"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::reset());
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::reset().red());
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,184 @@
+use itertools::Itertools;
+
+use crate::{
+    style::Color,
+    widgets::canvas::{line::draw_grid_line, Painter, Shape},
+};
+
+/// A path of connected line segments with the given color
+///
+/// Each point is joined to the next one by a straight line. A single point is drawn as a dot.
+/// Segments that go outside of the bounds of the canvas are clipped to the bounds.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polyline {
+    /// Points of the path, in order
+    pub points: Vec<(f64, f64)>,
+    /// Color of the path
+    pub color: Color,
+}
+
+impl Polyline {
+    /// Create a new polyline through the given points with the given color
+    pub fn new<P: Into<Vec<(f64, f64)>>>(points: P, color: Color) -> Self {
+        Self {
+            points: points.into(),
+            color,
+        }
+    }
+}
+
+impl Shape for Polyline {
+    fn draw(&self, painter: &mut Painter) {
+        if let [(x, y)] = self.points[..] {
+            if let Some((x, y)) = painter.get_point(x, y) {
+                painter.paint(x, y, self.color);
+            }
+            return;
+        }
+        let (x_bounds, y_bounds) = (painter.x_bounds(), painter.y_bounds());
+        for (&start, &end) in self.points.iter().tuple_windows() {
+            let Some((start, end)) = clip_segment(start, end, x_bounds, y_bounds) else {
+                continue;
+            };
+            let Some(start) = painter.get_sample(start.0, start.1) else {
+                continue;
+            };
+            let Some(end) = painter.get_sample(end.0, end.1) else {
+                continue;
+            };
+            draw_grid_line(painter, start, end, self.color);
+        }
+    }
+}
+
+type Point = (f64, f64);
+
+/// Clips a segment to the given bounds (Liang-Barsky)
+///
+/// Returns `None` when the segment is entirely outside of the bounds.
+fn clip_segment(
+    (x1, y1): Point,
+    (x2, y2): Point,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+) -> Option<(Point, Point)> {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let (mut t_min, mut t_max) = (0.0_f64, 1.0_f64);
+    for (p, q) in [
+        (-dx, x1 - x_bounds[0]),
+        (dx, x_bounds[1] - x1),
+        (-dy, y1 - y_bounds[0]),
+        (dy, y_bounds[1] - y1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t_min = t_min.max(q / p);
+        } else {
+            t_max = t_max.min(q / p);
+        }
+    }
+    if t_min > t_max {
+        return None;
+    }
+    // clamp to the bounds to avoid rounding errors pushing the points just outside of them
+    let point = |t: f64| {
+        (
+            (x1 + t * dx).clamp(x_bounds[0], x_bounds[1]),
+            (y1 + t * dy).clamp(y_bounds[0], y_bounds[1]),
+        )
+    };
+    Some((point(t_min), point(t_max)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::{canvas::Canvas, Widget},
+    };
+
+    fn render(polyline: &Polyline) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|context| context.draw(polyline));
+        canvas.render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    fn expected<const N: usize>(lines: [&str; N]) -> Buffer {
+        let mut expected = Buffer::with_lines(lines);
+        for cell in &mut expected.content {
+            if cell.symbol() == "•" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        expected
+    }
+
+    #[test]
+    fn zigzag() {
+        let polyline = Polyline::new([(0.0, 0.0), (3.0, 9.0), (6.0, 0.0), (9.0, 9.0)], Color::Red);
+        assert_eq!(
+            render(&polyline),
+            expected([
+                "   •     •",
+                "   •     •",
+                "  • •   • ",
+                "  • •   • ",
+                "  • •   • ",
+                " •   • •  ",
+                " •   • •  ",
+                " •   • •  ",
+                "•     •   ",
+                "•     •   ",
+            ])
+        );
+    }
+
+    #[test]
+    fn single_point() {
+        let polyline = Polyline::new([(2.0, 7.0)], Color::Red);
+        let mut lines = ["          "; 10];
+        lines[2] = "  •       ";
+        assert_eq!(render(&polyline), expected(lines));
+    }
+
+    #[test]
+    fn clipped_to_bounds() {
+        let polyline = Polyline::new(
+            [(-5.0, 4.0), (4.0, 4.0), (4.0, 20.0), (30.0, 20.0)],
+            Color::Red,
+        );
+        assert_eq!(
+            render(&polyline),
+            expected([
+                "    •     ",
+                "    •     ",
+                "    •     ",
+                "    •     ",
+                "    •     ",
+                "•••••     ",
+                "          ",
+                "          ",
+                "          ",
+                "          ",
+            ])
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let polyline = Polyline::new([], Color::Red);
+        assert_eq!(render(&polyline), expected(["          "; 10]));
+    }
+}
@@ -1,14 +1,17 @@
 use std::cmp::max;
 
+use itertools::Itertools;
 use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
+    buffer::Cell,
     layout::Flex,
     prelude::*,
     style::Styled,
     widgets::{
-        canvas::{Canvas, Line as CanvasLine, Points},
+        canvas::{Canvas, Line as CanvasLine, Painter, Points, Shape},
+        reflow::truncate_with_ellipsis,
         Block,
     },
 };
@@ -45,6 +48,10 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// Only every Nth label is displayed
+    label_interval: usize,
+    /// The number of tick marks drawn along the axis
+    ticks: usize,
 }
 
 impl<'a> Axis<'a> {
@@ -136,6 +143,72 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets the interval between displayed labels
+    ///
+    /// Only the labels whose index is a multiple of `interval` are displayed, starting with the
+    /// first label. The other labels are skipped but still take their place on the axis, so the
+    /// displayed labels stay at the same positions. This reduces clutter on dense axes. An interval
+    /// of 0 or 1 displays every label.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let labels = (0..24).map(|hour| format!("{hour}h").into()).collect();
+    /// let axis = Axis::default().labels(labels).label_interval(6);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_interval(mut self, interval: usize) -> Self {
+        self.label_interval = interval;
+        self
+    }
+
+    /// Sets the number of tick marks drawn along the axis
+    ///
+    /// The ticks are evenly spaced along the axis line, from the start to the end of the plotting
+    /// area, so that they are aligned with the labels when there are as many ticks as labels. The
+    /// ticks of the X axis point down towards its labels and the ticks of the Y axis point left
+    /// towards its labels. A value of `0` (the default) doesn't draw any tick.
+    ///
+    /// The axis line is only drawn when the axis has [labels](Axis::labels), and so are the ticks.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let axis = Axis::default()
+    ///     .bounds([0.0, 100.0])
+    ///     .labels(vec!["0".into(), "50".into(), "100".into()])
+    ///     .ticks(5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// The offsets of the ticks from the start of an axis of the given length
+    #[allow(clippy::cast_possible_truncation)]
+    fn tick_offsets(&self, length: u16) -> impl Iterator<Item = u16> {
+        let last = self.ticks.saturating_sub(1).max(1);
+        let length = usize::from(length.saturating_sub(1));
+        (0..self.ticks).map(move |i| (i * length / last) as u16)
+    }
+
+    /// Returns true if the label at the given index is displayed
+    const fn shows_label(&self, index: usize) -> bool {
+        let interval = if self.label_interval == 0 {
+            1
+        } else {
+            self.label_interval
+        };
+        index % interval == 0
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -149,8 +222,50 @@ pub enum GraphType {
     /// The order of the lines will be the same as the order of the points in the dataset, which
     /// allows this widget to draw lines both left-to-right and right-to-left
     Line,
+    /// Fill the area between the points and the horizontal baseline.
+    ///
+    /// The baseline is `y = 0`, clamped to the bounds of the Y axis. The fill uses the dataset's
+    /// color with the [`Modifier::DIM`] modifier so that it stays in the background. When `line`
+    /// is `true`, a line is also drawn between each following point on top of the fill using the
+    /// dataset's color, which makes it easy to emphasize a single dataset.
+    Area {
+        /// Whether to draw the line on top of the filled area
+        line: bool,
+    },
+    /// Stack the areas of all the `PercentArea` datasets of a chart, normalized to 100%.
+    ///
+    /// At each column of the graph, the values of the `PercentArea` datasets are added up and each
+    /// dataset fills a share of the full height of the graph proportional to its value. The first
+    /// dataset is at the bottom of the stack. Values are linearly interpolated between the points
+    /// of a dataset, and are 0 outside of its points. Negative values count as 0, and columns
+    /// where all the values are 0 are left empty.
+    ///
+    /// The bounds of the Y axis are ignored, and no points or lines are drawn.
+    PercentArea,
 }
 
+impl GraphType {
+    /// Returns true if lines should be drawn between each following point
+    const fn draws_line(self) -> bool {
+        matches!(self, Self::Line | Self::Area { line: true })
+    }
+}
+
+/// How the labels of the x axis of a [`Chart`] are laid out
+///
+/// See [`Chart::x_labels_layout`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum XLabelsLayout {
+    /// All the labels are on a single row below the axis
+    #[default]
+    Horizontal,
+    /// Every other label is on a second row, so that each label has twice as much space
+    Staggered,
+}
+
+/// The width of the sample symbol and the space before the name of a dataset in the legend
+const LEGEND_SAMPLE_WIDTH: u16 = 2;
+
 /// Allow users to specify the position of a legend in a [`Chart`]
 ///
 /// See [`Chart::legend_position`]
@@ -309,6 +424,25 @@ pub struct Dataset<'a> {
     style: Style,
 }
 
+impl Dataset<'_> {
+    /// The symbol shown next to the name of the dataset in the legend
+    ///
+    /// This is the symbol used to draw the data points of the dataset, so that the legend matches
+    /// the graph.
+    const fn legend_sample(&self) -> &'static str {
+        if matches!(self.graph_type, GraphType::PercentArea) {
+            return symbols::block::FULL;
+        }
+        match self.marker {
+            symbols::Marker::Dot => symbols::DOT,
+            symbols::Marker::Block => symbols::block::FULL,
+            symbols::Marker::Bar => symbols::bar::HALF,
+            symbols::Marker::Braille => "⣿",
+            symbols::Marker::HalfBlock => "▀",
+        }
+    }
+}
+
 impl<'a> Dataset<'a> {
     /// Sets the name of the dataset
     ///
@@ -362,9 +496,20 @@ impl<'a> Dataset<'a> {
 
     /// Sets how the dataset should be drawn
     ///
-    /// [`Chart`] can draw either a [scatter](GraphType::Scatter) or [line](GraphType::Line) charts.
-    /// A scatter will draw only the points in the dataset while a line will also draw a line
-    /// between them. See [`GraphType`] for more details
+    /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line),
+    /// [area](GraphType::Area) or [stacked percentage area](GraphType::PercentArea) charts. A
+    /// scatter will draw only the points in the dataset while a line will also draw a line between
+    /// them. An area fills the space below the points and can optionally draw the line on top. See
+    /// [`GraphType`] for more details
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let dataset = Dataset::default()
+    ///     .graph_type(GraphType::Area { line: true })
+    ///     .cyan();
+    /// ```
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -408,6 +553,8 @@ struct ChartLayout {
     title_y: Option<(u16, u16)>,
     /// Location of the first label of the x axis
     label_x: Option<u16>,
+    /// Whether the labels of the x axis are staggered on two rows
+    staggered_x_labels: bool,
     /// Location of the first label of the y axis
     label_y: Option<u16>,
     /// Y coordinate of the horizontal axis
@@ -480,10 +627,15 @@ struct ChartLayout {
 ///     .x_axis(x_axis)
 ///     .y_axis(y_axis);
 /// ```
+#[allow(clippy::struct_excessive_bools)] // independent rendering options
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Chart<'a> {
     /// A block to display around the widget eventually
     block: Option<Block<'a>>,
+    /// A title displayed above the plotting area
+    title: Option<Line<'a>>,
+    /// A subtitle displayed below the title
+    subtitle: Option<Line<'a>>,
     /// The horizontal axis
     x_axis: Axis<'a>,
     /// The vertical axis
@@ -497,6 +649,24 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// Whether to show a sample of the marker of each dataset next to its name in the legend
+    legend_samples: bool,
+    /// The maximum width of the legend, including its borders
+    legend_max_width: Option<u16>,
+    /// Whether to wrap the names that don't fit in the legend instead of truncating them
+    legend_wrap: bool,
+    /// The background color of the plotting area
+    plot_background: Option<Color>,
+    /// The data coordinates of the crosshair
+    cursor: Option<(f64, f64)>,
+    /// The style of the crosshair and of its labels
+    cursor_style: Style,
+    /// Whether to print the coordinates of the crosshair near the axes
+    cursor_labels: bool,
+    /// Whether the area datasets are stacked on top of each other
+    stacked: bool,
+    /// How the labels of the x axis are laid out
+    x_labels_layout: XLabelsLayout,
 }
 
 impl<'a> Chart<'a> {
@@ -528,12 +698,23 @@ impl<'a> Chart<'a> {
     pub fn new(datasets: Vec<Dataset<'a>>) -> Self {
         Self {
             block: None,
+            title: None,
+            subtitle: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
             style: Style::default(),
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            legend_samples: false,
+            legend_max_width: None,
+            legend_wrap: false,
+            plot_background: None,
+            cursor: None,
+            cursor_style: Style::new(),
+            cursor_labels: true,
+            stacked: false,
+            x_labels_layout: XLabelsLayout::Horizontal,
         }
     }
 
@@ -546,6 +727,39 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the title displayed above the plotting area
+    ///
+    /// The title is centered unless the line has its own [alignment](Line::alignment). A row is
+    /// reserved for the title and the plotting area is shrunk accordingly.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = Chart::new(vec![])
+    ///     .title("Temperature".bold())
+    ///     .subtitle("Last 24 hours");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title<T: Into<Line<'a>>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the subtitle displayed below the title
+    ///
+    /// Like the [title](Chart::title), the subtitle is centered unless the line has its own
+    /// alignment and a row is reserved for it above the plotting area.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn subtitle<T: Into<Line<'a>>>(mut self, subtitle: T) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
     /// Sets the style of the entire chart
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -689,6 +903,295 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Shows a sample of each dataset before its name in the legend
+    ///
+    /// The sample is the symbol used to draw the data points of the dataset, which depends on its
+    /// [marker](Dataset::marker) and [graph type](Dataset::graph_type), in the dataset's color. This
+    /// makes the legend an accurate key of the graph. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // The legend shows `• points` in red
+    /// let chart = Chart::new(vec![Dataset::default()
+    ///     .name("points")
+    ///     .marker(symbols::Marker::Dot)
+    ///     .red()])
+    /// .legend_samples(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend_samples(mut self, legend_samples: bool) -> Self {
+        self.legend_samples = legend_samples;
+        self
+    }
+
+    /// Sets the background color of the plotting area
+    ///
+    /// Only the area where the datasets are drawn is filled, the axes, their labels and the
+    /// surrounding block keep the background of the [chart style](Chart::style). This makes the
+    /// plot stand out when several charts are displayed side by side. By default the plotting area
+    /// uses the background of the chart style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = Chart::new(vec![])
+    ///     .block(Block::bordered())
+    ///     .plot_background(Color::DarkGray);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn plot_background(mut self, color: Color) -> Self {
+        self.plot_background = Some(color);
+        self
+    }
+
+    /// Sets the data coordinates of a crosshair drawn over the plotting area
+    ///
+    /// When set, a vertical and a horizontal line are drawn through the position of the `(x, y)`
+    /// coordinates in the plotting area, e.g. to follow the mouse or a keyboard cursor. The
+    /// coordinates are mapped to the area with the [bounds](Axis::bounds) of the axes and nothing
+    /// is drawn when they are out of bounds. The values of the coordinates are also printed next
+    /// to the axes, see [`Chart::cursor_labels`]. Defaults to `None`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 100.0]))
+    ///     .cursor(Some((2.5, 40.0)))
+    ///     .cursor_style(Style::new().yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cursor(mut self, cursor: Option<(f64, f64)>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Sets the style of the crosshair and of its labels
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.cursor_style = style.into();
+        self
+    }
+
+    /// Sets whether to print the coordinates of the [crosshair](Chart::cursor) near the axes
+    ///
+    /// The x value is printed on the bottom row of the plotting area, at the column of the
+    /// crosshair, and the y value on the left of the plotting area, at the row of the crosshair.
+    /// Defaults to `true`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cursor_labels(mut self, cursor_labels: bool) -> Self {
+        self.cursor_labels = cursor_labels;
+        self
+    }
+
+    /// Sets the maximum width of the legend, including its borders
+    ///
+    /// The names of the datasets that are too long to fit in the legend are truncated with an
+    /// ellipsis (`…`), or wrapped on several rows with [`Chart::legend_wrap`]. The
+    /// [samples](Chart::legend_samples) are always displayed in full. If there is no room left for
+    /// the names after the samples and the borders, the legend is hidden. By default the legend is
+    /// as wide as its longest name.
+    ///
+    /// The legend is still hidden when it is larger than allowed by the
+    /// [hidden legend constraints](Chart::hidden_legend_constraints).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // The legend shows `a very lo…`
+    /// let chart = Chart::new(vec![Dataset::default().name("a very long name")])
+    ///     .legend_max_width(12);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend_max_width(mut self, width: u16) -> Self {
+        self.legend_max_width = Some(width);
+        self
+    }
+
+    /// Wraps the names that don't fit in the legend on several rows instead of truncating them
+    ///
+    /// This only applies when the legend is [positioned](Chart::legend_position) at the top or
+    /// bottom of the chart, where the legend can grow vertically without hiding the middle of the
+    /// plot, and when its width is limited with [`Chart::legend_max_width`]. Defaults to `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // The legend shows `a very lon` and `g name` below it
+    /// let chart = Chart::new(vec![Dataset::default().name("a very long name")])
+    ///     .legend_position(Some(LegendPosition::Bottom))
+    ///     .legend_max_width(12)
+    ///     .legend_wrap(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend_wrap(mut self, wrap: bool) -> Self {
+        self.legend_wrap = wrap;
+        self
+    }
+
+    /// Stacks the [area](GraphType::Area) datasets on top of each other.
+    ///
+    /// When enabled, the points of each area dataset are drawn on top of the cumulative total of
+    /// the area datasets before it, and its fill covers the space between the previous total and
+    /// its own, so that the areas don't overlap and the top of the stack is the sum of the
+    /// datasets. The first area dataset is at the bottom of the stack. Other datasets are not
+    /// affected. Defaults to `false`.
+    ///
+    /// The values are added point by point, so the stacked datasets must have the same number of
+    /// points at the same x coordinates. The bounds of the Y axis are not adjusted, they should be
+    /// large enough for the stacked totals.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let reads = [(0.0, 1.0), (1.0, 2.0), (2.0, 1.0)];
+    /// let writes = [(0.0, 2.0), (1.0, 1.0), (2.0, 3.0)];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default()
+    ///         .data(&reads)
+    ///         .graph_type(GraphType::Area { line: true })
+    ///         .green(),
+    ///     Dataset::default()
+    ///         .data(&writes)
+    ///         .graph_type(GraphType::Area { line: true })
+    ///         .red(),
+    /// ])
+    /// .y_axis(Axis::default().bounds([0.0, 4.0]))
+    /// .stacked(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stacked(mut self, stacked: bool) -> Self {
+        self.stacked = stacked;
+        self
+    }
+
+    /// Sets how the labels of the x axis are laid out
+    ///
+    /// With [`XLabelsLayout::Staggered`], every other label is rendered on a second row below the
+    /// axis, which takes one more row from the plotting area. Each label can then use the space
+    /// around its neighbours, which avoids truncating the labels of charts with many x labels.
+    /// Labels that still don't fit are not rendered at all. Defaults to
+    /// [`XLabelsLayout::Horizontal`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().labels(vec!["0".into(), "10".into(), "20".into(), "30".into()]))
+    ///     .x_labels_layout(XLabelsLayout::Staggered);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn x_labels_layout(mut self, layout: XLabelsLayout) -> Self {
+        self.x_labels_layout = layout;
+        self
+    }
+
+    /// Whether the names that don't fit in the legend are wrapped instead of truncated
+    const fn wraps_legend(&self) -> bool {
+        self.legend_wrap
+            && matches!(
+                self.legend_position,
+                Some(
+                    LegendPosition::Top
+                        | LegendPosition::TopLeft
+                        | LegendPosition::TopRight
+                        | LegendPosition::Bottom
+                        | LegendPosition::BottomLeft
+                        | LegendPosition::BottomRight
+                )
+            )
+    }
+
+    /// The rows used to display the name of a dataset in a legend where names are `width` wide
+    fn legend_name_rows(&self, dataset: &Dataset<'a>, width: u16) -> Vec<Line<'a>> {
+        let Some(name) = dataset.name.clone() else {
+            return vec![];
+        };
+        let name = name.patch_style(dataset.style());
+        if name.width() <= width as usize {
+            vec![name]
+        } else if self.wraps_legend() {
+            wrap_legend_name(&name, width)
+        } else {
+            vec![truncate_with_ellipsis(&name, width)]
+        }
+    }
+
+    /// The width and height of the legend, including its borders
+    ///
+    /// Returns `None` when there is nothing to display in the legend or no room for the names.
+    #[allow(clippy::cast_possible_truncation)]
+    fn legend_size(&self) -> Option<(u16, u16)> {
+        let inner_width = self
+            .datasets
+            .iter()
+            .filter_map(|d| Some(d.name.as_ref()?.width() as u16 + self.legend_sample_width()))
+            .max()?;
+        let inner_width = self
+            .legend_max_width
+            .map_or(inner_width, |max| inner_width.min(max.saturating_sub(2)));
+        let name_width = inner_width.saturating_sub(self.legend_sample_width());
+        if inner_width == 0 || (self.legend_max_width.is_some() && name_width == 0) {
+            return None;
+        }
+        let inner_height = self
+            .datasets
+            .iter()
+            .map(|d| self.legend_name_rows(d, name_width).len() as u16)
+            .sum::<u16>();
+        Some((inner_width + 2, inner_height + 2))
+    }
+
+    /// The width added before the name of each dataset in the legend
+    const fn legend_sample_width(&self) -> u16 {
+        if self.legend_samples {
+            LEGEND_SAMPLE_WIDTH
+        } else {
+            0
+        }
+    }
+
+    /// Renders the title and subtitle at the top of the area and returns the area left below them
+    fn render_titles(&self, mut area: Rect, buf: &mut Buffer) -> Rect {
+        for line in [&self.title, &self.subtitle].into_iter().flatten() {
+            if area.is_empty() {
+                break;
+            }
+            let alignment = line.alignment.unwrap_or(Alignment::Center);
+            let row = Rect { height: 1, ..area };
+            line.clone().alignment(alignment).render(row, buf);
+            area.y += 1;
+            area.height -= 1;
+        }
+        area
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
@@ -699,9 +1202,15 @@ impl<'a> Chart<'a> {
         let mut y = area.bottom() - 1;
 
         let mut label_x = None;
+        let mut staggered_x_labels = false;
         if self.x_axis.labels.is_some() && y > area.top() {
             label_x = Some(y);
             y -= 1;
+            if self.x_labels_layout == XLabelsLayout::Staggered && y > area.top() {
+                label_x = Some(y);
+                staggered_x_labels = true;
+                y -= 1;
+            }
         }
 
         let label_y = self.y_axis.labels.as_ref().and(Some(x));
@@ -748,48 +1257,38 @@ impl<'a> Chart<'a> {
         }
 
         let mut legend_area = None;
-        if let Some(legend_position) = self.legend_position {
-            let legends = self
-                .datasets
-                .iter()
-                .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
-
-            if let Some(inner_width) = legends.clone().max() {
-                let legend_width = inner_width + 2;
-                let legend_height = legends.count() as u16 + 2;
-
-                let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                if inner_width > 0
-                    && legend_width <= max_legend_width.width
-                    && legend_height <= max_legend_height.height
-                {
-                    legend_area = legend_position.layout(
-                        graph_area,
-                        legend_width,
-                        legend_height,
-                        title_x
-                            .and(self.x_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                        title_y
-                            .and(self.y_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                    );
-                }
+        if let (Some(legend_position), Some((legend_width, legend_height))) =
+            (self.legend_position, self.legend_size())
+        {
+            let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
+                .flex(Flex::Start)
+                .areas(graph_area);
+
+            let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
+                .flex(Flex::Start)
+                .areas(graph_area);
+
+            if legend_width <= max_legend_width.width && legend_height <= max_legend_height.height {
+                legend_area = legend_position.layout(
+                    graph_area,
+                    legend_width,
+                    legend_height,
+                    title_x
+                        .and(self.x_axis.title.as_ref())
+                        .map(|t| t.width() as u16)
+                        .unwrap_or_default(),
+                    title_y
+                        .and(self.y_axis.title.as_ref())
+                        .map(|t| t.width() as u16)
+                        .unwrap_or_default(),
+                );
             }
         }
         Some(ChartLayout {
             title_x,
             title_y,
             label_x,
+            staggered_x_labels,
             label_y,
             axis_x,
             axis_y,
@@ -844,11 +1343,20 @@ impl<'a> Chart<'a> {
         }
 
         let width_between_ticks = graph_area.width / labels_len;
+        // staggered labels don't have a neighbour on their row around their tick, so they can use
+        // half of the space of the neighbouring ticks on each side
+        let staggered = layout.staggered_x_labels;
+        let extra_width = if staggered {
+            width_between_ticks / 2
+        } else {
+            0
+        };
+        let row = |index: usize| if staggered { y + (index % 2) as u16 } else { y };
 
         let label_area = self.first_x_label_area(
-            y,
+            row(0),
             labels.first().unwrap().width() as u16,
-            width_between_ticks,
+            width_between_ticks + extra_width,
             chart_area,
             graph_area,
         );
@@ -859,21 +1367,57 @@ impl<'a> Chart<'a> {
             Alignment::Right => Alignment::Left,
         };
 
-        Self::render_label(buf, labels.first().unwrap(), label_area, label_alignment);
+        Self::render_x_label(
+            buf,
+            labels.first().unwrap(),
+            label_area,
+            label_alignment,
+            staggered,
+        );
 
         for (i, label) in labels[1..labels.len() - 1].iter().enumerate() {
+            if !self.x_axis.shows_label(i + 1) {
+                continue;
+            }
             // We add 1 to x (and width-1 below) to leave at least one space before each
             // intermediate labels
-            let x = graph_area.left() + (i + 1) as u16 * width_between_ticks + 1;
-            let label_area = Rect::new(x, y, width_between_ticks.saturating_sub(1), 1);
+            let x = graph_area.left() + (i + 1) as u16 * width_between_ticks + 1 - extra_width;
+            let width = (width_between_ticks.saturating_sub(1) + 2 * extra_width)
+                .min(graph_area.right() - x);
+            let label_area = Rect::new(x, row(i + 1), width, 1);
 
-            Self::render_label(buf, label, label_area, Alignment::Center);
+            Self::render_x_label(buf, label, label_area, Alignment::Center, staggered);
         }
 
-        let x = graph_area.right() - width_between_ticks;
-        let label_area = Rect::new(x, y, width_between_ticks, 1);
+        if !self.x_axis.shows_label(labels.len() - 1) {
+            return;
+        }
+        let width = width_between_ticks + extra_width;
+        let x = graph_area.right() - width;
+        let label_area = Rect::new(x, row(labels.len() - 1), width, 1);
         // The last label should be aligned Right to be at the edge of the graph area
-        Self::render_label(buf, labels.last().unwrap(), label_area, Alignment::Right);
+        Self::render_x_label(
+            buf,
+            labels.last().unwrap(),
+            label_area,
+            Alignment::Right,
+            staggered,
+        );
+    }
+
+    /// Renders a label of the x axis, skipping staggered labels that don't fit instead of
+    /// truncating them
+    fn render_x_label(
+        buf: &mut Buffer,
+        label: &Span,
+        label_area: Rect,
+        alignment: Alignment,
+        staggered: bool,
+    ) {
+        if staggered && label.width() > label_area.width as usize {
+            return;
+        }
+        Self::render_label(buf, label, label_area, alignment);
     }
 
     fn first_x_label_area(
@@ -924,7 +1468,7 @@ impl<'a> Chart<'a> {
         let labels_len = labels.len() as u16;
         for (i, label) in labels.iter().enumerate() {
             let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
-            if dy < graph_area.bottom() {
+            if dy < graph_area.bottom() && self.y_axis.shows_label(i) {
                 let label_area = Rect::new(
                     x,
                     graph_area.bottom().saturating_sub(1) - dy,
@@ -937,6 +1481,272 @@ impl<'a> Chart<'a> {
     }
 }
 
+impl Chart<'_> {
+    /// Draws the crosshair through the position of the cursor in the graph area
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn render_cursor(&self, graph_area: Rect, buf: &mut Buffer) {
+        let Some((x, y)) = self.cursor else {
+            return;
+        };
+        let ([left, right], [bottom, top]) = (self.x_axis.bounds, self.y_axis.bounds);
+        if !(left..=right).contains(&x) || !(bottom..=top).contains(&y) {
+            return;
+        }
+        let offset = |value: f64, min: f64, max: f64, length: u16| {
+            let ratio = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.0
+            };
+            (ratio * f64::from(length - 1)).round() as u16
+        };
+        let column = graph_area.left() + offset(x, left, right, graph_area.width);
+        let row = graph_area.bottom() - 1 - offset(y, bottom, top, graph_area.height);
+
+        for position in graph_area.columns().map(|c| Position::new(c.x, row)) {
+            buf.get_mut(position.x, position.y)
+                .set_symbol(symbols::line::HORIZONTAL)
+                .set_style(self.cursor_style);
+        }
+        for position in graph_area.rows().map(|r| Position::new(column, r.y)) {
+            buf.get_mut(position.x, position.y)
+                .set_symbol(symbols::line::VERTICAL)
+                .set_style(self.cursor_style);
+        }
+        buf.get_mut(column, row)
+            .set_symbol(symbols::line::CROSS)
+            .set_style(self.cursor_style);
+
+        if self.cursor_labels {
+            let x_label = x.to_string();
+            let x_label_start = column
+                .min(graph_area.right().saturating_sub(x_label.width() as u16))
+                .max(graph_area.left());
+            buf.set_stringn(
+                x_label_start,
+                graph_area.bottom() - 1,
+                x_label,
+                (graph_area.right() - x_label_start) as usize,
+                self.cursor_style,
+            );
+            buf.set_stringn(
+                graph_area.left(),
+                row,
+                y.to_string(),
+                graph_area.width as usize,
+                self.cursor_style,
+            );
+        }
+    }
+
+    /// Paints the filled area of a [`GraphType::Area`] dataset on a buffer covering `graph_area`
+    ///
+    /// The area is filled between `coords` and `base`, or the horizontal baseline if `base` is
+    /// empty.
+    fn area_fill(
+        &self,
+        dataset: &Dataset,
+        coords: &[(f64, f64)],
+        base: &[(f64, f64)],
+        graph_area: Rect,
+    ) -> Buffer {
+        let mut fill = Buffer::empty(graph_area);
+        Canvas::default()
+            .x_bounds(self.x_axis.bounds)
+            .y_bounds(self.y_axis.bounds)
+            .marker(dataset.marker)
+            .paint(|ctx| {
+                ctx.draw(&AreaFill {
+                    coords,
+                    base,
+                    x_bounds: self.x_axis.bounds,
+                    y_bounds: self.y_axis.bounds,
+                    color: dataset.style.fg.unwrap_or(Color::Reset),
+                });
+            })
+            .render(graph_area, &mut fill);
+        fill
+    }
+}
+
+impl Chart<'_> {
+    /// Renders the [`GraphType::PercentArea`] datasets stacked and normalized to the graph height
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn render_percent_areas(&self, graph_area: Rect, buf: &mut Buffer) {
+        let datasets = self
+            .datasets
+            .iter()
+            .filter(|dataset| dataset.graph_type == GraphType::PercentArea)
+            .collect_vec();
+        if datasets.is_empty() {
+            return;
+        }
+        let [left, right] = self.x_axis.bounds;
+        let column_width = (right - left) / f64::from(graph_area.width);
+        let height = f64::from(graph_area.height);
+        for (i, column) in graph_area.columns().enumerate() {
+            // sample the values at the center of the column
+            let x = (i as f64 + 0.5).mul_add(column_width, left);
+            let values = datasets
+                .iter()
+                .map(|dataset| interpolate(dataset.data, x).max(0.0))
+                .collect_vec();
+            let total: f64 = values.iter().sum();
+            if total <= 0.0 {
+                continue;
+            }
+            // round the cumulative heights so that the stack always fills the whole column
+            let mut stacked = 0.0;
+            let mut bottom = column.bottom();
+            for (dataset, value) in datasets.iter().zip(values) {
+                stacked += value;
+                let top = column.bottom() - (stacked / total * height).round() as u16;
+                for y in top..bottom {
+                    buf.get_mut(column.x, y)
+                        .set_symbol(symbols::block::FULL)
+                        .set_style(dataset.style);
+                }
+                bottom = top;
+            }
+        }
+    }
+}
+
+/// Returns the value of a series of points at `x`, linearly interpolated between the points
+///
+/// Returns 0 when `x` is outside of the points, or when there are less than 2 points.
+fn interpolate(data: &[(f64, f64)], x: f64) -> f64 {
+    data.windows(2)
+        .find_map(|points| {
+            let ((x1, y1), (x2, y2)) = (points[0], points[1]);
+            let dx = x2 - x1;
+            if x < x1.min(x2) || x > x1.max(x2) {
+                None
+            } else if dx == 0.0 {
+                Some(y1.max(y2))
+            } else {
+                Some((y2 - y1).mul_add((x - x1) / dx, y1))
+            }
+        })
+        .unwrap_or(0.0)
+}
+
+/// Adds the values of `stack` to the values of the points of `data` with the same index
+fn stack_points(data: &[(f64, f64)], stack: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    data.iter()
+        .enumerate()
+        .map(|(i, &(x, y))| (x, y + stack.get(i).map_or(0.0, |&(_, total)| total)))
+        .collect()
+}
+
+/// Copies the symbol and foreground color of the cells painted on `layer` to `buf` and applies
+/// `modify` to each of them
+fn overlay_painted_cells(buf: &mut Buffer, layer: &Buffer, modify: impl Fn(&mut Cell)) {
+    for position in layer.area.positions() {
+        let painted = layer.get(position.x, position.y);
+        if painted.symbol() == " " {
+            continue;
+        }
+        let cell = buf
+            .get_mut(position.x, position.y)
+            .set_symbol(painted.symbol());
+        if painted.fg != Color::Reset {
+            cell.set_fg(painted.fg);
+        }
+        modify(cell);
+    }
+}
+
+/// Fills the area between a series of points and the horizontal baseline of a chart
+///
+/// When `base` is not empty, the area is filled down to the points of `base` with the same index
+/// instead of the baseline, which is used to stack areas. The cells of `base` itself are left to
+/// the area below.
+struct AreaFill<'a> {
+    coords: &'a [(f64, f64)],
+    base: &'a [(f64, f64)],
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    color: Color,
+}
+
+impl Shape for AreaFill<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let [left, right] = self.x_bounds;
+        let [bottom, top] = self.y_bounds;
+        let baseline = 0.0_f64.max(bottom).min(top);
+        for (i, data) in self.coords.windows(2).enumerate() {
+            let (mut start, mut end) = (data[0], data[1]);
+            let (mut base_start, mut base_end) = match self.base.get(i..i + 2) {
+                Some(base) => (base[0].1, base[1].1),
+                None => (baseline, baseline),
+            };
+            if start.0 > end.0 {
+                std::mem::swap(&mut start, &mut end);
+                std::mem::swap(&mut base_start, &mut base_end);
+            }
+            let y_at = |x: f64, y1: f64, y2: f64| {
+                let dx = end.0 - start.0;
+                let y = if dx == 0.0 {
+                    y1
+                } else {
+                    y1 + (y2 - y1) * (x - start.0) / dx
+                };
+                y.max(bottom).min(top)
+            };
+            let (x1, x2) = (start.0.max(left), end.0.min(right));
+            if x1 > x2 {
+                continue;
+            }
+            let (Some((gx1, gy1)), Some((gx2, gy2)), Some((_, by1)), Some((_, by2))) = (
+                painter.get_point(x1, y_at(x1, start.1, end.1)),
+                painter.get_point(x2, y_at(x2, start.1, end.1)),
+                painter.get_point(x1, y_at(x1, base_start, base_end)),
+                painter.get_point(x2, y_at(x2, base_start, base_end)),
+            ) else {
+                continue;
+            };
+            for gx in gx1..=gx2 {
+                let (gy, base) = if gx2 == gx1 {
+                    (gy1.min(gy2), by1.max(by2))
+                } else {
+                    let t = (gx - gx1) as f64 / (gx2 - gx1) as f64;
+                    (
+                        (gy1 as f64 + (gy2 as f64 - gy1 as f64) * t).round() as usize,
+                        (by1 as f64 + (by2 as f64 - by1 as f64) * t).round() as usize,
+                    )
+                };
+                for y in gy.min(base)..=gy.max(base) {
+                    // the points of a stacked base belong to the area below
+                    if self.base.is_empty() || y != base {
+                        painter.paint(gx, y, self.color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Splits a legend name in rows of at most `width` columns
+fn wrap_legend_name(name: &Line, width: u16) -> Vec<Line<'static>> {
+    let mut rows = vec![];
+    let mut row: Vec<Span> = vec![];
+    let mut row_width = 0;
+    for grapheme in name.styled_graphemes(Style::default()) {
+        let grapheme_width = grapheme.symbol.width() as u16;
+        if row_width + grapheme_width > width && !row.is_empty() {
+            rows.push(Line::from(std::mem::take(&mut row)));
+            row_width = 0;
+        }
+        row.push(Span::styled(grapheme.symbol.to_string(), grapheme.style));
+        row_width += grapheme_width;
+    }
+    if !row.is_empty() {
+        rows.push(Line::from(row));
+    }
+    rows
+}
+
 impl Widget for Chart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         self.render_ref(area, buf);
@@ -949,7 +1759,7 @@ impl WidgetRef for Chart<'_> {
         buf.set_style(area, self.style);
 
         self.block.render_ref(area, buf);
-        let chart_area = self.block.inner_if_some(area);
+        let chart_area = self.render_titles(self.block.inner_if_some(area), buf);
         let Some(layout) = self.layout(chart_area) else {
             return;
         };
@@ -987,19 +1797,56 @@ impl WidgetRef for Chart<'_> {
             }
         }
 
+        if let Some(y) = layout.axis_x {
+            for dx in self.x_axis.tick_offsets(graph_area.width) {
+                buf.get_mut(graph_area.left() + dx, y)
+                    .set_symbol(symbols::line::HORIZONTAL_DOWN);
+            }
+        }
+
+        if let Some(x) = layout.axis_y {
+            for dy in self.y_axis.tick_offsets(graph_area.height) {
+                buf.get_mut(x, graph_area.bottom() - 1 - dy)
+                    .set_symbol(symbols::line::VERTICAL_LEFT);
+            }
+        }
+
+        let background_color = self
+            .plot_background
+            .or(self.style.bg)
+            .unwrap_or(Color::Reset);
+        if self.plot_background.is_some() {
+            buf.set_style(graph_area, Style::new().bg(background_color));
+        }
+
+        self.render_percent_areas(graph_area, buf);
+
+        // the cumulative total of the area datasets when they are stacked
+        let mut stack: Vec<(f64, f64)> = Vec::new();
         for dataset in &self.datasets {
-            Canvas::default()
-                .background_color(self.style.bg.unwrap_or(Color::Reset))
+            if dataset.graph_type == GraphType::PercentArea {
+                continue;
+            }
+            let is_area = matches!(dataset.graph_type, GraphType::Area { .. });
+            let stacked_data;
+            let (coords, base) = if self.stacked && is_area {
+                stacked_data = stack_points(dataset.data, &stack);
+                (stacked_data.as_slice(), std::mem::take(&mut stack))
+            } else {
+                (dataset.data, Vec::new())
+            };
+            let canvas = Canvas::default()
+                .background_color(background_color)
                 .x_bounds(self.x_axis.bounds)
                 .y_bounds(self.y_axis.bounds)
                 .marker(dataset.marker)
                 .paint(|ctx| {
                     ctx.draw(&Points {
-                        coords: dataset.data,
+                        coords,
                         color: dataset.style.fg.unwrap_or(Color::Reset),
                     });
-                    if dataset.graph_type == GraphType::Line {
-                        for data in dataset.data.windows(2) {
+                    if dataset.graph_type.draws_line() {
+                        for data in coords.windows(2) {
                             ctx.draw(&CanvasLine {
                                 x1: data[0].0,
                                 y1: data[0].1,
@@ -1009,10 +1856,26 @@ impl WidgetRef for Chart<'_> {
                             });
                         }
                     }
-                })
-                .render(graph_area, buf);
+                });
+            if is_area {
+                // The fill is dimmed while the points and line on top of it are not, so both are
+                // painted on their own buffer to only restyle the cells they cover
+                buf.set_style(graph_area, Style::default().bg(background_color));
+                let fill = self.area_fill(dataset, coords, &base, graph_area);
+                overlay_painted_cells(buf, &fill, |cell| cell.modifier.insert(Modifier::DIM));
+                let mut points = Buffer::empty(graph_area);
+                canvas.render(graph_area, &mut points);
+                overlay_painted_cells(buf, &points, |cell| cell.modifier.remove(Modifier::DIM));
+            } else {
+                canvas.render(graph_area, buf);
+            }
+            if self.stacked && is_area {
+                stack = coords.to_vec();
+            }
         }
 
+        self.render_cursor(graph_area, buf);
+
         if let Some((x, y)) = layout.title_x {
             let title = self.x_axis.title.as_ref().unwrap();
             let width = graph_area
@@ -1053,22 +1916,19 @@ impl WidgetRef for Chart<'_> {
             buf.set_style(legend_area, original_style);
             Block::bordered().render(legend_area, buf);
 
-            for (i, (dataset_name, dataset_style)) in self
-                .datasets
-                .iter()
-                .filter_map(|ds| Some((ds.name.as_ref()?, ds.style())))
-                .enumerate()
-            {
-                let name = dataset_name.clone().patch_style(dataset_style);
-                name.render(
-                    Rect {
-                        x: legend_area.x + 1,
-                        y: legend_area.y + 1 + i as u16,
-                        width: legend_area.width - 2,
-                        height: 1,
-                    },
-                    buf,
-                );
+            let inner_area = legend_area.inner(Margin::new(1, 1));
+            let name_width = inner_area.width.saturating_sub(self.legend_sample_width());
+            let mut rows = inner_area.rows();
+            for dataset in &self.datasets {
+                let names = self.legend_name_rows(dataset, name_width);
+                for (i, (name, area)) in names.into_iter().zip(rows.by_ref()).enumerate() {
+                    if self.legend_samples && i == 0 {
+                        let sample_style =
+                            Style::new().fg(dataset.style.fg.unwrap_or(Color::Reset));
+                        buf.set_string(area.x, area.y, dataset.legend_sample(), sample_style);
+                    }
+                    name.render(area.indent_x(self.legend_sample_width()), buf);
+                }
             }
         }
     }
@@ -1194,15 +2054,129 @@ mod tests {
     fn graph_type_to_string() {
         assert_eq!(GraphType::Scatter.to_string(), "Scatter");
         assert_eq!(GraphType::Line.to_string(), "Line");
+        assert_eq!(GraphType::Area { line: true }.to_string(), "Area");
+        assert_eq!(GraphType::PercentArea.to_string(), "PercentArea");
     }
 
     #[test]
     fn graph_type_from_str() {
         assert_eq!("Scatter".parse::<GraphType>(), Ok(GraphType::Scatter));
         assert_eq!("Line".parse::<GraphType>(), Ok(GraphType::Line));
+        assert_eq!(
+            "Area".parse::<GraphType>(),
+            Ok(GraphType::Area { line: false })
+        );
+        assert_eq!(
+            "PercentArea".parse::<GraphType>(),
+            Ok(GraphType::PercentArea)
+        );
         assert_eq!("".parse::<GraphType>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn area_graph_type_renders_dimmed_fill_under_line() {
+        let data = [(0.0, 1.0), (4.0, 3.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Area { line: true })
+            .red();
+        let chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["     ", "    █", "  ███", "█████", "█████"]);
+        // the line is drawn on top of the dimmed fill
+        expected.set_style(Rect::new(4, 1, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 2, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 2, 1, 1), Style::new().red().dim());
+        expected.set_style(Rect::new(0, 3, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 3, 3, 1), Style::new().red().dim());
+        expected.set_style(Rect::new(0, 4, 5, 1), Style::new().red().dim());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn percent_area_graph_type_stacks_normalized_datasets() {
+        let bottom = [(0.0, 1.0), (3.0, 1.0)];
+        let top = [(0.0, 3.0), (1.0, 3.0), (2.0, 1.0), (3.0, 1.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&bottom)
+                .graph_type(GraphType::PercentArea)
+                .red(),
+            Dataset::default()
+                .data(&top)
+                .graph_type(GraphType::PercentArea)
+                .blue(),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        chart.render(buffer.area, &mut buffer);
+        // the last column has no data, so it is left empty
+        let mut expected = Buffer::with_lines(["███ "; 4]);
+        expected.set_style(Rect::new(0, 0, 3, 4), Style::new().blue());
+        // 1 / (1 + 3), 1 / (1 + 2) and 1 / (1 + 1) of the height
+        expected.set_style(Rect::new(0, 3, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 2, 1, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stacked_area_datasets_sum_up() {
+        let bottom = [(0.0, 1.0), (4.0, 1.0)];
+        let top = [(0.0, 2.0), (4.0, 3.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&bottom)
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Area { line: false })
+                .red(),
+            Dataset::default()
+                .data(&top)
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Area { line: true })
+                .blue(),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]))
+        .stacked(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        chart.render(buffer.area, &mut buffer);
+        // the line at the top of the stack goes from 1 + 2 to 1 + 3
+        let mut expected = Buffer::with_lines(["   ██", "█████", "█████", "█████", "█████"]);
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 1, 3, 1), Style::new().blue());
+        expected.set_style(Rect::new(3, 1, 2, 1), Style::new().blue().dim());
+        expected.set_style(Rect::new(0, 2, 5, 1), Style::new().blue().dim());
+        expected.set_style(Rect::new(0, 3, 5, 2), Style::new().red().dim());
+        // the points of the bottom dataset are not dimmed
+        expected.set_style(Rect::new(0, 3, 1, 1), Style::new().red().not_dim());
+        expected.set_style(Rect::new(4, 3, 1, 1), Style::new().red().not_dim());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stack_points_adds_values_with_same_index() {
+        let stack = [(0.0, 1.0), (1.0, 2.0)];
+        let data = [(0.0, 3.0), (1.0, 4.0), (2.0, 5.0)];
+        assert_eq!(
+            stack_points(&data, &stack),
+            [(0.0, 4.0), (1.0, 6.0), (2.0, 5.0)]
+        );
+    }
+
+    #[test]
+    fn interpolate_between_points() {
+        let data = [(0.0, 0.0), (2.0, 4.0), (4.0, 0.0)];
+        assert!((interpolate(&data, 1.0) - 2.0).abs() < f64::EPSILON);
+        assert!((interpolate(&data, 2.0) - 4.0).abs() < f64::EPSILON);
+        assert!((interpolate(&data, 3.5) - 1.0).abs() < f64::EPSILON);
+        assert!(interpolate(&data, 5.0).abs() < f64::EPSILON);
+        assert!(interpolate(&[(1.0, 3.0)], 1.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn it_does_not_panic_if_title_is_wider_than_buffer() {
         let widget = Chart::default()
@@ -1236,6 +2210,120 @@ mod tests {
         assert!(layout.legend_area.is_none());
     }
 
+    #[test]
+    fn label_interval_shows_every_nth_label() {
+        let chart = |interval| {
+            Chart::new(vec![])
+                .x_axis(
+                    Axis::default()
+                        .labels(["a", "b", "c", "d", "e"].map(Span::from).to_vec())
+                        .label_interval(interval),
+                )
+                .y_axis(
+                    Axis::default()
+                        .labels(["x", "y", "z"].map(Span::from).to_vec())
+                        .label_interval(interval),
+                )
+        };
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 22, 5));
+        chart(1).render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "z│                    ",
+            "y│                    ",
+            "x│                    ",
+            " └────────────────────",
+            " a      b   c   d    e",
+        ]);
+        assert_eq!(buffer, expected);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 22, 5));
+        chart(2).render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "z│                    ",
+            " │                    ",
+            "x│                    ",
+            " └────────────────────",
+            " a          c        e",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::horizontal(XLabelsLayout::Horizontal, [
+        "y│                      ",
+        " │                      ",
+        "x│                      ",
+        " └──────────────────────",
+        " 0   1 2 3 4 5 6      70",
+    ])]
+    #[case::staggered(XLabelsLayout::Staggered, [
+        "y│                      ",
+        "x│                      ",
+        " └──────────────────────",
+        " 0    20  40  60        ",
+        "    10  30  50        70",
+    ])]
+    fn x_labels_layout<'line, Lines>(#[case] layout: XLabelsLayout, #[case] expected: Lines)
+    where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let labels = (0..8).map(|i| Span::from((i * 10).to_string())).collect();
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().labels(labels))
+            .y_axis(Axis::default().labels(["x", "y"].map(Span::from).to_vec()))
+            .x_labels_layout(layout);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 24, 5));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn staggered_x_labels_that_do_not_fit_are_skipped() {
+        let labels = ["0", "1000", "2000", "3000", "4"].map(Span::from).to_vec();
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().labels(labels))
+            .y_axis(Axis::default().labels(["x", "y"].map(Span::from).to_vec()))
+            .x_labels_layout(XLabelsLayout::Staggered);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 4));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "y│          ",
+            " └──────────",
+            " 0         4",
+            "            ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_and_subtitle_render_above_the_plot() {
+        let chart = Chart::new(vec![])
+            .title("Title")
+            .subtitle(Line::from("sub").alignment(Alignment::Right))
+            .x_axis(Axis::default().labels(["a", "b"].map(Span::from).to_vec()))
+            .y_axis(Axis::default().labels(["x", "y"].map(Span::from).to_vec()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 6));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "   Title   ",
+            "        sub",
+            "y│         ",
+            "x│         ",
+            " └─────────",
+            " a        b",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn titles_are_clipped_to_the_area() {
+        let chart = Chart::new(vec![]).title("Title").subtitle("sub");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" Title "]));
+    }
+
     #[test]
     fn dataset_legend_style_is_patched() {
         let long_dataset_name = Dataset::default().name("Very long name");
@@ -1255,6 +2343,257 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn legend_samples_match_dataset_markers() {
+        let widget = Chart::new(vec![
+            Dataset::default()
+                .name("a")
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .red(),
+            Dataset::default()
+                .name("b")
+                .marker(symbols::Marker::Bar)
+                .graph_type(GraphType::Line)
+                .blue(),
+            Dataset::default()
+                .name("c")
+                .graph_type(GraphType::PercentArea)
+                .green(),
+        ])
+        .legend_samples(true)
+        .hidden_legend_constraints((100.into(), 100.into()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "               ┌───┐",
+            "               │• a│",
+            "               │▄ b│",
+            "               │█ c│",
+            "               └───┘",
+        ]);
+        for (y, color) in [(1, Color::Red), (2, Color::Blue), (3, Color::Green)] {
+            expected.set_style(Rect::new(16, y, 1, 1), color);
+            expected.set_style(Rect::new(18, y, 1, 1), color);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn plot_background_fills_graph_area_only() {
+        let widget = Chart::new(vec![])
+            .x_axis(Axis::default().labels(vec!["0".into(), "1".into()]))
+            .y_axis(Axis::default().labels(vec!["0".into(), "1".into()]))
+            .plot_background(Color::Blue);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["1│    ", "0│    ", " └────", " 0   1"]);
+        expected.set_style(Rect::new(2, 0, 4, 2), Style::new().bg(Color::Blue));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn axis_ticks() {
+        let widget = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 8.0])
+                    .labels(vec!["0".into(), "8".into()])
+                    .ticks(5),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 4.0])
+                    .labels(vec!["0".into(), "4".into()])
+                    .ticks(5),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 11));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "4┤         ",
+                " │         ",
+                " ┤         ",
+                " │         ",
+                " ┤         ",
+                " │         ",
+                " ┤         ",
+                " │         ",
+                "0┤         ",
+                " └┬─┬─┬─┬─┬",
+                " 0        8",
+            ])
+        );
+    }
+
+    #[test]
+    fn cursor_crosshair_position() {
+        let widget = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([-5.0, 5.0]))
+            .cursor(Some((5.0, -3.0)))
+            .cursor_labels(false)
+            .cursor_style(Color::Yellow);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 6));
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "     │     ",
+            "     │     ",
+            "     │     ",
+            "     │     ",
+            "─────┼─────",
+            "     │     ",
+        ]);
+        expected.set_style(Rect::new(5, 0, 1, 6), Color::Yellow);
+        expected.set_style(Rect::new(0, 4, 11, 1), Color::Yellow);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn cursor_labels_near_axes() {
+        let widget = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 10.0])
+                    .labels(vec!["0".into(), "10".into()]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 4.0])
+                    .labels(vec!["0".into(), "4".into()]),
+            )
+            .cursor(Some((7.5, 2.0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 7));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "4│        │   ",
+                " │        │   ",
+                " │2───────┼───",
+                " │        │   ",
+                "0│        7.5 ",
+                " └────────────",
+                " 0          10",
+            ])
+        );
+    }
+
+    #[test]
+    fn cursor_out_of_bounds() {
+        let widget = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+            .cursor(Some((11.0, 5.0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 5, 5)));
+    }
+
+    #[test]
+    fn legend_max_width_truncates_long_names() {
+        let widget = Chart::new(vec![
+            Dataset::default().name("a very long name"),
+            Dataset::default().name("b"),
+        ])
+        .legend_max_width(8)
+        .hidden_legend_constraints((100.into(), 100.into()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        widget.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "            ┌──────┐",
+            "            │a ver…│",
+            "            │b     │",
+            "            └──────┘",
+            "                    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn legend_max_width_keeps_samples() {
+        let widget = Chart::new(vec![Dataset::default()
+            .name("a very long name")
+            .marker(symbols::Marker::Dot)
+            .red()])
+        .legend_samples(true)
+        .legend_max_width(8)
+        .hidden_legend_constraints((100.into(), 100.into()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 4));
+        widget.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "            ┌──────┐",
+            "            │• a v…│",
+            "            └──────┘",
+            "                    ",
+        ]);
+        expected.set_style(Rect::new(13, 1, 1, 1), Color::Red);
+        expected.set_style(Rect::new(15, 1, 4, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::bottom(LegendPosition::Bottom, [
+        "                    ",
+        "                    ",
+        "       ┌────┐       ",
+        "       │abcd│       ",
+        "       │efgh│       ",
+        "       │ij  │       ",
+        "       │k   │       ",
+        "       └────┘       ",
+    ])]
+    #[case::right(LegendPosition::Right, [
+        "                    ",
+        "                    ",
+        "              ┌────┐",
+        "              │abc…│",
+        "              │k   │",
+        "              └────┘",
+        "                    ",
+        "                    ",
+    ])]
+    fn legend_wrap_at_top_or_bottom(#[case] position: LegendPosition, #[case] expected: [&str; 8]) {
+        let widget = Chart::new(vec![
+            Dataset::default().name("abcdefghij"),
+            Dataset::default().name("k"),
+        ])
+        .legend_position(Some(position))
+        .legend_max_width(6)
+        .legend_wrap(true)
+        .hidden_legend_constraints((100.into(), 100.into()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 8));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::no_room_for_names(false, 2)]
+    #[case::no_room_after_samples(true, 4)]
+    fn legend_hidden_when_names_dont_fit(#[case] samples: bool, #[case] max_width: u16) {
+        let widget = Chart::new(vec![Dataset::default().name("a")])
+            .legend_samples(samples)
+            .legend_max_width(max_width)
+            .hidden_legend_constraints((100.into(), 100.into()));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        widget.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 10, 4)));
+    }
+
+    #[rstest]
+    #[case::dot(symbols::Marker::Dot, "•")]
+    #[case::block(symbols::Marker::Block, "█")]
+    #[case::bar(symbols::Marker::Bar, "▄")]
+    #[case::braille(symbols::Marker::Braille, "⣿")]
+    #[case::half_block(symbols::Marker::HalfBlock, "▀")]
+    fn legend_sample_uses_scatter_marker(#[case] marker: symbols::Marker, #[case] sample: &str) {
+        let dataset = Dataset::default()
+            .marker(marker)
+            .graph_type(GraphType::Scatter);
+        assert_eq!(dataset.legend_sample(), sample);
+    }
+
     #[test]
     fn test_chart_have_a_topleft_legend() {
         let chart = Chart::new(vec![Dataset::default().name("Ds1")])
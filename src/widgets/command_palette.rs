@@ -0,0 +1,555 @@
+use std::borrow::Cow;
+
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{
+        popup::{impl_styled, render_items, render_popup, Selection},
+        Block,
+    },
+};
+
+/// State of the [`CommandPalette`] widget
+///
+/// This state holds the query typed by the user, the indices of the commands matching the query
+/// and the selected command. The matching commands are only known once the palette is rendered,
+/// so [`CommandPaletteState::filtered`] and [`CommandPaletteState::selected_command`] reflect the
+/// last render.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = CommandPaletteState::default();
+/// state.push_char('o');
+/// state.push_char('p');
+/// state.select_next();
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommandPaletteState {
+    query: String,
+    filtered: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    selection: Selection,
+}
+
+impl CommandPaletteState {
+    /// Sets the query used to filter the commands
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_query<T: Into<String>>(mut self, query: T) -> Self {
+        self.set_query(query);
+        self
+    }
+
+    /// Sets the index of the selected command among the matching commands
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selection.selected = selected;
+        self
+    }
+
+    /// The query used to filter the commands
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Replaces the query used to filter the commands
+    ///
+    /// This selects the first matching command, as the matching commands change.
+    pub fn set_query<T: Into<String>>(&mut self, query: T) {
+        self.query = query.into();
+        self.reset_selection();
+    }
+
+    /// Appends a character to the query, e.g. when the user types it
+    ///
+    /// This selects the first matching command, as the matching commands change.
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.reset_selection();
+    }
+
+    /// Removes the last character of the query, e.g. when the user presses backspace
+    ///
+    /// This selects the first matching command, as the matching commands change.
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.reset_selection();
+    }
+
+    /// Clears the query, so that all the commands match
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+        self.reset_selection();
+    }
+
+    /// Indices of the commands matching the query, as of the last render
+    pub fn filtered(&self) -> &[usize] {
+        &self.filtered
+    }
+
+    /// Index of the first visible matching command
+    pub const fn offset(&self) -> usize {
+        self.selection.offset
+    }
+
+    /// Index of the selected command among the matching commands
+    ///
+    /// Returns `None` if no command is selected
+    pub const fn selected(&self) -> Option<usize> {
+        self.selection.selected
+    }
+
+    /// Index of the selected command among all the commands, as of the last render
+    ///
+    /// Returns `None` if no command is selected or no command matches the query
+    pub fn selected_command(&self) -> Option<usize> {
+        self.selection
+            .selected
+            .and_then(|i| self.filtered.get(i).copied())
+    }
+
+    /// Sets the index of the selected command among the matching commands
+    ///
+    /// Set to `None` if no command is selected. This will also reset the offset to `0`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selection.select(index);
+    }
+
+    /// Selects the next matching command or the first one if no command is selected
+    ///
+    /// Note: until the palette is rendered, the number of matching commands is not known, so the
+    /// index will be corrected when rendering
+    pub fn select_next(&mut self) {
+        self.selection.select_next();
+    }
+
+    /// Selects the previous matching command or the last one if no command is selected
+    ///
+    /// Note: until the palette is rendered, the number of matching commands is not known, so the
+    /// index will be corrected when rendering
+    pub fn select_previous(&mut self) {
+        self.selection.select_previous();
+    }
+
+    fn reset_selection(&mut self) {
+        self.selection = Selection {
+            offset: 0,
+            selected: Some(0),
+        };
+    }
+}
+
+/// A searchable list of commands drawn as an overlay, like the command palette of code editors.
+///
+/// The palette shows the query typed so far, followed by the commands matching it. A command
+/// matches when it contains all the characters of the query in order, ignoring case and the spaces
+/// of the query, e.g. `"opf"` matches `"Open File"`. The matching characters are styled with the
+/// [match style](CommandPalette::match_style) and the selected command with the
+/// [highlight style](CommandPalette::highlight_style).
+///
+/// The palette is centered in the area it is rendered in, which is [cleared](Clear) first, so the
+/// area given to render is usually the whole frame. At most
+/// [`max_height`](CommandPalette::max_height) commands are visible at once, scrolling to keep the
+/// selected command visible.
+///
+/// The query and the selection are stored in the [`CommandPaletteState`], which is updated by the
+/// application in response to key presses.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// let palette = CommandPalette::new(["Open File", "Close Window", "Toggle Panel"])
+///     .highlight_style(Style::new().reversed())
+///     .match_style(Style::new().bold());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = CommandPaletteState::default().with_query("op");
+///
+/// frame.render_stateful_widget(palette, frame.size(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CommandPalette<'a> {
+    /// The commands to choose from
+    items: Vec<Cow<'a, str>>,
+    /// Displayed in front of the query
+    prompt: Cow<'a, str>,
+    /// Width of the palette
+    width: u16,
+    /// Maximum number of visible commands
+    max_height: u16,
+    /// Block drawn around the palette
+    block: Option<Block<'a>>,
+    /// Base style of the palette
+    style: Style,
+    /// Style of the selected command
+    highlight_style: Style,
+    /// Style of the characters of the commands matching the query
+    match_style: Style,
+}
+
+impl Default for CommandPalette<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            prompt: Cow::Borrowed("> "),
+            width: 50,
+            max_height: 10,
+            block: Some(Block::bordered()),
+            style: Style::new(),
+            highlight_style: Style::new(),
+            match_style: Style::new(),
+        }
+    }
+}
+
+impl<'a> CommandPalette<'a> {
+    /// Creates a new palette with the given commands
+    ///
+    /// `items` is a collection of anything convertible to a `Cow<str>`, e.g. `&str` or `String`.
+    pub fn new<I, T>(items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the commands
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the text displayed in front of the query
+    ///
+    /// The default is `"> "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn prompt<T: Into<Cow<'a, str>>>(mut self, prompt: T) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the width of the palette, including its block
+    ///
+    /// The default is 50. The palette is narrowed to fit in the area it is rendered in.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum number of visible commands
+    ///
+    /// The default is 10.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_height(mut self, max_height: u16) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the block drawn around the palette
+    ///
+    /// The default is a bordered block. Use `None` to draw the palette without a block.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Option<Block<'a>>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the base style of the palette
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected command
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the characters of the commands matching the query
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.match_style = style.into();
+        self
+    }
+
+    /// Returns the area of the palette centered within the given area
+    fn popup_area(&self, area: Rect, visible_items: usize) -> Rect {
+        // the query line is above the items
+        let content_height = u16::try_from(visible_items)
+            .unwrap_or(u16::MAX)
+            .min(self.max_height)
+            .saturating_add(1);
+        let height = self.block.as_ref().map_or(content_height, |block| {
+            block.outer_size(self.width, content_height).1
+        });
+        let (width, height) = (self.width.min(area.width), height.min(area.height));
+        let x = area.x + (area.width - width) / 2;
+        let y = area.y + (area.height - height) / 2;
+        Rect::new(x, y, width, height)
+    }
+
+    /// Returns the command as a line with the characters matching the query styled
+    fn command_line(&self, item: &str, query: &str) -> Line<'static> {
+        let matches = fuzzy_match(item, query).unwrap_or_default();
+        let mut spans: Vec<Span<'static>> = vec![];
+        for (i, c) in item.char_indices() {
+            let style = if matches.contains(&i) {
+                self.match_style
+            } else {
+                Style::new()
+            };
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push(c),
+                _ => spans.push(Span::styled(c.to_string(), style)),
+            }
+        }
+        Line::from(spans)
+    }
+}
+
+/// Returns the byte indices of the characters of `item` matching the characters of `query` in
+/// order, ignoring case and the spaces of the query, or `None` if `item` doesn't match.
+fn fuzzy_match(item: &str, query: &str) -> Option<Vec<usize>> {
+    let mut query = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut indices = vec![];
+    for (i, c) in item.char_indices() {
+        let Some(&q) = query.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(q.to_lowercase()) {
+            indices.push(i);
+            query.next();
+        }
+    }
+    query.peek().is_none().then_some(indices)
+}
+
+impl Widget for CommandPalette<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl WidgetRef for CommandPalette<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = CommandPaletteState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for CommandPalette<'_> {
+    type State = CommandPaletteState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized, the blanket impl is used with the
+// unstable-widget-ref feature
+#[cfg(not(feature = "unstable-widget-ref"))]
+impl StatefulWidget for &CommandPalette<'_> {
+    type State = CommandPaletteState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for CommandPalette<'_> {
+    type State = CommandPaletteState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.filtered = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| fuzzy_match(item, &state.query).is_some())
+            .map(|(i, _)| i)
+            .collect();
+
+        // Select the first matching command by default, and clamp the selection to the last one
+        state.selection.selected.get_or_insert(0);
+        state.selection.clamp(state.filtered.len());
+
+        let popup_area = self.popup_area(area, state.filtered.len());
+        if popup_area.is_empty() {
+            return;
+        }
+        let inner = render_popup(self.block.as_ref(), self.style, popup_area, buf);
+        if inner.is_empty() {
+            return;
+        }
+
+        let query = Line::from(vec![
+            Span::raw(self.prompt.as_ref()),
+            Span::raw(state.query.as_str()),
+        ]);
+        query.render(Rect { height: 1, ..inner }, buf);
+
+        let items_area = Rect {
+            y: inner.y + 1,
+            height: inner.height - 1,
+            ..inner
+        };
+        render_items(
+            &mut state.selection,
+            state.filtered.len(),
+            |index| self.command_line(&self.items[state.filtered[index]], &state.query),
+            self.highlight_style,
+            items_area,
+            buf,
+        );
+    }
+}
+
+impl_styled!(CommandPalette);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMANDS: [&str; 4] = ["Open File", "Close Window", "Toggle Panel", "Copy Path"];
+
+    #[test]
+    fn fuzzy_match_ignores_case_and_spaces() {
+        assert_eq!(fuzzy_match("Open File", "opf"), Some(vec![0, 1, 5]));
+        assert_eq!(fuzzy_match("Open File", "o f"), Some(vec![0, 5]));
+        assert_eq!(fuzzy_match("Open File", ""), Some(vec![]));
+        assert_eq!(fuzzy_match("Close Window", "op"), None);
+    }
+
+    #[test]
+    fn render_all_commands_without_query() {
+        let palette = CommandPalette::new(COMMANDS).width(16);
+        let mut state = CommandPaletteState::default();
+        let mut buf = Buffer::with_lines(["xxxxxxxxxxxxxxxxxx"; 8]);
+        StatefulWidget::render(&palette, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "x┌──────────────┐x",
+                "x│>             │x",
+                "x│Open File     │x",
+                "x│Close Window  │x",
+                "x│Toggle Panel  │x",
+                "x│Copy Path     │x",
+                "x└──────────────┘x",
+                "xxxxxxxxxxxxxxxxxx",
+            ])
+        );
+        assert_eq!(state.filtered(), [0, 1, 2, 3]);
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn typing_filters_and_highlights_matches() {
+        let palette = CommandPalette::new(COMMANDS)
+            .width(16)
+            .highlight_style(Style::new().reversed())
+            .match_style(Style::new().bold());
+        let mut state = CommandPaletteState::default();
+        state.push_char('o');
+        state.push_char('p');
+        state.select_next();
+        let mut buf = Buffer::with_lines(["xxxxxxxxxxxxxxxxxx"; 7]);
+        StatefulWidget::render(&palette, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "x┌──────────────┐x",
+            "x│> op          │x",
+            "x│Open File     │x",
+            "x│Toggle Panel  │x",
+            "x│Copy Path     │x",
+            "x└──────────────┘x",
+            "xxxxxxxxxxxxxxxxxx",
+        ]);
+        expected.set_style(Rect::new(2, 2, 2, 1), Style::new().bold());
+        expected.set_style(Rect::new(3, 3, 1, 1), Style::new().bold());
+        expected.set_style(Rect::new(9, 3, 1, 1), Style::new().bold());
+        expected.set_style(Rect::new(3, 4, 2, 1), Style::new().bold());
+        expected.set_style(Rect::new(2, 3, 14, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+        assert_eq!(state.filtered(), [0, 2, 3]);
+        assert_eq!(state.selected_command(), Some(2));
+
+        state.pop_char();
+        assert_eq!(state.query(), "o");
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn selection_scrolls_and_is_clamped() {
+        let palette = CommandPalette::new(COMMANDS).width(16).max_height(2);
+        let mut state = CommandPaletteState::default().with_selected(Some(10));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 5));
+        StatefulWidget::render(&palette, buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), Some(3));
+        assert_eq!(state.offset(), 2);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "┌──────────────┐",
+                "│>             │",
+                "│Toggle Panel  │",
+                "│Copy Path     │",
+                "└──────────────┘",
+            ])
+        );
+    }
+
+    #[test]
+    fn no_selection_without_matches() {
+        let palette = CommandPalette::new(COMMANDS);
+        let mut state = CommandPaletteState::default().with_query("xyz");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 5));
+        StatefulWidget::render(&palette, buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), None);
+        assert_eq!(state.selected_command(), None);
+    }
+}
@@ -0,0 +1,591 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bitflags::bitflags;
+
+use crate::{prelude::*, style::Styled, symbols::line, widgets::Block};
+
+/// A widget to draw simple node-and-edge diagrams
+///
+/// Each node is a [`Block`] rendered in a [`Rect`] relative to the area of the diagram. Nodes are
+/// connected by [`Edge`]s which are drawn as orthogonal lines using box drawing characters. Edges
+/// start and end at an [`Anchor`] (the middle of one side of a node). Where an edge meets the
+/// border of a node, or another edge, the glyphs are merged into corners, T-junctions and crosses.
+///
+/// Edges are routed with at most two bends:
+/// - between a horizontal and a vertical anchor, the edge is L-shaped
+/// - between two horizontal (or two vertical) anchors, the edge bends halfway between them
+///
+/// When such a path would cross a node, the edge is routed around the nodes instead, using the
+/// path with the fewest bends (and then the shortest one) that fits in the diagram area.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let diagram = Diagram::new()
+///     .node(Rect::new(0, 0, 9, 3), Block::bordered().title("Start"))
+///     .node(Rect::new(12, 4, 7, 3), Block::bordered().title("End"))
+///     .edge(Edge::new(0, Anchor::Right, 1, Anchor::Top));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Diagram<'a> {
+    /// The nodes of the diagram and where they are rendered, relative to the diagram area
+    nodes: Vec<(Rect, Block<'a>)>,
+    /// The connections between the nodes
+    edges: Vec<Edge>,
+    /// Style of the edges
+    style: Style,
+    /// Symbols used to draw the edges
+    line_set: line::Set,
+}
+
+/// A side of a node where an [`Edge`] is attached
+///
+/// The edge is attached to the middle of the given side.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Anchor {
+    /// The top border of the node
+    Top,
+    /// The bottom border of the node
+    Bottom,
+    /// The left border of the node
+    Left,
+    /// The right border of the node. This is the default.
+    #[default]
+    Right,
+}
+
+/// A connection between two nodes of a [`Diagram`]
+///
+/// Nodes are referred to by their index, in the order they were added to the diagram.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Edge {
+    from: (usize, Anchor),
+    to: (usize, Anchor),
+}
+
+bitflags! {
+    /// The directions a cell of a line connects to
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+    struct Directions: u8 {
+        const UP = 0b0001;
+        const DOWN = 0b0010;
+        const LEFT = 0b0100;
+        const RIGHT = 0b1000;
+    }
+}
+
+impl Edge {
+    /// Creates a new edge from the `from_anchor` side of the node at index `from` to the
+    /// `to_anchor` side of the node at index `to`
+    pub const fn new(from: usize, from_anchor: Anchor, to: usize, to_anchor: Anchor) -> Self {
+        Self {
+            from: (from, from_anchor),
+            to: (to, to_anchor),
+        }
+    }
+}
+
+impl Anchor {
+    /// Returns the position of the anchor on the given node area
+    const fn position(self, area: Rect) -> Position {
+        let (x, y) = match self {
+            Self::Top => (area.x.saturating_add(area.width / 2), area.y),
+            Self::Bottom => (
+                area.x.saturating_add(area.width / 2),
+                area.bottom().saturating_sub(1),
+            ),
+            Self::Left => (area.x, area.y.saturating_add(area.height / 2)),
+            Self::Right => (
+                area.right().saturating_sub(1),
+                area.y.saturating_add(area.height / 2),
+            ),
+        };
+        Position { x, y }
+    }
+
+    const fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    const fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Returns the neighbor of `position` on this side, if any
+    fn next(self, position: Position) -> Option<Position> {
+        let Position { x, y } = position;
+        match self {
+            Self::Top => Some(Position::new(x, y.checked_sub(1)?)),
+            Self::Bottom => Some(Position::new(x, y.checked_add(1)?)),
+            Self::Left => Some(Position::new(x.checked_sub(1)?, y)),
+            Self::Right => Some(Position::new(x.checked_add(1)?, y)),
+        }
+    }
+}
+
+impl<'a> Diagram<'a> {
+    /// Creates a new empty diagram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node rendered as the given [`Block`] in `area`
+    ///
+    /// The area is relative to the area the diagram is rendered in. Nodes are referred to by
+    /// [`Edge`]s using the order in which they are added, starting at 0.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn node(mut self, area: Rect, block: Block<'a>) -> Self {
+        self.nodes.push((area, block));
+        self
+    }
+
+    /// Adds an [`Edge`] between two nodes
+    ///
+    /// Edges referring to nodes that don't exist are ignored.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edges.push(edge);
+        self
+    }
+
+    /// Sets the style of the edges
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the symbols used to draw the edges
+    ///
+    /// The default is [`line::NORMAL`]. Borders of the nodes drawn using the same set (or the
+    /// normal set) are merged with the edges.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_set(mut self, line_set: line::Set) -> Self {
+        self.line_set = line_set;
+        self
+    }
+
+    /// Returns the points of the path of an edge, including its start and end
+    ///
+    /// `nodes` are the areas of the nodes in the buffer.
+    fn route(edge: Edge, nodes: &[Rect], area: Rect) -> Option<Vec<Position>> {
+        let (from_anchor, to_anchor) = (edge.from.1, edge.to.1);
+        let start = from_anchor.position(*nodes.get(edge.from.0)?);
+        let end = to_anchor.position(*nodes.get(edge.to.0)?);
+        let path = match (from_anchor.is_horizontal(), to_anchor.is_horizontal()) {
+            (true, false) => vec![start, Position::new(end.x, start.y), end],
+            (false, true) => vec![start, Position::new(start.x, end.y), end],
+            (true, true) => {
+                let x = start.x.min(end.x) + start.x.abs_diff(end.x) / 2;
+                vec![
+                    start,
+                    Position::new(x, start.y),
+                    Position::new(x, end.y),
+                    end,
+                ]
+            }
+            (false, false) => {
+                let y = start.y.min(end.y) + start.y.abs_diff(end.y) / 2;
+                vec![
+                    start,
+                    Position::new(start.x, y),
+                    Position::new(end.x, y),
+                    end,
+                ]
+            }
+        };
+        let crosses_node = path
+            .windows(2)
+            .flat_map(|segment| segment_cells(segment[0], segment[1]))
+            .filter(|&position| position != start && position != end)
+            .any(|position| nodes.iter().any(|node| node.contains(position)));
+        if crosses_node {
+            let detour = Self::route_around((start, from_anchor), (end, to_anchor), nodes, area);
+            return Some(detour.unwrap_or(path));
+        }
+        Some(path)
+    }
+
+    /// Returns the path with the fewest bends, and then the shortest one, between two anchors
+    /// which doesn't cross any node and stays in `area`
+    fn route_around(
+        (start, from_anchor): (Position, Anchor),
+        (end, to_anchor): (Position, Anchor),
+        nodes: &[Rect],
+        area: Rect,
+    ) -> Option<Vec<Position>> {
+        const HEADINGS: [Anchor; 4] = [Anchor::Top, Anchor::Bottom, Anchor::Left, Anchor::Right];
+        let heading_index = |heading: Anchor| HEADINGS.iter().position(|&h| h == heading);
+        let is_free = |position: Position| {
+            area.contains(position) && !nodes.iter().any(|node| node.contains(position))
+        };
+        // the edge leaves the start node outward and enters the end node inward
+        let first_heading = heading_index(from_anchor)?;
+        let last_heading = heading_index(to_anchor.opposite())?;
+        let first = from_anchor
+            .next(start)
+            .filter(|&position| is_free(position))?;
+        if !area.contains(end) {
+            return None;
+        }
+
+        let index = |position: Position, heading: usize| {
+            let (x, y) = (position.x - area.x, position.y - area.y);
+            (usize::from(y) * usize::from(area.width) + usize::from(x)) * HEADINGS.len() + heading
+        };
+        let len = usize::from(area.area()) * HEADINGS.len();
+        let mut costs = vec![(u32::MAX, u32::MAX); len];
+        let mut previous: Vec<Option<(Position, usize)>> = vec![None; len];
+        let mut queue = BinaryHeap::new();
+        costs[index(first, first_heading)] = (0, 1);
+        queue.push(Reverse((0, 1, first, first_heading)));
+        while let Some(Reverse((bends, length, position, heading))) = queue.pop() {
+            if (bends, length) > costs[index(position, heading)] {
+                continue;
+            }
+            if position == end {
+                let mut path = vec![end];
+                let mut state = previous[index(end, heading)];
+                while let Some((position, heading)) = state {
+                    path.push(position);
+                    state = previous[index(position, heading)];
+                }
+                path.push(start);
+                path.reverse();
+                // only keep the corners
+                let mut corners = vec![start];
+                for points in path.windows(3) {
+                    if points[0].x != points[2].x && points[0].y != points[2].y {
+                        corners.push(points[1]);
+                    }
+                }
+                corners.push(end);
+                return Some(corners);
+            }
+            for (next_heading, direction) in HEADINGS.into_iter().enumerate() {
+                if direction == HEADINGS[heading].opposite() {
+                    continue;
+                }
+                let Some(next) = direction.next(position) else {
+                    continue;
+                };
+                let enters_end = next == end && next_heading == last_heading;
+                if !enters_end && !is_free(next) {
+                    continue;
+                }
+                let cost = (bends + u32::from(next_heading != heading), length + 1);
+                let next_index = index(next, next_heading);
+                if cost < costs[next_index] {
+                    costs[next_index] = cost;
+                    previous[next_index] = Some((position, heading));
+                    queue.push(Reverse((cost.0, cost.1, next, next_heading)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the directions the given symbol connects to, if it is a line symbol
+    fn directions(&self, symbol: &str) -> Option<Directions> {
+        [self.line_set, line::NORMAL, line::ROUNDED]
+            .iter()
+            .find_map(|set| {
+                let directions = if symbol == set.vertical {
+                    Directions::UP | Directions::DOWN
+                } else if symbol == set.horizontal {
+                    Directions::LEFT | Directions::RIGHT
+                } else if symbol == set.top_left {
+                    Directions::DOWN | Directions::RIGHT
+                } else if symbol == set.top_right {
+                    Directions::DOWN | Directions::LEFT
+                } else if symbol == set.bottom_left {
+                    Directions::UP | Directions::RIGHT
+                } else if symbol == set.bottom_right {
+                    Directions::UP | Directions::LEFT
+                } else if symbol == set.vertical_left {
+                    Directions::UP | Directions::DOWN | Directions::LEFT
+                } else if symbol == set.vertical_right {
+                    Directions::UP | Directions::DOWN | Directions::RIGHT
+                } else if symbol == set.horizontal_down {
+                    Directions::LEFT | Directions::RIGHT | Directions::DOWN
+                } else if symbol == set.horizontal_up {
+                    Directions::LEFT | Directions::RIGHT | Directions::UP
+                } else if symbol == set.cross {
+                    Directions::all()
+                } else {
+                    return None;
+                };
+                Some(directions)
+            })
+    }
+
+    /// Returns the symbol that connects to the given directions
+    fn symbol(&self, directions: Directions) -> &'static str {
+        const UP_DOWN: Directions = Directions::UP.union(Directions::DOWN);
+        const LEFT_RIGHT: Directions = Directions::LEFT.union(Directions::RIGHT);
+        let set = self.line_set;
+        match directions {
+            d if d == Directions::all() => set.cross,
+            d if d == UP_DOWN | Directions::LEFT => set.vertical_left,
+            d if d == UP_DOWN | Directions::RIGHT => set.vertical_right,
+            d if d == LEFT_RIGHT | Directions::DOWN => set.horizontal_down,
+            d if d == LEFT_RIGHT | Directions::UP => set.horizontal_up,
+            d if d == Directions::DOWN | Directions::RIGHT => set.top_left,
+            d if d == Directions::DOWN | Directions::LEFT => set.top_right,
+            d if d == Directions::UP | Directions::RIGHT => set.bottom_left,
+            d if d == Directions::UP | Directions::LEFT => set.bottom_right,
+            d if d.intersects(UP_DOWN) => set.vertical,
+            _ => set.horizontal,
+        }
+    }
+
+    /// Adds the given directions to the cell at `position`, merging them with the line symbol
+    /// already in the cell if any
+    fn connect(&self, position: Position, directions: Directions, area: Rect, buf: &mut Buffer) {
+        if directions.is_empty() || !area.contains(position) {
+            return;
+        }
+        let cell = buf.get_mut(position.x, position.y);
+        let directions = self
+            .directions(cell.symbol())
+            .map_or(directions, |existing| existing | directions);
+        cell.set_symbol(self.symbol(directions))
+            .set_style(self.style);
+    }
+
+    fn render_edge(&self, path: &[Position], area: Rect, buf: &mut Buffer) {
+        // The directions of the cells where two segments meet are combined before being merged
+        // with the buffer, as a dangling end of a segment can't be told apart from a full line
+        let mut cells: Vec<(Position, Directions)> = vec![];
+        let mut push = |position: Position, directions: Directions| match cells.last_mut() {
+            Some((last, last_directions)) if *last == position => *last_directions |= directions,
+            _ => cells.push((position, directions)),
+        };
+        for segment in path.windows(2) {
+            let (from, to) = (segment[0], segment[1]);
+            let (left, right) = (from.x.min(to.x), from.x.max(to.x));
+            let (top, bottom) = (from.y.min(to.y), from.y.max(to.y));
+            for position in segment_cells(from, to) {
+                let mut directions = Directions::empty();
+                if from.y == to.y {
+                    directions.set(Directions::LEFT, position.x > left);
+                    directions.set(Directions::RIGHT, position.x < right);
+                } else {
+                    directions.set(Directions::UP, position.y > top);
+                    directions.set(Directions::DOWN, position.y < bottom);
+                }
+                push(position, directions);
+            }
+        }
+        for (position, directions) in cells {
+            self.connect(position, directions, area, buf);
+        }
+    }
+}
+
+/// Returns the cells of a horizontal or vertical segment, from `from` to `to` included
+fn segment_cells(from: Position, to: Position) -> impl Iterator<Item = Position> {
+    let steps = from.x.abs_diff(to.x).max(from.y.abs_diff(to.y));
+    (0..=steps).map(move |step| {
+        Position::new(
+            step_toward(from.x, to.x, step),
+            step_toward(from.y, to.y, step),
+        )
+    })
+}
+
+/// Returns the area of a node in the buffer, given its area relative to the diagram area
+fn node_area(node: Rect, area: Rect) -> Rect {
+    Rect::new(
+        area.x.saturating_add(node.x),
+        area.y.saturating_add(node.y),
+        node.width,
+        node.height,
+    )
+}
+
+/// Moves `step` cells from `from` toward `to`, without going past `to`
+const fn step_toward(from: u16, to: u16, step: u16) -> u16 {
+    if from <= to {
+        let position = from.saturating_add(step);
+        if position < to {
+            position
+        } else {
+            to
+        }
+    } else {
+        let position = from.saturating_sub(step);
+        if position > to {
+            position
+        } else {
+            to
+        }
+    }
+}
+
+impl Widget for Diagram<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Diagram<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let nodes: Vec<Rect> = self
+            .nodes
+            .iter()
+            .map(|(node, _)| node_area(*node, area))
+            .collect();
+        for (node, (_, block)) in nodes.iter().zip(&self.nodes) {
+            block.render_ref(node.intersection(area), buf);
+        }
+        for edge in &self.edges {
+            if let Some(path) = Self::route(*edge, &nodes, area) {
+                self.render_edge(&path, area, buf);
+            }
+        }
+    }
+}
+
+impl Styled for Diagram<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn l_shaped_edge() {
+        let diagram = Diagram::new()
+            .node(Rect::new(0, 0, 5, 3), Block::bordered())
+            .node(Rect::new(8, 4, 5, 3), Block::bordered())
+            .edge(Edge::new(0, Anchor::Right, 1, Anchor::Top));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 7));
+        diagram.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┐        ",
+            "│   ├─────┐  ",
+            "└───┘     │  ",
+            "          │  ",
+            "        ┌─┴─┐",
+            "        │   │",
+            "        └───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn edge_between_parallel_anchors() {
+        let diagram = Diagram::new()
+            .node(Rect::new(0, 0, 3, 3), Block::bordered())
+            .node(Rect::new(7, 2, 3, 3), Block::bordered())
+            .edge(Edge::new(0, Anchor::Right, 1, Anchor::Left));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        diagram.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌─┐       ",
+            "│ ├─┐     ",
+            "└─┘ │  ┌─┐",
+            "    └──┤ │",
+            "       └─┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn crossing_edges_are_merged() {
+        let diagram = Diagram::new()
+            .node(Rect::new(2, 0, 1, 1), Block::new())
+            .node(Rect::new(2, 4, 1, 1), Block::new())
+            .node(Rect::new(0, 2, 1, 1), Block::new())
+            .node(Rect::new(4, 2, 1, 1), Block::new())
+            .edge(Edge::new(0, Anchor::Bottom, 1, Anchor::Top))
+            .edge(Edge::new(2, Anchor::Right, 3, Anchor::Left));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        diagram.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "  │  ",
+            "  │  ",
+            "──┼──",
+            "  │  ",
+            "  │  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn edge_is_routed_around_nodes() {
+        let diagram = Diagram::new()
+            .node(Rect::new(0, 2, 3, 3), Block::bordered())
+            .node(Rect::new(5, 1, 3, 5), Block::bordered())
+            .node(Rect::new(10, 2, 3, 3), Block::bordered())
+            .edge(Edge::new(0, Anchor::Right, 2, Anchor::Left));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 7));
+        diagram.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "             ",
+            "     ┌─┐     ",
+            "┌─┐  │ │  ┌─┐",
+            "│ ├─┐│ │ ┌┤ │",
+            "└─┘ ││ │ │└─┘",
+            "    │└─┘ │   ",
+            "    └────┘   ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn nodes_are_clipped_to_the_area() {
+        let diagram = Diagram::new()
+            .node(Rect::new(0, 0, 3, 3), Block::bordered())
+            .node(Rect::new(u16::MAX, u16::MAX, 3, 3), Block::bordered())
+            .edge(Edge::new(0, Anchor::Right, 1, Anchor::Left));
+        let area = Rect::new(100, 100, 3, 3);
+        let mut buffer = Buffer::empty(area);
+        diagram.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌─┐", "│ ├", "└─┘"]);
+        expected.area = area;
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn edges_to_missing_nodes_are_ignored() {
+        let diagram = Diagram::new()
+            .node(Rect::new(0, 0, 3, 3), Block::bordered())
+            .edge(Edge::new(0, Anchor::Right, 1, Anchor::Left));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        diagram.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌─┐", "│ │", "└─┘"]));
+    }
+}
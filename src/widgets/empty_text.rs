@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+/// Renders the placeholder of an empty list or table, if any, centered in the given area
+pub fn render_empty_text(text: Option<&Text>, area: Rect, buf: &mut Buffer) {
+    let Some(text) = text else {
+        return;
+    };
+    let height = (text.height() as u16).min(area.height);
+    let text_area = Rect {
+        y: area.y + (area.height - height) / 2,
+        height,
+        ..area
+    };
+    let alignment = text.alignment.unwrap_or(Alignment::Center);
+    text.clone().alignment(alignment).render(text_area, buf);
+}
@@ -6,8 +6,10 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 /// [`Gauge::ratio`]. The bar width and height are defined by the [`Rect`] it is
 /// [rendered](Widget::render) in.
 ///
-/// The associated label is always centered horizontally and vertically. If not set with
-/// [`Gauge::label`], the label is the percentage of the bar filled.
+/// The associated label is centered vertically and, by default, horizontally. It can be aligned to
+/// the left or right with [`Gauge::label_alignment`] or hidden with [`Gauge::show_label`]. If not
+/// set with [`Gauge::label`], the label is the percentage of the bar filled, or a fraction such as
+/// `3/10` with [`Gauge::label_format`].
 ///
 /// You might want to have a higher precision bar using [`Gauge::use_unicode`].
 ///
@@ -33,14 +35,53 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 ///
 /// - [`LineGauge`] for a thin progress bar
 #[allow(clippy::struct_field_names)] // gauge_style needs to be differentiated to style
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
     label: Option<Span<'a>>,
+    label_alignment: Alignment,
+    show_label: bool,
+    label_format: LabelFormat,
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    gradient: Vec<(f64, Color)>,
+}
+
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            label_alignment: Alignment::Center,
+            show_label: true,
+            label_format: LabelFormat::Percentage,
+            use_unicode: false,
+            style: Style::default(),
+            gauge_style: Style::default(),
+            gradient: Vec::new(),
+        }
+    }
+}
+
+/// The format of the default label of a [`Gauge`]
+///
+/// See [`Gauge::label_format`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LabelFormat {
+    /// The percentage of the bar filled, e.g. `30%`. This is the **default**.
+    #[default]
+    Percentage,
+    /// The filled part of a total count, e.g. `3/10`
+    ///
+    /// The current count is the ratio of the gauge multiplied by the total, rounded to the nearest
+    /// integer.
+    Fraction {
+        /// The count represented by a full bar
+        total: u64,
+    },
 }
 
 impl<'a> Gauge<'a> {
@@ -95,9 +136,9 @@ impl<'a> Gauge<'a> {
         self
     }
 
-    /// Sets the label to display in the center of the bar.
+    /// Sets the label to display in the bar.
     ///
-    /// For a left-aligned label, see [`LineGauge`].
+    /// The label is centered by default, see [`Gauge::label_alignment`].
     /// If the label is not defined, it is the percentage filled.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn label<T>(mut self, label: T) -> Self
@@ -108,6 +149,56 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets the horizontal alignment of the label in the bar.
+    ///
+    /// The label is always on the middle row of the bar. It is centered by default. The part of
+    /// the label drawn over the filled part of the bar has its colors inverted so that it stays
+    /// readable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let gauge = Gauge::default()
+    ///     .percent(40)
+    ///     .label_alignment(Alignment::Right);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_alignment(mut self, alignment: Alignment) -> Self {
+        self.label_alignment = alignment;
+        self
+    }
+
+    /// Sets the format of the label displayed when no label is set with [`Gauge::label`].
+    ///
+    /// The label is the percentage of the bar filled by default. With [`LabelFormat::Fraction`],
+    /// it is the filled part of the given total instead, which is more meaningful for gauges that
+    /// represent counts. The bar is still filled according to the ratio.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // shows `3/10`
+    /// let gauge = Gauge::default()
+    ///     .ratio(0.3)
+    ///     .label_format(LabelFormat::Fraction { total: 10 });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_format(mut self, format: LabelFormat) -> Self {
+        self.label_format = format;
+        self
+    }
+
+    /// Sets whether the label is displayed.
+    ///
+    /// The label is shown by default. When hidden, the whole bar is filled with the gauge symbols.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_label(mut self, show_label: bool) -> Self {
+        self.show_label = show_label;
+        self
+    }
+
     /// Sets the widget style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -141,6 +232,35 @@ impl<'a> Gauge<'a> {
         self.use_unicode = unicode;
         self
     }
+
+    /// Sets a gradient of colors to fill the bar with.
+    ///
+    /// `stops` are `(ratio, color)` pairs where the ratio is the position of the color along the
+    /// bar, between `0.0` (left edge) and `1.0` (right edge). Each filled cell gets the color of
+    /// the gradient at its position as its foreground color, instead of the foreground color of
+    /// the [gauge style](Gauge::gauge_style). The color is interpolated between the surrounding
+    /// stops when both are [`Color::Rgb`] colors. Other colors (e.g. [`Color::Indexed`]) can't be
+    /// interpolated and are used unchanged up to the next stop.
+    ///
+    /// An empty gradient (the default) fills the bar with the gauge style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // a heat indicator going from green to red through yellow
+    /// let gauge = Gauge::default().ratio(0.8).gradient(vec![
+    ///     (0.0, Color::Rgb(0, 255, 0)),
+    ///     (0.5, Color::Rgb(255, 255, 0)),
+    ///     (1.0, Color::Rgb(255, 0, 0)),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient(mut self, mut stops: Vec<(f64, Color)>) -> Self {
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self.gradient = stops;
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
@@ -167,11 +287,22 @@ impl Gauge<'_> {
         buf.set_style(gauge_area, self.gauge_style);
 
         // compute label value and its position
-        // label is put at the center of the gauge_area
-        let default_label = Span::raw(format!("{}%", f64::round(self.ratio * 100.0)));
+        // label is put on the middle row of the gauge_area, aligned horizontally
+        let default_label = match self.label_format {
+            LabelFormat::Percentage => Span::raw(format!("{}%", f64::round(self.ratio * 100.0))),
+            LabelFormat::Fraction { total } => {
+                let current = f64::round(self.ratio * total as f64);
+                Span::raw(format!("{current}/{total}"))
+            }
+        };
         let label = self.label.as_ref().unwrap_or(&default_label);
         let clamped_label_width = gauge_area.width.min(label.width() as u16);
-        let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
+        let label_offset = match self.label_alignment {
+            Alignment::Left => 0,
+            Alignment::Center => (gauge_area.width - clamped_label_width) / 2,
+            Alignment::Right => gauge_area.width - clamped_label_width,
+        };
+        let label_col = gauge_area.left() + label_offset;
         let label_row = gauge_area.top() + gauge_area.height / 2;
 
         // the gauge will be filled proportionally to the ratio
@@ -184,27 +315,87 @@ impl Gauge<'_> {
         for y in gauge_area.top()..gauge_area.bottom() {
             // render the filled area (left to end)
             for x in gauge_area.left()..end {
+                let fg = self.fill_color(gauge_area, x);
                 let cell = buf.get_mut(x, y);
                 // Use full block for the filled part of the gauge and spaces for the part that is
                 // covered by the label. Note that the background and foreground colors are swapped
                 // for the label part, otherwise the gauge will be inverted
-                if x < label_col || x > label_col + clamped_label_width || y != label_row {
+                if !self.show_label
+                    || x < label_col
+                    || x > label_col + clamped_label_width
+                    || y != label_row
+                {
                     cell.set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
+                        .set_fg(fg)
                         .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
                 } else {
                     cell.set_symbol(" ")
                         .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                        .set_bg(fg);
                 }
             }
             if self.use_unicode && self.ratio < 1.0 {
-                buf.get_mut(end, y)
+                let fg = self.fill_color(gauge_area, end);
+                let cell = buf
+                    .get_mut(end, y)
                     .set_symbol(get_unicode_block(filled_width % 1.0));
+                if !self.gradient.is_empty() {
+                    cell.set_fg(fg);
+                }
             }
         }
         // render the label
-        buf.set_span(label_col, label_row, label, clamped_label_width);
+        if self.show_label {
+            buf.set_span(label_col, label_row, label, clamped_label_width);
+        }
+    }
+}
+
+impl Gauge<'_> {
+    /// The foreground color of the filled cells of the given column
+    ///
+    /// This is the color of the gradient at the position of the column in the bar if there is a
+    /// gradient, or the foreground color of the gauge style otherwise.
+    fn fill_color(&self, gauge_area: Rect, x: u16) -> Color {
+        let Some(&(first_stop, first_color)) = self.gradient.first() else {
+            return self.gauge_style.fg.unwrap_or(Color::Reset);
+        };
+        let position = if gauge_area.width > 1 {
+            f64::from(x - gauge_area.left()) / f64::from(gauge_area.width - 1)
+        } else {
+            0.0
+        };
+        if position <= first_stop {
+            return first_color;
+        }
+        self.gradient
+            .windows(2)
+            .find(|stops| position < stops[1].0)
+            .map_or_else(
+                || self.gradient[self.gradient.len() - 1].1,
+                |stops| interpolate_color(stops[0], stops[1], position),
+            )
+    }
+}
+
+/// The color at `position` between two gradient stops
+///
+/// Only rgb colors are interpolated, other colors are returned unchanged up to the next stop.
+fn interpolate_color(
+    (from_stop, from): (f64, Color),
+    (to_stop, to): (f64, Color),
+    position: f64,
+) -> Color {
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let progress = (position - from_stop) / (to_stop - from_stop);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let channel = |from: u8, to: u8| {
+                (f64::from(from) + (f64::from(to) - f64::from(from)) * progress).round() as u8
+            };
+            Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+        }
+        _ => from,
     }
 }
 
@@ -233,6 +424,9 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 /// The associated label is always left-aligned. If not set with [`LineGauge::label`], the label is
 /// the percentage of the bar filled.
 ///
+/// The gauge can also be drawn vertically, e.g. for level meters in a sidebar, with
+/// [`LineGauge::direction`].
+///
 /// You can also set the symbols used to draw the bar with [`LineGauge::line_set`].
 ///
 /// To style the gauge line use [`LineGauge::filled_style`] and [`LineGauge::unfilled_style`] which
@@ -259,17 +453,33 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 /// # See also
 ///
 /// - [`Gauge`] for bigger, higher precision and more configurable progress bar
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LineGauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
     label: Option<Line<'a>>,
     line_set: symbols::line::Set,
+    direction: Direction,
     style: Style,
     filled_style: Style,
     unfilled_style: Style,
 }
 
+impl Default for LineGauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            line_set: symbols::line::Set::default(),
+            direction: Direction::Horizontal,
+            style: Style::default(),
+            filled_style: Style::default(),
+            unfilled_style: Style::default(),
+        }
+    }
+}
+
 impl<'a> LineGauge<'a> {
     /// Surrounds the `LineGauge` with a [`Block`].
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -309,6 +519,27 @@ impl<'a> LineGauge<'a> {
         self
     }
 
+    /// Sets whether the line is drawn horizontally or vertically.
+    ///
+    /// The default is [`Direction::Horizontal`], which draws the label followed by the line on the
+    /// first row of the area. With [`Direction::Vertical`], the line is drawn in the first column of
+    /// the area and filled from the bottom up, and the label is drawn below it on the last row.
+    /// Labels wider than the area are truncated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let meter = LineGauge::default()
+    ///     .ratio(0.3)
+    ///     .direction(Direction::Vertical);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the label to display.
     ///
     /// With `LineGauge`, labels are only on the left, see [`Gauge`] for a centered label.
@@ -395,6 +626,15 @@ impl WidgetRef for LineGauge<'_> {
         let ratio = self.ratio;
         let default_label = Line::from(format!("{:.0}%", ratio * 100.0));
         let label = self.label.as_ref().unwrap_or(&default_label);
+        match self.direction {
+            Direction::Horizontal => self.render_horizontal(gauge_area, label, buf),
+            Direction::Vertical => self.render_vertical(gauge_area, label, buf),
+        }
+    }
+}
+
+impl LineGauge<'_> {
+    fn render_horizontal(&self, gauge_area: Rect, label: &Line, buf: &mut Buffer) {
         let (col, row) = buf.set_line(gauge_area.left(), gauge_area.top(), label, gauge_area.width);
         let start = col + 1;
         if start >= gauge_area.right() {
@@ -414,6 +654,29 @@ impl WidgetRef for LineGauge<'_> {
                 .set_style(self.unfilled_style);
         }
     }
+
+    fn render_vertical(&self, gauge_area: Rect, label: &Line, buf: &mut Buffer) {
+        let label_row = gauge_area.bottom() - 1;
+        buf.set_line(gauge_area.left(), label_row, label, gauge_area.width);
+        let top = gauge_area.top();
+        if top >= label_row {
+            return;
+        }
+
+        // the line is filled from the bottom up
+        let col = gauge_area.left();
+        let start = label_row - (f64::from(label_row - top) * self.ratio).floor() as u16;
+        for row in top..start {
+            buf.get_mut(col, row)
+                .set_symbol(self.line_set.vertical)
+                .set_style(self.unfilled_style);
+        }
+        for row in start..label_row {
+            buf.get_mut(col, row)
+                .set_symbol(self.line_set.vertical)
+                .set_style(self.filled_style);
+        }
+    }
 }
 
 impl<'a> Styled for Gauge<'a> {
@@ -442,6 +705,8 @@ impl<'a> Styled for LineGauge<'a> {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -462,6 +727,132 @@ mod tests {
         let _ = Gauge::default().ratio(-0.5);
     }
 
+    #[rstest]
+    #[case::left(Alignment::Left, 0)]
+    #[case::center(Alignment::Center, 3)]
+    #[case::right(Alignment::Right, 7)]
+    fn gauge_label_alignment(#[case] alignment: Alignment, #[case] label_x: u16) {
+        let gauge = Gauge::default()
+            .ratio(0.5)
+            .gauge_style(Style::new().red().on_blue())
+            .label_alignment(alignment);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["█████     "]);
+        expected.set_style(expected.area, Style::new().red().on_blue());
+        // the part of the label over the filled part of the bar is inverted
+        for x in label_x..=label_x + 3 {
+            if x < 5 {
+                expected
+                    .get_mut(x, 0)
+                    .set_symbol(" ")
+                    .set_style(Style::new().blue().on_red());
+            }
+        }
+        expected.set_string(label_x, 0, "50%", Style::new());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn gauge_fraction_label() {
+        let gauge = Gauge::default()
+            .ratio(0.3)
+            .gauge_style(Style::new().red().on_blue())
+            .label_format(LabelFormat::Fraction { total: 10 });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        gauge.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["█3/10 "]);
+        expected.set_style(expected.area, Style::new().red().on_blue());
+        // the part of the label over the filled part of the bar is inverted
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().blue().on_red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn gauge_hide_label() {
+        let gauge = Gauge::default()
+            .ratio(0.5)
+            .gauge_style(Style::new().red().on_blue())
+            .show_label(false);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["█████     "]);
+        expected.set_style(expected.area, Style::new().red().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    /// The foreground color of the cells of the first row of a buffer
+    fn row_colors(buf: &Buffer) -> Vec<Color> {
+        (buf.area.left()..buf.area.right())
+            .map(|x| buf.get(x, buf.area.top()).fg)
+            .collect()
+    }
+
+    #[test]
+    fn gauge_gradient_interpolates_rgb_colors() {
+        let gauge = Gauge::default().ratio(1.0).gradient(vec![
+            (1.0, Color::Rgb(255, 0, 0)),
+            (0.0, Color::Rgb(0, 255, 0)),
+            (0.5, Color::Rgb(255, 255, 0)),
+        ]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 3));
+        gauge.render(buf.area, &mut buf);
+        let colors = row_colors(&buf);
+        assert_eq!(colors[0], Color::Rgb(0, 255, 0));
+        assert_eq!(colors[1], Color::Rgb(51, 255, 0));
+        assert_eq!(colors[5], Color::Rgb(255, 255, 0));
+        assert_eq!(colors[10], Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn gauge_gradient_only_colors_filled_cells() {
+        let gauge = Gauge::default().ratio(0.5).gradient(vec![
+            (0.0, Color::Rgb(0, 0, 0)),
+            (1.0, Color::Rgb(90, 0, 0)),
+        ]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(
+            row_colors(&buf),
+            [
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(10, 0, 0),
+                Color::Rgb(20, 0, 0),
+                Color::Rgb(30, 0, 0),
+                Color::Rgb(40, 0, 0),
+                Color::Reset,
+                Color::Reset,
+                Color::Reset,
+                Color::Reset,
+                Color::Reset,
+            ]
+        );
+    }
+
+    #[test]
+    fn gauge_gradient_passes_indexed_colors_through() {
+        let gauge = Gauge::default().ratio(1.0).gradient(vec![
+            (0.0, Color::Indexed(1)),
+            (0.5, Color::Indexed(2)),
+            (1.0, Color::Rgb(0, 0, 0)),
+        ]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(
+            row_colors(&buf),
+            [
+                Color::Indexed(1),
+                Color::Indexed(1),
+                Color::Indexed(2),
+                Color::Indexed(2),
+                Color::Rgb(0, 0, 0),
+            ]
+        );
+    }
+
     #[test]
     fn gauge_can_be_stylized() {
         assert_eq!(
@@ -474,6 +865,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn line_gauge_vertical() {
+        let gauge = LineGauge::default()
+            .ratio(0.3)
+            .direction(Direction::Vertical)
+            .filled_style(Style::new().green())
+            .unfilled_style(Style::new().dark_gray());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 11));
+        gauge.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines([
+            "│   ", "│   ", "│   ", "│   ", "│   ", "│   ", "│   ", "│   ", "│   ", "│   ", "30% ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 1, 7), Style::new().dark_gray());
+        expected.set_style(Rect::new(0, 7, 1, 3), Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::single_cell(Rect::new(0, 0, 1, 1), ["3"])]
+    #[case::single_row(Rect::new(0, 0, 4, 1), ["30% "])]
+    fn line_gauge_vertical_small_area<const N: usize>(
+        #[case] area: Rect,
+        #[case] expected: [&str; N],
+    ) {
+        let gauge = LineGauge::default()
+            .ratio(0.3)
+            .direction(Direction::Vertical);
+        let mut buf = Buffer::empty(area);
+        gauge.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
     #[test]
     fn line_gauge_can_be_stylized() {
         assert_eq!(
@@ -518,6 +942,7 @@ mod tests {
                 label: None,
                 style: Style::default(),
                 line_set: symbols::line::NORMAL,
+                direction: Direction::Horizontal,
                 filled_style: Style::default(),
                 unfilled_style: Style::default()
             }
@@ -1,10 +1,11 @@
 use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
     prelude::*,
     style::Styled,
-    widgets::{Block, HighlightSpacing},
+    widgets::{empty_text::render_empty_text, Block, HighlightSpacing},
 };
 
 /// State of the [`List`] widget
@@ -49,6 +50,8 @@ use crate::{
 pub struct ListState {
     offset: usize,
     selected: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    item_horizontal_offset: usize,
 }
 
 impl ListState {
@@ -84,6 +87,26 @@ impl ListState {
         self
     }
 
+    /// Sets the number of columns skipped at the start of the content of each item
+    ///
+    /// This scrolls the content of all the items horizontally, e.g. to show the end of long paths
+    /// while keeping single line items. The highlight symbol stays at the left of the list. Wide
+    /// characters that are cut by the offset are replaced with spaces.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default().with_item_horizontal_offset(5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_item_horizontal_offset(mut self, offset: usize) -> Self {
+        self.item_horizontal_offset = offset;
+        self
+    }
+
     /// Index of the first item to be displayed
     ///
     /// # Examples
@@ -110,6 +133,32 @@ impl ListState {
         &mut self.offset
     }
 
+    /// Number of columns skipped at the start of the content of each item
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default();
+    /// assert_eq!(state.item_horizontal_offset(), 0);
+    /// ```
+    pub const fn item_horizontal_offset(&self) -> usize {
+        self.item_horizontal_offset
+    }
+
+    /// Mutable reference to the number of columns skipped at the start of the content of each item
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = ListState::default();
+    /// *state.item_horizontal_offset_mut() = 5;
+    /// ```
+    pub fn item_horizontal_offset_mut(&mut self) -> &mut usize {
+        &mut self.item_horizontal_offset
+    }
+
     /// Index of the selected item
     ///
     /// Returns `None` if no item is selected
@@ -285,6 +334,7 @@ impl ListState {
 pub struct ListItem<'a> {
     content: Text<'a>,
     style: Style,
+    section_header: bool,
 }
 
 impl<'a> ListItem<'a> {
@@ -326,6 +376,7 @@ impl<'a> ListItem<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            section_header: false,
         }
     }
 
@@ -359,6 +410,32 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Marks the item as the header of a section
+    ///
+    /// A section is made of its header and the items after it, up to the next header. With
+    /// [`List::sticky_headers`], the header of the section at the start of the visible items stays
+    /// pinned while the rest of the section scrolls beneath it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let items = [
+    ///     ListItem::new("A").bold().section_header(true),
+    ///     ListItem::new("Alice"),
+    ///     ListItem::new("B").bold().section_header(true),
+    ///     ListItem::new("Bob"),
+    /// ];
+    /// let list = List::new(items).sticky_headers(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn section_header(mut self, section_header: bool) -> Self {
+        self.section_header = section_header;
+        self
+    }
+
     /// Returns the item height
     ///
     /// # Examples
@@ -442,6 +519,7 @@ where
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 /// multi-line items
 /// - [`List::direction`] sets the list direction
+/// - [`List::separator`] sets the symbol of a separator rendered between items
 ///
 /// # Examples
 ///
@@ -505,8 +583,20 @@ pub struct List<'a> {
     repeat_highlight_symbol: bool,
     /// Decides when to allocate spacing for the selection symbol
     highlight_spacing: HighlightSpacing,
+    /// The part of the selected item that gets the highlight style
+    highlight_scope: HighlightScope,
     /// How many items to try to keep visible before and after the selected item
     scroll_padding: usize,
+    /// Symbol repeated over a row between consecutive items
+    separator: Option<&'a str>,
+    /// Style used to render the separators
+    separator_style: Style,
+    /// Whether to render a separator before the first and after the last visible item
+    surround_with_separators: bool,
+    /// Whether the header of the section at the start of the visible items stays pinned
+    sticky_headers: bool,
+    /// Placeholder rendered when the list has no items
+    empty_text: Option<Text<'a>>,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -523,6 +613,19 @@ pub enum ListDirection {
     BottomToTop,
 }
 
+/// Defines which part of the selected item is styled with the highlight style.
+///
+/// See [`List::highlight_scope`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum HighlightScope {
+    /// The whole row of the selected item is highlighted
+    #[default]
+    Row,
+    /// Only the highlight symbol column of the selected item is highlighted, the content keeps its
+    /// own style
+    SymbolOnly,
+}
+
 impl<'a> List<'a> {
     /// Creates a new list from [`ListItem`]s
     ///
@@ -692,7 +795,9 @@ impl<'a> List<'a> {
 
     /// Set whether to repeat the highlight symbol and style over selected multi-line items
     ///
-    /// This is `false` by default.
+    /// This is `false` by default, in which case the continuation lines of the selected item are
+    /// padded with blank space as wide as the highlight symbol, so that the content of every line
+    /// stays aligned.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -732,6 +837,33 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set which part of the selected item is styled with the highlight style
+    ///
+    /// - [`HighlightScope::Row`] highlights the whole row of the selected item. This is the
+    ///   default.
+    /// - [`HighlightScope::SymbolOnly`] only highlights the column of the
+    ///   [highlight symbol](List::highlight_symbol), so that the content of the selected item keeps
+    ///   its own style. Nothing is highlighted when the [highlight spacing](List::highlight_spacing)
+    ///   is not allocated.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1"];
+    /// let list = List::new(items)
+    ///     .highlight_symbol("▌")
+    ///     .highlight_style(Style::new().yellow())
+    ///     .highlight_scope(HighlightScope::SymbolOnly);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_scope(mut self, scope: HighlightScope) -> Self {
+        self.highlight_scope = scope;
+        self
+    }
+
     /// Defines the list direction (up or down)
     ///
     /// Defines if the `List` is displayed *top to bottom* (default) or *bottom to top*.
@@ -773,6 +905,120 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets a separator rendered between consecutive items
+    ///
+    /// The `separator` symbol is repeated over a whole row between each item and the next one,
+    /// e.g. `"─"` for a menu divider. There is no separator after the last item unless
+    /// [`List::surround_with_separators`] is set. The separators take one row each, which is taken
+    /// into account when scrolling to the selected item. With [`ListDirection::BottomToTop`], the
+    /// separators are rendered above the items they follow, so they stay between the items.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).separator("─");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Sets the style of the separators
+    ///
+    /// This style is patched on top of the [style](List::style) of the list.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).separator("─").separator_style(Color::DarkGray);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.separator_style = style.into();
+        self
+    }
+
+    /// Sets whether to also render a separator before the first and after the last visible item
+    ///
+    /// These separators are top and bottom rules around the visible items (bottom and top rules
+    /// with [`ListDirection::BottomToTop`]). Their rows are reserved, so that they stay visible
+    /// when the list scrolls. This has no effect unless a [separator](List::separator) is set.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .separator("─")
+    ///     .surround_with_separators(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn surround_with_separators(mut self, surround: bool) -> Self {
+        self.surround_with_separators = surround;
+        self
+    }
+
+    /// Sets whether the [section headers](ListItem::section_header) stay pinned
+    ///
+    /// When the list is scrolled past the header of a section, the header is still rendered at the
+    /// top of the list (at the bottom with [`ListDirection::BottomToTop`]) and the items of the
+    /// section scroll beneath it. The pinned header takes rows from the visible items, and the
+    /// selected item is kept visible below it. The header is not selected while it is pinned.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let items = [
+    ///     ListItem::new("A").section_header(true),
+    ///     ListItem::new("Alice"),
+    ///     ListItem::new("Anna"),
+    /// ];
+    /// let list = List::new(items).sticky_headers(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sticky_headers(mut self, sticky_headers: bool) -> Self {
+        self.sticky_headers = sticky_headers;
+        self
+    }
+
+    /// Sets a placeholder text rendered when the list has no items
+    ///
+    /// `text` accepts any type that is convertible to [`Text`], so its style and alignment can be
+    /// set on the text itself. The placeholder is vertically centered in the list area (inside the
+    /// block), and horizontally centered unless the text or its lines have their own alignment.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let list = List::new(Vec::<ListItem>::new()).empty_text("No results".italic());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn empty_text<T: Into<Text<'a>>>(mut self, text: T) -> Self {
+        self.empty_text = Some(text.into());
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
@@ -783,6 +1029,96 @@ impl<'a> List<'a> {
         self.items.is_empty()
     }
 
+    /// The height of the item at `index`, including the separator after it
+    fn item_height(&self, index: usize) -> usize {
+        let has_separator = self.separator.is_some() && index + 1 < self.items.len();
+        self.items[index].height() + usize::from(has_separator)
+    }
+
+    /// The header of the section of the item at `index`, if that item is not a header itself
+    fn section_header_of(&self, index: usize) -> Option<usize> {
+        if self.items[index].section_header {
+            return None;
+        }
+        self.items[..index]
+            .iter()
+            .rposition(|item| item.section_header)
+    }
+
+    /// Calculates which items are visible, and which section header is pinned before them
+    ///
+    /// The pinned header takes the rows of its height (and separator) from the visible items.
+    fn get_items_bounds_with_sticky_header(
+        &self,
+        selected: Option<usize>,
+        offset: usize,
+        max_height: usize,
+    ) -> (Option<usize>, usize, usize) {
+        let (first, last) = self.get_items_bounds(selected, offset, max_height);
+        if !self.sticky_headers {
+            return (None, first, last);
+        }
+        let Some(header) = self.section_header_of(first) else {
+            return (None, first, last);
+        };
+        let header_height = self.item_height(header);
+        if header_height >= max_height {
+            return (None, first, last);
+        }
+        let (sticky_first, sticky_last) =
+            self.get_items_bounds(selected, first, max_height - header_height);
+        // the items may have scrolled into the next section to keep the selection visible
+        match self.section_header_of(sticky_first) {
+            Some(sticky_header) if sticky_header == header => {
+                (Some(header), sticky_first, sticky_last)
+            }
+            _ => (None, first, last),
+        }
+    }
+
+    /// The number of rows taken by the separators before the first and after the last item
+    const fn surrounding_separators_height(&self) -> usize {
+        if self.separator.is_some() && self.surround_with_separators {
+            2
+        } else {
+            0
+        }
+    }
+
+    /// Renders the separators before the first and after the last visible item, if enabled
+    ///
+    /// `items_height` is the height taken by the first separator and the visible items.
+    fn render_surrounding_separators(&self, list_area: Rect, items_height: u16, buf: &mut Buffer) {
+        if self.surrounding_separators_height() == 0 {
+            return;
+        }
+        let (first_y, last_y) = if self.direction == ListDirection::BottomToTop {
+            let last_y = list_area.bottom().saturating_sub(items_height + 1);
+            (list_area.bottom() - 1, last_y)
+        } else {
+            (list_area.top(), list_area.top() + items_height)
+        };
+        for y in [first_y, last_y] {
+            let row = Rect::new(list_area.x, y, list_area.width, 1);
+            self.render_separator(row.intersection(list_area), buf);
+        }
+    }
+
+    /// Renders a separator over the given row
+    fn render_separator(&self, row: Rect, buf: &mut Buffer) {
+        let Some(separator) = self.separator else {
+            return;
+        };
+        let separator_width = separator.width();
+        if separator_width == 0 {
+            return;
+        }
+        let style = self.style.patch(self.separator_style);
+        buf.set_style(row, style);
+        let rule = separator.repeat(usize::from(row.width).div_ceil(separator_width));
+        buf.set_stringn(row.x, row.y, rule, usize::from(row.width), style);
+    }
+
     /// Applies scroll padding to the selected index, reducing the padding value to keep the
     /// selected item on screen even with items of inconsistent sizes
     ///
@@ -809,7 +1145,7 @@ impl<'a> List<'a> {
                     .saturating_add(scroll_padding)
                     .min(last_valid_index)
             {
-                height_around_selected += self.items[index].height();
+                height_around_selected += self.item_height(index);
             }
             if height_around_selected <= max_height {
                 break;
@@ -847,12 +1183,12 @@ impl<'a> List<'a> {
 
         // Calculate the last visible index and total height of the items
         // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
+        for index in offset..self.items.len() {
+            if height_from_offset + self.item_height(index) > max_height {
                 break;
             }
 
-            height_from_offset += item.height();
+            height_from_offset += self.item_height(index);
 
             last_visible_index += 1;
         }
@@ -875,7 +1211,7 @@ impl<'a> List<'a> {
         // the offset is still set), we still need to show this item
         while index_to_display >= last_visible_index {
             height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
+                height_from_offset.saturating_add(self.item_height(last_visible_index));
 
             last_visible_index += 1;
 
@@ -883,7 +1219,7 @@ impl<'a> List<'a> {
             // for the selected/offset item
             while height_from_offset > max_height {
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
+                    height_from_offset.saturating_sub(self.item_height(first_visible_index));
 
                 // Remove this item to view by starting at the next item index
                 first_visible_index += 1;
@@ -896,14 +1232,14 @@ impl<'a> List<'a> {
             first_visible_index -= 1;
 
             height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
+                height_from_offset.saturating_add(self.item_height(first_visible_index));
 
             // Don't show an item if it is beyond our viewable height
             while height_from_offset > max_height {
                 last_visible_index -= 1;
 
                 height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
+                    height_from_offset.saturating_sub(self.item_height(last_visible_index));
             }
         }
 
@@ -932,7 +1268,9 @@ impl StatefulWidget for List<'_> {
     }
 }
 
-// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+// Note: remove this when StatefulWidgetRef is stabilized, the blanket impl is used with the
+// unstable-widget-ref feature
+#[cfg(not(feature = "unstable-widget-ref"))]
 impl StatefulWidget for &List<'_> {
     type State = ListState;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
@@ -954,6 +1292,7 @@ impl StatefulWidgetRef for List<'_> {
 
         if self.items.is_empty() {
             state.select(None);
+            render_empty_text(self.empty_text.as_ref(), list_area, buf);
             return;
         }
 
@@ -962,10 +1301,11 @@ impl StatefulWidgetRef for List<'_> {
             state.select(Some(self.items.len().saturating_sub(1)));
         }
 
-        let list_height = list_area.height as usize;
+        let list_height =
+            (list_area.height as usize).saturating_sub(self.surrounding_separators_height());
 
-        let (first_visible_index, last_visible_index) =
-            self.get_items_bounds(state.selected, state.offset, list_height);
+        let (sticky_header, first_visible_index, last_visible_index) =
+            self.get_items_bounds_with_sticky_header(state.selected, state.offset, list_height);
 
         // Important: this changes the state's offset to be the beginning of the now viewable items
         state.offset = first_visible_index;
@@ -974,23 +1314,32 @@ impl StatefulWidgetRef for List<'_> {
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
         let blank_symbol = " ".repeat(highlight_symbol.width());
 
-        let mut current_height = 0;
+        // The separator before the first visible item takes the first row
+        let mut current_height = (self.surrounding_separators_height() / 2) as u16;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
-        for (i, item) in self
-            .items
-            .iter()
-            .enumerate()
-            .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
-        {
-            let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
-                (list_area.left(), list_area.bottom() - current_height)
+        let visible_indices = sticky_header
+            .into_iter()
+            .chain(first_visible_index..last_visible_index);
+        for i in visible_indices {
+            let item = &self.items[i];
+            // The separator after the item is above it when the list goes from bottom to top
+            let separator_height = (self.item_height(i) - item.height()) as u16;
+            let (x, y, separator_y) = if self.direction == ListDirection::BottomToTop {
+                current_height += item.height() as u16 + separator_height;
+                let separator_y = list_area.bottom() - current_height;
+                (
+                    list_area.left(),
+                    separator_y + separator_height,
+                    separator_y,
+                )
             } else {
-                let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
-                pos
+                let y = list_area.top() + current_height;
+                current_height += item.height() as u16 + separator_height;
+                (list_area.left(), y, y + item.height() as u16)
             };
+            if separator_height > 0 {
+                self.render_separator(Rect::new(x, separator_y, list_area.width, 1), buf);
+            }
 
             let row_area = Rect {
                 x,
@@ -1004,17 +1353,23 @@ impl StatefulWidgetRef for List<'_> {
 
             let is_selected = state.selected.map_or(false, |s| s == i);
 
-            let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
-                Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
-                    ..row_area
-                }
+            let highlight_symbol_width = if selection_spacing {
+                (highlight_symbol.width() as u16).min(row_area.width)
             } else {
-                row_area
+                0
             };
-            item.content.clone().render(item_area, buf);
+            let item_area = Rect {
+                x: row_area.x + highlight_symbol_width,
+                width: row_area.width - highlight_symbol_width,
+                ..row_area
+            };
+            let mut content = item.content.clone();
+            if state.item_horizontal_offset > 0 {
+                for line in &mut content.lines {
+                    *line = skip_columns(line, state.item_horizontal_offset);
+                }
+            }
+            content.render(item_area, buf);
 
             for j in 0..item.content.height() {
                 // if the item is selected, we need to display the highlight symbol:
@@ -1037,9 +1392,55 @@ impl StatefulWidgetRef for List<'_> {
             }
 
             if is_selected {
-                buf.set_style(row_area, self.highlight_style);
+                let highlight_area = match self.highlight_scope {
+                    HighlightScope::Row => row_area,
+                    HighlightScope::SymbolOnly => Rect {
+                        width: highlight_symbol_width,
+                        ..row_area
+                    },
+                };
+                buf.set_style(highlight_area, self.highlight_style);
             }
         }
+
+        self.render_surrounding_separators(list_area, current_height, buf);
+    }
+}
+
+/// Returns the line without its first `columns` columns
+///
+/// A wide grapheme that is cut by the end of the skipped columns is replaced with spaces so that
+/// the rest of the line stays in place.
+fn skip_columns<'a>(line: &Line<'a>, columns: usize) -> Line<'a> {
+    let mut remaining = columns;
+    let mut spans = Vec::with_capacity(line.spans.len());
+    for span in &line.spans {
+        if remaining == 0 {
+            spans.push(span.clone());
+            continue;
+        }
+        let mut start = span.content.len();
+        let mut padding = 0;
+        for (index, grapheme) in span.content.grapheme_indices(true) {
+            if remaining == 0 {
+                start = index;
+                break;
+            }
+            let width = grapheme.width();
+            padding = width.saturating_sub(remaining);
+            remaining = remaining.saturating_sub(width);
+        }
+        if padding > 0 {
+            spans.push(Span::styled(" ".repeat(padding), span.style));
+        }
+        if let Some(rest) = span.content.get(start..).filter(|rest| !rest.is_empty()) {
+            spans.push(Span::styled(rest.to_string(), span.style));
+        }
+    }
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
     }
 }
 
@@ -1583,6 +1984,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn item_horizontal_offset_scrolls_items() {
+        let list = List::new(["/usr/local/bin", "/etc/hosts", "/tmp"]).highlight_symbol(">>");
+        let mut state = ListState::default()
+            .with_selected(Some(1))
+            .with_item_horizontal_offset(5);
+        let buffer = render_stateful_widget(list, &mut state, 10, 3);
+        // the highlight symbol stays at the left
+        let expected = Buffer::with_lines(["  local/bi", ">>hosts   ", "          "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn item_horizontal_offset_clips_wide_characters() {
+        let list = List::new([
+            Line::from(vec!["ab".red(), "日本語".blue()]),
+            Line::from("abc日本語"),
+        ]);
+        let mut state = ListState::default().with_item_horizontal_offset(5);
+        let buffer = render_stateful_widget(list, &mut state, 6, 2);
+        // the second half of `本` is replaced with a space
+        let mut expected = Buffer::with_lines([" 語   ", "本語  "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().blue());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_list_items_setter() {
         let list = List::default().items(["Item 0", "Item 1", "Item 2"]);
@@ -1669,6 +2096,36 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn highlight_scope_symbol_only_keeps_item_style() {
+        let list = List::new([
+            ListItem::new("Item 0").blue(),
+            ListItem::new(Line::from(vec!["Item ".into(), "1".bold()])).blue(),
+        ])
+        .highlight_symbol(">>")
+        .highlight_style(Style::new().yellow().on_red())
+        .highlight_scope(HighlightScope::SymbolOnly);
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = render_stateful_widget(list, &mut state, 10, 3);
+        let mut expected = Buffer::with_lines(["  Item 0  ", ">>Item 1  ", "          "]);
+        expected.set_style(Rect::new(0, 0, 10, 2), Style::new().blue());
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().yellow().on_red());
+        expected.set_style(Rect::new(7, 1, 1, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_scope_symbol_only_without_spacing() {
+        let list = List::new(["Item 0", "Item 1"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().yellow())
+            .highlight_spacing(HighlightSpacing::Never)
+            .highlight_scope(HighlightScope::SymbolOnly);
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = render_stateful_widget(list, &mut state, 8, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 0  ", "Item 1  "]));
+    }
+
     #[test]
     fn test_list_highlight_spacing_default_whenselected() {
         // when not selected
@@ -1798,6 +2255,15 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_list_pads_continuation_lines_of_selected_item() {
+        let list = List::new(["Item 0\nLine 2", "Item 1"]).highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(0));
+        let buffer = render_stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines([">>Item 0  ", "  Line 2  ", "  Item 1  "]);
+        assert_eq!(buffer, expected);
+    }
+
     #[rstest]
     #[case::top_to_bottom(ListDirection::TopToBottom, [
         "Item 0    ",
@@ -1821,6 +2287,138 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
+    #[rstest]
+    #[case::top_to_bottom(ListDirection::TopToBottom, [
+        "Item 0  ",
+        "────────",
+        "Item 1  ",
+        "────────",
+        "Item 2  ",
+        "        ",
+    ])]
+    #[case::bottom_to_top(ListDirection::BottomToTop, [
+        "        ",
+        "Item 2  ",
+        "────────",
+        "Item 1  ",
+        "────────",
+        "Item 0  ",
+    ])]
+    fn separators_between_items(#[case] direction: ListDirection, #[case] expected: [&str; 6]) {
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .separator("─")
+            .separator_style(Style::new().dark_gray())
+            .direction(direction);
+        let buffer = render_widget(list, 8, 6);
+        let mut expected = Buffer::with_lines(expected);
+        for y in 0..6 {
+            if expected.get(0, y).symbol() == "─" {
+                expected.set_style(Rect::new(0, y, 8, 1), Style::new().dark_gray());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn separators_are_counted_when_scrolling() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .separator("─")
+            .highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = render_stateful_widget(list, &mut state, 8, 4);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "  Item 2",
+            "────────",
+            ">>Item 3",
+            "        ",
+        ]);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[test]
+    fn separators_surround_items() {
+        let list = List::new(["Item 0", "Item 1"])
+            .separator("─")
+            .surround_with_separators(true);
+        let buffer = render_widget(list, 8, 6);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "────────",
+            "Item 0  ",
+            "────────",
+            "Item 1  ",
+            "────────",
+            "        ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn separators_surround_items_bottom_to_top() {
+        let list = List::new(["Item 0", "Item 1"])
+            .separator("─")
+            .surround_with_separators(true)
+            .direction(ListDirection::BottomToTop);
+        let buffer = render_widget(list, 8, 6);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "        ",
+            "────────",
+            "Item 1  ",
+            "────────",
+            "Item 0  ",
+            "────────",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn surrounding_separators_rows_are_reserved_when_scrolling() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .separator("-")
+            .separator_style(Style::new().dark_gray())
+            .surround_with_separators(true)
+            .highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(3));
+        let buffer = render_stateful_widget(list, &mut state, 8, 5);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "--------",
+            "  Item 2",
+            "--------",
+            ">>Item 3",
+            "--------",
+        ]);
+        for y in [0, 2, 4] {
+            expected.set_style(Rect::new(0, y, 8, 1), Style::new().dark_gray());
+        }
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[test]
+    fn surround_without_separator_has_no_effect() {
+        let list = List::new(["Item 0", "Item 1"]).surround_with_separators(true);
+        let buffer = render_widget(list, 8, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Item 0  ", "Item 1  ", "        "])
+        );
+    }
+
+    #[test]
+    fn surrounding_separators_in_small_area() {
+        let list = List::new(["Item 0", "Item 1"])
+            .separator("─")
+            .surround_with_separators(true)
+            .direction(ListDirection::BottomToTop);
+        let mut state = ListState::default().with_selected(Some(0));
+        // doesn't panic when the rules don't fit
+        render_stateful_widget(list, &mut state, 8, 1);
+    }
+
     #[test]
     fn test_list_truncate_items() {
         let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3", "Item 4"]);
@@ -2236,6 +2834,34 @@ mod tests {
         terminal.backend().assert_buffer_lines(expected);
     }
 
+    /// Moving the selection through a list keeps `scroll_padding` items visible on either side of
+    /// the selection, except at the start and end of the list where the padding collapses
+    #[test]
+    fn test_padding_kept_while_navigating() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let mut state = ListState::default();
+        let items = (0..10).map(|i| format!("Item {i}"));
+        let list = List::new(items).scroll_padding(1);
+
+        let mut offsets = vec![];
+        for _ in 0..10 {
+            state.select_next();
+            StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+            offsets.push(state.offset());
+        }
+        // selections 0..=9
+        assert_eq!(offsets, [0, 0, 0, 0, 1, 2, 3, 4, 5, 5]);
+
+        let mut offsets = vec![];
+        for _ in 0..9 {
+            state.select_previous();
+            StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+            offsets.push(state.offset());
+        }
+        // selections 8..=0
+        assert_eq!(offsets, [5, 5, 5, 4, 3, 2, 1, 0, 0]);
+    }
+
     // Tests to make sure when it's pushing back the first visible index value that it doesnt
     // include an item that's too large
     #[test]
@@ -2290,4 +2916,86 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    fn sectioned_list() -> List<'static> {
+        let items = ["A", "a1", "a2", "a3", "a4", "a5", "B", "b1", "b2"]
+            .map(|name| ListItem::new(name).section_header(name.chars().all(char::is_uppercase)));
+        List::new(items).sticky_headers(true)
+    }
+
+    #[rstest]
+    #[case::section_start(0, ["A     ", "a1    ", "a2    ", "a3    "])]
+    #[case::within_section(4, ["A     ", "a2    ", "a3    ", "a4    "])]
+    #[case::next_section_visible(7, ["A     ", "a5    ", "B     ", "b1    "])]
+    #[case::next_header_visible(8, ["a5    ", "B     ", "b1    ", "b2    "])]
+    fn sticky_headers<'line, Lines>(#[case] selected: usize, #[case] expected: Lines)
+    where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let mut state = ListState::default().with_selected(Some(selected));
+        let buffer = render_stateful_widget(sectioned_list(), &mut state, 6, 4);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn sticky_headers_pinned_while_scrolling_within_section() {
+        let mut state = ListState::default();
+        for selected in 3..=5 {
+            state.select(Some(selected));
+            let buffer = render_stateful_widget(sectioned_list(), &mut state, 6, 3);
+            assert_eq!(buffer.get(0, 0).symbol(), "A");
+            assert_eq!(state.offset(), selected - 1);
+        }
+
+        let list = sectioned_list().sticky_headers(false);
+        let buffer = render_stateful_widget(list, &mut state, 6, 3);
+        assert_eq!(buffer, Buffer::with_lines(["a4    ", "a5    ", "B     "]));
+    }
+
+    #[test]
+    fn sticky_header_is_not_selected() {
+        let list = sectioned_list()
+            .highlight_symbol(">")
+            .highlight_style(Style::new().red());
+        let mut state = ListState::default().with_offset(3);
+        state.select(Some(4));
+        let buffer = render_stateful_widget(list, &mut state, 6, 2);
+        let mut expected = Buffer::with_lines([" A    ", ">a4   "]);
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn empty_text_renders_when_there_are_no_items() {
+        let list = List::new(Vec::<ListItem>::new())
+            .block(Block::bordered())
+            .empty_text("No results".italic());
+        let buffer = render_widget(list, 14, 5);
+        let mut expected = Buffer::with_lines([
+            "┌────────────┐",
+            "│            │",
+            "│ No results │",
+            "│            │",
+            "└────────────┘",
+        ]);
+        expected.set_style(Rect::new(2, 2, 10, 1), Style::new().italic());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn empty_text_honors_alignment() {
+        let list = List::new(Vec::<ListItem>::new()).empty_text(Text::from("None").right_aligned());
+        assert_eq!(render_widget(list, 6, 1), Buffer::with_lines(["  None"]));
+    }
+
+    #[test]
+    fn empty_text_is_ignored_when_there_are_items() {
+        let list = List::new(["Item 0", "Item 1"]).empty_text("No results");
+        let buffer = render_widget(list, 10, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Item 0    ", "Item 1    ", "          "])
+        );
+    }
 }
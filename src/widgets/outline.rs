@@ -0,0 +1,575 @@
+use std::collections::BTreeSet;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{prelude::*, style::Styled, widgets::Block};
+
+/// A heading of an [`Outline`]
+///
+/// An entry has a title, a nesting level and a target, which is the position the application
+/// jumps to when the entry is selected (e.g. the line of the heading in the document). Entries
+/// with a level of `0` are at the top of the outline, and each entry is a child of the closest
+/// previous entry with a lower level.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let entry = OutlineEntry::new(1, "Installation", 42);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct OutlineEntry<'a> {
+    title: Line<'a>,
+    level: usize,
+    target: usize,
+}
+
+impl<'a> OutlineEntry<'a> {
+    /// Creates a new entry
+    ///
+    /// `title` accepts any type that can be converted into a [`Line`] (e.g. `&str`, `String`,
+    /// [`Span`], ...).
+    pub fn new<T: Into<Line<'a>>>(level: usize, title: T, target: usize) -> Self {
+        Self {
+            title: title.into(),
+            level,
+            target,
+        }
+    }
+
+    /// The nesting level of the entry
+    pub const fn level(&self) -> usize {
+        self.level
+    }
+
+    /// The position the application jumps to when the entry is selected
+    pub const fn target(&self) -> usize {
+        self.target
+    }
+}
+
+/// State of the [`Outline`] widget
+///
+/// This state holds the selected entry and the entries that are collapsed. Entries are identified
+/// by their index in the outline, whether they are visible or not. The visible entries are only
+/// known once the outline is rendered, so [`OutlineState::select_next`] and
+/// [`OutlineState::select_previous`] move through the entries visible during the last render.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = OutlineState::default().with_selected(Some(1));
+/// state.toggle_selected();
+/// assert!(state.is_collapsed(1));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlineState {
+    offset: usize,
+    selected: Option<usize>,
+    collapsed: BTreeSet<usize>,
+    visible: Vec<usize>,
+}
+
+impl OutlineState {
+    /// Sets the index of the selected entry
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the first visible entry among the entries visible during the last render
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Index of the selected entry
+    ///
+    /// Returns `None` if no entry is selected
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Sets the index of the selected entry
+    ///
+    /// Set to `None` if no entry is selected. If the entry is hidden in a collapsed entry, the
+    /// selection moves to the collapsed entry when rendering.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Selects the next visible entry or the first one if no entry is selected
+    pub fn select_next(&mut self) {
+        let next = match self.visible_position() {
+            Some(position) => self.visible.get(position + 1).copied().or(self.selected),
+            None => self.visible.first().copied(),
+        };
+        self.select(next.or(Some(0)));
+    }
+
+    /// Selects the previous visible entry or the last one if no entry is selected
+    pub fn select_previous(&mut self) {
+        let previous = match self.visible_position() {
+            Some(position) => self.visible.get(position.saturating_sub(1)).copied(),
+            None => self.visible.last().copied(),
+        };
+        self.select(previous.or(Some(0)));
+    }
+
+    /// Indices of the entries visible during the last render, in order
+    pub fn visible(&self) -> &[usize] {
+        &self.visible
+    }
+
+    /// Returns whether the children of the entry at `index` are hidden
+    pub fn is_collapsed(&self, index: usize) -> bool {
+        self.collapsed.contains(&index)
+    }
+
+    /// Hides the children of the entry at `index`
+    pub fn collapse(&mut self, index: usize) {
+        self.collapsed.insert(index);
+    }
+
+    /// Shows the children of the entry at `index`
+    pub fn expand(&mut self, index: usize) {
+        self.collapsed.remove(&index);
+    }
+
+    /// Collapses the entry at `index` if it is expanded, and expands it otherwise
+    pub fn toggle(&mut self, index: usize) {
+        if !self.collapsed.remove(&index) {
+            self.collapsed.insert(index);
+        }
+    }
+
+    /// Collapses the selected entry if it is expanded, and expands it otherwise
+    pub fn toggle_selected(&mut self) {
+        if let Some(selected) = self.selected {
+            self.toggle(selected);
+        }
+    }
+
+    /// Position of the selected entry among the visible entries
+    fn visible_position(&self) -> Option<usize> {
+        let selected = self.selected?;
+        self.visible.iter().position(|&index| index == selected)
+    }
+}
+
+/// A collapsible outline of nested headings, e.g. the table of contents of a document.
+///
+/// Each [`OutlineEntry`] is indented according to its level. Entries that have children are
+/// prefixed with an [expanded](Outline::expanded_symbol) or [collapsed](Outline::collapsed_symbol)
+/// symbol, and the children of collapsed entries are hidden. The selected entry is styled with the
+/// [highlight style](Outline::highlight_style) and the outline scrolls to keep it visible.
+///
+/// The selection and the collapsed entries are stored in the [`OutlineState`], which is updated by
+/// the application in response to key presses. The position to jump to when an entry is chosen is
+/// returned by [`Outline::selected_target`].
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let outline = Outline::new([
+///     OutlineEntry::new(0, "Introduction", 0),
+///     OutlineEntry::new(0, "Usage", 12),
+///     OutlineEntry::new(1, "Installation", 14),
+///     OutlineEntry::new(1, "Configuration", 30),
+/// ])
+/// .block(Block::bordered().title("Contents"))
+/// .highlight_style(Style::new().reversed());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = OutlineState::default().with_selected(Some(2));
+///
+/// frame.render_stateful_widget(&outline, area, &mut state);
+/// assert_eq!(outline.selected_target(&state), Some(14));
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Outline<'a> {
+    /// The headings of the outline
+    entries: Vec<OutlineEntry<'a>>,
+    /// Block drawn around the outline
+    block: Option<Block<'a>>,
+    /// Base style of the outline
+    style: Style,
+    /// Style of the selected entry
+    highlight_style: Style,
+    /// Drawn in front of the selected entry
+    highlight_symbol: &'a str,
+    /// Number of columns added to the indentation for each level
+    indent: u16,
+    /// Drawn in front of the entries whose children are visible
+    expanded_symbol: &'a str,
+    /// Drawn in front of the entries whose children are hidden
+    collapsed_symbol: &'a str,
+}
+
+impl Default for Outline<'_> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            block: None,
+            style: Style::new(),
+            highlight_style: Style::new(),
+            highlight_symbol: "",
+            indent: 2,
+            expanded_symbol: "▾ ",
+            collapsed_symbol: "▸ ",
+        }
+    }
+}
+
+impl<'a> Outline<'a> {
+    /// Creates a new outline with the given entries
+    pub fn new<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = OutlineEntry<'a>>,
+    {
+        Self {
+            entries: entries.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the block drawn around the outline
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the outline
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected entry
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the symbol drawn in front of the selected entry
+    ///
+    /// The other entries are padded with spaces so that they stay aligned. The default is empty.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Self {
+        self.highlight_symbol = highlight_symbol;
+        self
+    }
+
+    /// Sets the number of columns added to the indentation for each level
+    ///
+    /// The default is 2.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the symbol drawn in front of the entries whose children are visible
+    ///
+    /// The default is `"▾ "`. Entries without children are padded with spaces instead.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn expanded_symbol(mut self, symbol: &'a str) -> Self {
+        self.expanded_symbol = symbol;
+        self
+    }
+
+    /// Sets the symbol drawn in front of the entries whose children are hidden
+    ///
+    /// The default is `"▸ "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn collapsed_symbol(mut self, symbol: &'a str) -> Self {
+        self.collapsed_symbol = symbol;
+        self
+    }
+
+    /// Returns the target of the selected entry
+    ///
+    /// Returns `None` if no entry is selected or the selected index is out of bounds.
+    pub fn selected_target(&self, state: &OutlineState) -> Option<usize> {
+        state
+            .selected
+            .and_then(|index| self.entries.get(index))
+            .map(OutlineEntry::target)
+    }
+
+    /// Returns whether the entry at `index` has children
+    fn has_children(&self, index: usize) -> bool {
+        self.entries
+            .get(index + 1)
+            .is_some_and(|next| next.level > self.entries[index].level)
+    }
+
+    /// Returns the indices of the entries that are not hidden in a collapsed entry
+    fn visible_entries(&self, state: &OutlineState) -> Vec<usize> {
+        let mut visible = vec![];
+        let mut collapsed_level = None;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if collapsed_level.is_some_and(|level| entry.level > level) {
+                continue;
+            }
+            collapsed_level = state.is_collapsed(index).then_some(entry.level);
+            visible.push(index);
+        }
+        visible
+    }
+
+    /// Returns the line of an entry, indented and prefixed with its expand or collapse symbol
+    fn entry_line(&self, index: usize, state: &OutlineState) -> Line<'a> {
+        let entry = &self.entries[index];
+        let symbol = if !self.has_children(index) {
+            " ".repeat(self.expanded_symbol.width())
+        } else if state.is_collapsed(index) {
+            self.collapsed_symbol.to_string()
+        } else {
+            self.expanded_symbol.to_string()
+        };
+        let indent = " ".repeat(entry.level * usize::from(self.indent));
+        let mut spans = vec![Span::raw(indent), Span::raw(symbol)];
+        spans.extend(entry.title.spans.iter().cloned());
+        Line::from(spans).style(entry.title.style)
+    }
+}
+
+impl Widget for Outline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl WidgetRef for Outline<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = OutlineState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Outline<'_> {
+    type State = OutlineState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized, the blanket impl is used with the
+// unstable-widget-ref feature
+#[cfg(not(feature = "unstable-widget-ref"))]
+impl StatefulWidget for &Outline<'_> {
+    type State = OutlineState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Outline<'_> {
+    type State = OutlineState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.visible = self.visible_entries(state);
+
+        // An entry hidden in a collapsed entry can't be selected, so select the closest visible
+        // entry before it, which is the collapsed entry
+        state.selected = state.selected.map(|selected| {
+            let selected = selected.min(self.entries.len().saturating_sub(1));
+            state
+                .visible
+                .iter()
+                .rev()
+                .find(|&&index| index <= selected)
+                .copied()
+                .unwrap_or(selected)
+        });
+
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() || state.visible.is_empty() {
+            return;
+        }
+
+        // Scroll so that the selected entry is visible
+        let height = usize::from(inner.height);
+        if let Some(position) = state.visible_position() {
+            state.offset = state
+                .offset
+                .clamp((position + 1).saturating_sub(height), position);
+        }
+        state.offset = state.offset.min(state.visible.len().saturating_sub(height));
+
+        let symbol_width = if state.selected.is_some() {
+            self.highlight_symbol.width() as u16
+        } else {
+            0
+        };
+        for (row, &index) in inner.rows().zip(state.visible.iter().skip(state.offset)) {
+            let is_selected = state.selected == Some(index);
+            if is_selected {
+                buf.set_stringn(
+                    row.x,
+                    row.y,
+                    self.highlight_symbol,
+                    usize::from(symbol_width),
+                    Style::new(),
+                );
+            }
+            let x = row.x.saturating_add(symbol_width);
+            let width = row.right().saturating_sub(x);
+            buf.set_line(x, row.y, &self.entry_line(index, state), width);
+            if is_selected {
+                buf.set_style(row, self.highlight_style);
+            }
+        }
+    }
+}
+
+impl Styled for Outline<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outline() -> Outline<'static> {
+        Outline::new([
+            OutlineEntry::new(0, "Intro", 1),
+            OutlineEntry::new(0, "Usage", 10),
+            OutlineEntry::new(1, "Install", 12),
+            OutlineEntry::new(1, "Config", 20),
+            OutlineEntry::new(0, "API", 30),
+        ])
+        .highlight_symbol("> ")
+        .highlight_style(Style::new().bold())
+    }
+
+    #[test]
+    fn render_selected_child() {
+        let outline = outline();
+        let mut state = OutlineState::default().with_selected(Some(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+        StatefulWidget::render(&outline, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "    Intro      ",
+            "  ▾ Usage      ",
+            ">     Install  ",
+            "      Config   ",
+            "    API        ",
+            "               ",
+        ]);
+        expected.set_style(Rect::new(0, 2, 15, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+        assert_eq!(outline.selected_target(&state), Some(12));
+        assert_eq!(state.visible(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn render_without_selection() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13, 5));
+        Widget::render(outline(), buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "  Intro      ",
+            "▾ Usage      ",
+            "    Install  ",
+            "    Config   ",
+            "  API        ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn collapse_hides_children_and_moves_selection() {
+        let outline = outline();
+        let mut state = OutlineState::default().with_selected(Some(3));
+        state.collapse(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+        StatefulWidget::render(&outline, buf.area, &mut buf, &mut state);
+        let mut expected =
+            Buffer::with_lines(["    Intro      ", "> ▸ Usage      ", "    API        "]);
+        expected.set_style(Rect::new(0, 1, 15, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+        assert_eq!(state.selected(), Some(1));
+        assert_eq!(outline.selected_target(&state), Some(10));
+        assert_eq!(state.visible(), &[0, 1, 4]);
+    }
+
+    #[test]
+    fn select_next_and_previous_skip_hidden_entries() {
+        let outline = outline();
+        let mut state = OutlineState::default();
+        state.collapse(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+        StatefulWidget::render(&outline, buf.area, &mut buf, &mut state);
+
+        state.select_next();
+        assert_eq!(state.selected(), Some(0));
+        state.select_next();
+        assert_eq!(state.selected(), Some(1));
+        state.select_next();
+        assert_eq!(state.selected(), Some(4));
+        state.select_next();
+        assert_eq!(state.selected(), Some(4));
+        state.select_previous();
+        assert_eq!(state.selected(), Some(1));
+
+        state.toggle_selected();
+        StatefulWidget::render(&outline, buf.area, &mut buf, &mut state);
+        state.select_next();
+        assert_eq!(state.selected(), Some(2));
+    }
+
+    #[test]
+    fn render_scrolls_to_selected() {
+        let outline = outline();
+        let mut state = OutlineState::default().with_selected(Some(4));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+        StatefulWidget::render(&outline, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["      Config   ", ">   API        "]);
+        expected.set_style(Rect::new(0, 1, 15, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+        assert_eq!(state.offset(), 3);
+    }
+}
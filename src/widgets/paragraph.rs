@@ -5,7 +5,7 @@ use crate::{
     style::Styled,
     text::StyledGrapheme,
     widgets::{
-        reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
+        reflow::{expand_tabs, LineComposer, LineTruncator, WordWrapper, WrappedLine},
         Block,
     },
 };
@@ -41,6 +41,7 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 ///     .wrap(Wrap { trim: true });
 /// ```
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)] // independent rendering options
 pub struct Paragraph<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -54,6 +55,24 @@ pub struct Paragraph<'a> {
     scroll: (u16, u16),
     /// Alignment of the text
     alignment: Alignment,
+    /// Whether wrapped lines are justified
+    justify: bool,
+    /// Whether words wider than the paragraph continue on the next line when wrapping
+    break_long_words: bool,
+    /// Whether words can be broken at soft hyphens when wrapping
+    hyphenate: bool,
+    /// Whether to render a gutter with the line numbers
+    line_numbers: bool,
+    /// Style of the line numbers
+    line_number_style: Style,
+    /// Number of columns between tab stops
+    tab_width: u16,
+    /// The first and last highlighted positions of the text
+    highlight_range: Option<(Position, Position)>,
+    /// Style of the highlighted text
+    highlight_style: Style,
+    /// Direction in which the text is read
+    direction: TextDirection,
 }
 
 /// Describes how to wrap text across lines.
@@ -91,6 +110,29 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// The direction in which the text of a [`Paragraph`] is read
+///
+/// See [`Paragraph::direction`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextDirection {
+    /// Left to right, e.g. for English. This is the **default**.
+    #[default]
+    Ltr,
+    /// Right to left, e.g. for Arabic or Hebrew
+    Rtl,
+}
+
+impl TextDirection {
+    /// Returns the alignment on screen of a line with the given alignment in this direction
+    const fn align(self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (Self::Rtl, Alignment::Left) => Alignment::Right,
+            (Self::Rtl, Alignment::Right) => Alignment::Left,
+            (_, alignment) => alignment,
+        }
+    }
+}
+
 type Horizontal = u16;
 type Vertical = u16;
 
@@ -121,6 +163,15 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: (0, 0),
             alignment: Alignment::Left,
+            justify: false,
+            break_long_words: true,
+            hyphenate: false,
+            line_numbers: false,
+            line_number_style: Style::new(),
+            tab_width: 8,
+            highlight_range: None,
+            highlight_style: Style::new(),
+            direction: TextDirection::Ltr,
         }
     }
 
@@ -180,6 +231,11 @@ impl<'a> Paragraph<'a> {
     /// scroll, and the x offset is the number of characters to scroll. The scroll offset is applied
     /// after the text is wrapped and aligned.
     ///
+    /// The x offset is only applied to left aligned lines when the paragraph is not
+    /// [wrapped](Self::wrap). Each line is clipped by skipping `x` display columns from its start.
+    /// A wide character (e.g. CJK) that straddles the left edge is replaced by blank columns in
+    /// its style.
+    ///
     /// Note: the order of the tuple is (y, x) instead of (x, y), which is different from general
     /// convention across the crate.
     ///
@@ -253,6 +309,197 @@ impl<'a> Paragraph<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Justifies the wrapped lines of the given paragraph.
+    ///
+    /// Extra space is distributed between the words of each wrapped line so that both margins are
+    /// flush. The last line of each line of text and lines that contain a single word are left
+    /// aligned. Justification overrides the [alignment](Self::alignment) of the paragraph and of
+    /// its lines, and only applies when the paragraph is [wrapped](Self::wrap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("Hello World")
+    ///     .wrap(Wrap { trim: true })
+    ///     .justify(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Sets whether words wider than the paragraph are broken across lines when wrapping.
+    ///
+    /// When enabled (the default), a word that does not fit on a line of its own, such as a long
+    /// URL or hash, is broken at the edge of the paragraph and continues on the next line. When
+    /// disabled, the word is truncated at the edge of the paragraph instead. This only applies
+    /// when the paragraph is [wrapped](Self::wrap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("https://ratatui.rs/concepts/layout/")
+    ///     .wrap(Wrap { trim: true })
+    ///     .break_long_words(false);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn break_long_words(mut self, break_long_words: bool) -> Self {
+        self.break_long_words = break_long_words;
+        self
+    }
+
+    /// Sets whether words can be broken at soft hyphens (`U+00AD`) when wrapping.
+    ///
+    /// When enabled, soft hyphens are hidden and mark where a word may be broken. A word that does
+    /// not fit on a line is broken at its last soft hyphen that fits, and a visible `-` is shown at
+    /// the end of the line. Words without a suitable soft hyphen fall back to the
+    /// [`break_long_words`](Self::break_long_words) behavior. This only applies when the paragraph
+    /// is [wrapped](Self::wrap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("in\u{ad}com\u{ad}pre\u{ad}hen\u{ad}si\u{ad}ble")
+    ///     .wrap(Wrap { trim: true })
+    ///     .hyphenate(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
+
+    /// Renders a gutter with line numbers before the text of the given paragraph.
+    ///
+    /// The numbers are right aligned in a gutter sized to the number of digits of the last line
+    /// number, followed by a single space. Numbers account for the [scroll](Self::scroll) offset,
+    /// and when the paragraph is [wrapped](Self::wrap), only the first row of each line shows its
+    /// number.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("fn main() {\n}").line_numbers(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the style of the line numbers.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The line numbers are only rendered when [`Paragraph::line_numbers`] is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("fn main() {\n}")
+    ///     .line_numbers(true)
+    ///     .line_number_style(Style::new().dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_number_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.line_number_style = style.into();
+        self
+    }
+
+    /// Sets the number of columns between tab stops.
+    ///
+    /// Each tab (`\t`) in the text is expanded to spaces up to the next tab stop before the text is
+    /// wrapped, truncated or aligned. Tab stops are placed every `tab_width` columns from the start
+    /// of each line of text. A width of 0 removes the tabs. The default is 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("name\tvalue").tab_width(4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Sets the direction in which the text is read.
+    ///
+    /// With [`TextDirection::Rtl`], each line is laid out from the right edge of the paragraph:
+    /// the first grapheme of the line is rendered rightmost and the following graphemes to its
+    /// left. The alignment is mirrored too, so that left aligned lines (the default) start at the
+    /// right edge and right aligned lines end at the left edge. The text is wrapped and scrolled as
+    /// in the default [`TextDirection::Ltr`] direction. This only reverses the order of the
+    /// graphemes of each line, it doesn't implement the bidirectional algorithm, so left to right
+    /// runs embedded in right to left text (e.g. numbers) are reversed too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("שלום עולם")
+    ///     .direction(TextDirection::Rtl)
+    ///     .wrap(Wrap { trim: true });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the range of the text that is highlighted.
+    ///
+    /// The range goes from the first to the last highlighted positions, both included. Positions
+    /// are expressed in the coordinates of the text, not of the rendered area: `y` is the index of
+    /// the line of text and `x` the index of the grapheme in that line. The highlight therefore
+    /// follows the text when it is wrapped or scrolled, and can span several lines. The positions
+    /// can be given in any order. `None` (the default) removes the highlight.
+    ///
+    /// The [highlight style](Paragraph::highlight_style) is applied on top of the style of the
+    /// highlighted text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // highlights "world" and "Good"
+    /// let paragraph = Paragraph::new("Hello world\nGood bye")
+    ///     .highlight_range(Some((Position::new(6, 0), Position::new(3, 1))))
+    ///     .highlight_style(Style::new().reversed());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_range(mut self, range: Option<(Position, Position)>) -> Self {
+        self.highlight_range = range;
+        self
+    }
+
+    /// Sets the style of the text in the [highlight range](Paragraph::highlight_range).
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("Hello world")
+    ///     .highlight_range(Some((Position::new(0, 0), Position::new(4, 0))))
+    ///     .highlight_style(Style::new().black().on_yellow());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
     /// Calculates the number of lines needed to fully render.
     ///
     /// Given a max line width, this method calculates the number of lines that a paragraph will
@@ -275,20 +522,13 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui-org/ratatui/issues/293"
     )]
     pub fn line_count(&self, width: u16) -> usize {
+        let width = width.saturating_sub(self.gutter_width());
         if width < 1 {
             return 0;
         }
 
-        if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
+        if self.wrap.is_some() {
+            let mut line_composer = self.line_composer(width);
             let mut count = 0;
             while line_composer.next_line().is_some() {
                 count += 1;
@@ -299,6 +539,60 @@ impl<'a> Paragraph<'a> {
         }
     }
 
+    /// Returns the lines of the paragraph as they are displayed in an area of the given width.
+    ///
+    /// The text is wrapped (or truncated when the paragraph is not [wrapped](Self::wrap)) the same
+    /// way as when rendering, so the returned lines can be computed ahead of time, e.g. to cache
+    /// them or to only render the visible part of a large text. Each line has the resolved
+    /// alignment and the styles of the paragraph, text, line and spans. The block, the line
+    /// numbers gutter and the vertical scroll offset are not included.
+    ///
+    /// Note: The design for text wrapping is not stable and might affect this API.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unstable-rendered-line-info")] {
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+    /// assert_eq!(
+    ///     paragraph.wrapped_lines(8),
+    ///     [
+    ///         Line::from("Hello").left_aligned(),
+    ///         Line::from("World").left_aligned()
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    #[stability::unstable(
+        feature = "rendered-line-info",
+        issue = "https://github.com/ratatui-org/ratatui/issues/293"
+    )]
+    pub fn wrapped_lines(&self, width: u16) -> Vec<Line<'static>> {
+        let width = width.saturating_sub(self.gutter_width());
+        if width < 1 {
+            return vec![];
+        }
+
+        let mut lines = vec![];
+        let mut composer = self.line_composer(width);
+        while let Some(WrappedLine {
+            line, alignment, ..
+        }) = composer.next_line()
+        {
+            let mut spans: Vec<Span<'static>> = vec![];
+            for StyledGrapheme { symbol, style } in line {
+                let style = self.style.patch(*style);
+                match spans.last_mut() {
+                    Some(span) if span.style == style => span.content.to_mut().push_str(symbol),
+                    _ => spans.push(Span::styled((*symbol).to_string(), style)),
+                }
+            }
+            lines.push(Line::from(spans).alignment(alignment));
+        }
+        lines
+    }
+
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
     ///
     /// Note: The design for text wrapping is not stable and might affect this API.
@@ -318,7 +612,109 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui-org/ratatui/issues/293"
     )]
     pub fn line_width(&self) -> usize {
-        self.text.iter().map(Line::width).max().unwrap_or_default()
+        self.text
+            .iter()
+            .map(|line| {
+                expand_tabs(line.styled_graphemes(Style::default()), self.tab_width)
+                    .map(|grapheme| grapheme.symbol.width())
+                    .sum::<usize>()
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the areas where the link spans of the paragraph are rendered in the given area.
+    ///
+    /// The spans for which `is_link` returns `true` are the links, e.g. spans with a specific
+    /// style. Each area is returned with the index of the line of the text and the index of the
+    /// span in that line, and covers the cells of one row where the span is rendered, after
+    /// wrapping, alignment and scrolling. A span that is wrapped over several rows has an area per
+    /// row, and a span that is scrolled out of view has none. The areas are in the order of the
+    /// rows, from left to right. This is meant for hit-testing, e.g. to find the link under a
+    /// mouse click; rendering the paragraph is not affected.
+    ///
+    /// Note: The design for text wrapping is not stable and might affect this API.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "unstable-rendered-line-info")] {
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let text = Line::from(vec!["See ".into(), "the docs".underlined(), " here".into()]);
+    /// let paragraph = Paragraph::new(text);
+    /// let areas = paragraph.rendered_link_areas(Rect::new(0, 0, 20, 1), |span| {
+    ///     span.style.add_modifier.contains(Modifier::UNDERLINED)
+    /// });
+    /// assert_eq!(areas, [((0, 1), Rect::new(4, 0, 8, 1))]);
+    /// # }
+    /// ```
+    #[stability::unstable(
+        feature = "rendered-line-info",
+        issue = "https://github.com/ratatui-org/ratatui/issues/293"
+    )]
+    pub fn rendered_link_areas<F>(&self, area: Rect, is_link: F) -> Vec<((usize, usize), Rect)>
+    where
+        F: Fn(&Span) -> bool,
+    {
+        // the graphemes of the composer output borrow the content of the spans, so a grapheme is
+        // part of a span when it points into the content of the span
+        let is_link = &is_link;
+        let spans: Vec<_> = self
+            .text
+            .iter()
+            .enumerate()
+            .flat_map(|(line_index, line)| {
+                line.spans
+                    .iter()
+                    .enumerate()
+                    .map(move |(span_index, span)| {
+                        let start = span.content.as_ptr() as usize;
+                        let range = start..start + span.content.len();
+                        ((line_index, span_index), range, is_link(span))
+                    })
+            })
+            .collect();
+        let inner = self.block.inner_if_some(area);
+        let mut areas: Vec<((usize, usize), Rect)> = vec![];
+        if !spans.iter().any(|(_, _, is_link)| *is_link) || inner.is_empty() {
+            return areas;
+        }
+        // the graphemes are laid out in the order of the text, so the span of a grapheme is
+        // searched from the span of the previous one to tell apart spans with the same content
+        let mut current = 0;
+        let text_width = inner.width.saturating_sub(self.gutter_width());
+        self.layout_rows(self.line_composer(text_width), inner, |row| {
+            current = current.max(spans.partition_point(|((line, _), ..)| *line < row.line_index));
+            for (x, grapheme) in row.graphemes {
+                let address = grapheme.symbol.as_ptr() as usize;
+                let Some(index) = spans[current..]
+                    .iter()
+                    .take_while(|((line, _), ..)| *line == row.line_index)
+                    .position(|(_, range, _)| range.contains(&address))
+                else {
+                    continue;
+                };
+                current += index;
+                let (link, _, is_link) = spans[current];
+                if !is_link {
+                    continue;
+                }
+                let width = grapheme.symbol.width() as u16;
+                match areas.last_mut() {
+                    Some((last, rect)) if *last == link && rect.y == row.y && rect.right() == x => {
+                        rect.width += width;
+                    }
+                    Some((last, rect))
+                        if *last == link && rect.y == row.y && x + width == rect.x =>
+                    {
+                        rect.x = x;
+                        rect.width += width;
+                    }
+                    _ => areas.push((link, Rect::new(x, row.y, width, 1))),
+                }
+            }
+        });
+        areas
     }
 }
 
@@ -338,54 +734,154 @@ impl WidgetRef for Paragraph<'_> {
 }
 
 impl Paragraph<'_> {
+    /// Returns the width of the line numbers gutter, including the space separating it from the
+    /// text, or 0 if line numbers are disabled
+    fn gutter_width(&self) -> u16 {
+        if self.line_numbers {
+            self.text.height().max(1).ilog10() as u16 + 2
+        } else {
+            0
+        }
+    }
+
     fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
+        let text_width = text_area.width.saturating_sub(self.gutter_width());
+        self.render_text(self.line_composer(text_width), text_area, buf);
+    }
+
+    /// Returns whether the grapheme at the given position of the text is highlighted
+    fn is_highlighted(&self, line_index: usize, column: usize) -> bool {
+        let Some((start, end)) = self.highlight_range else {
+            return false;
+        };
+        let (start, end) = if (start.y, start.x) <= (end.y, end.x) {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let position = (line_index, column);
+        (usize::from(start.y), usize::from(start.x)) <= position
+            && position <= (usize::from(end.y), usize::from(end.x))
+    }
+
+    /// Returns a composer that wraps or truncates the lines of the paragraph to the given width
+    fn line_composer(&self, text_width: u16) -> impl LineComposer<'_> + '_ {
+        let styled = self.text.iter().enumerate().map(move |(line_index, line)| {
+            let graphemes = line.styled_graphemes(self.text.style).enumerate().map(
+                move |(column, mut grapheme)| {
+                    if self.is_highlighted(line_index, column) {
+                        grapheme.style = grapheme.style.patch(self.highlight_style);
+                    }
+                    grapheme
+                },
+            );
+            let graphemes = expand_tabs(graphemes, self.tab_width);
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
 
         if let Some(Wrap { trim }) = self.wrap {
-            let line_composer = WordWrapper::new(styled, text_area.width, trim);
-            self.render_text(line_composer, text_area, buf);
+            let mut line_composer = WordWrapper::new(styled, text_width, trim);
+            line_composer.set_justify(self.justify);
+            line_composer.set_break_long_words(self.break_long_words);
+            line_composer.set_hyphenate(self.hyphenate);
+            Composer::Wrapped(line_composer)
         } else {
-            let mut line_composer = LineTruncator::new(styled, text_area.width);
+            let mut line_composer = LineTruncator::new(styled, text_width);
             line_composer.set_horizontal_offset(self.scroll.1);
-            self.render_text(line_composer, text_area, buf);
+            Composer::Truncated(line_composer)
         }
     }
-}
 
-impl<'a> Paragraph<'a> {
-    fn render_text<C: LineComposer<'a>>(&self, mut composer: C, area: Rect, buf: &mut Buffer) {
+    fn render_text<'a>(&self, composer: impl LineComposer<'a>, area: Rect, buf: &mut Buffer) {
+        let gutter_width = self.gutter_width();
+        self.layout_rows(composer, area, |row| {
+            // Continuation rows of wrapped lines have a blank gutter
+            if self.line_numbers && row.is_first {
+                let number = format!(
+                    "{:>width$}",
+                    row.line_index + 1,
+                    width = usize::from(gutter_width - 1)
+                );
+                buf.set_stringn(
+                    area.left(),
+                    row.y,
+                    number,
+                    usize::from(gutter_width - 1),
+                    self.line_number_style,
+                );
+            }
+            for (x, StyledGrapheme { symbol, style }) in row.graphemes {
+                // If the symbol is empty, the last char which rendered last time will
+                // leave on the line. It's a quick fix.
+                let symbol = if symbol.is_empty() { " " } else { symbol };
+                buf.get_mut(x, row.y).set_symbol(symbol).set_style(*style);
+            }
+        });
+    }
+
+    /// Lays out the rows produced by `composer` in `area`, skipping the rows scrolled out of view
+    ///
+    /// `visit` is called with each visible row, in order.
+    fn layout_rows<'a>(
+        &self,
+        mut composer: impl LineComposer<'a>,
+        area: Rect,
+        mut visit: impl FnMut(RowLayout<'_, 'a, '_>),
+    ) {
+        let gutter_width = self.gutter_width();
+        let mut previous_line_index = None;
         let mut y = 0;
         while let Some(WrappedLine {
             line: current_line,
             width: current_line_width,
             alignment: current_line_alignment,
+            line_index,
         }) = composer.next_line()
         {
             if y >= self.scroll.0 {
-                let mut x = get_line_offset(current_line_width, area.width, current_line_alignment);
-                for StyledGrapheme { symbol, style } in current_line {
-                    let width = symbol.width();
-                    if width == 0 {
-                        continue;
-                    }
-                    // If the symbol is empty, the last char which rendered last time will
-                    // leave on the line. It's a quick fix.
-                    let symbol = if symbol.is_empty() { " " } else { symbol };
-                    buf.get_mut(area.left() + x, area.top() + y - self.scroll.0)
-                        .set_symbol(symbol)
-                        .set_style(*style);
-                    x += width as u16;
-                }
+                let line_x = gutter_width
+                    + get_line_offset(
+                        current_line_width,
+                        area.width - gutter_width,
+                        self.direction.align(current_line_alignment),
+                    );
+                // right to left lines are laid out from their right end
+                let start_x = match self.direction {
+                    TextDirection::Ltr => line_x,
+                    TextDirection::Rtl => line_x + current_line_width,
+                };
+                let direction = self.direction;
+                let mut graphemes = current_line
+                    .iter()
+                    .filter(|grapheme| grapheme.symbol.width() > 0)
+                    .scan(start_x, move |x, grapheme| {
+                        let width = grapheme.symbol.width() as u16;
+                        let symbol_x = match direction {
+                            TextDirection::Ltr => {
+                                *x += width;
+                                *x - width
+                            }
+                            TextDirection::Rtl => {
+                                *x = x.saturating_sub(width);
+                                *x
+                            }
+                        };
+                        Some((area.left() + symbol_x, grapheme))
+                    });
+                visit(RowLayout {
+                    y: area.top() + y - self.scroll.0,
+                    line_index,
+                    is_first: previous_line_index != Some(line_index),
+                    graphemes: &mut graphemes,
+                });
             }
+            previous_line_index = Some(line_index);
             y += 1;
             if y >= area.height + self.scroll.0 {
                 break;
@@ -394,6 +890,41 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+/// The line composer of a [`Paragraph`], depending on whether it is wrapped
+enum Composer<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    Wrapped(WordWrapper<'a, O, I>),
+    Truncated(LineTruncator<'a, O, I>),
+}
+
+impl<'a, O, I> LineComposer<'a> for Composer<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+        match self {
+            Self::Wrapped(composer) => composer.next_line(),
+            Self::Truncated(composer) => composer.next_line(),
+        }
+    }
+}
+
+/// A row of text laid out in the area of a [`Paragraph`]
+struct RowLayout<'g, 'a, 'i> {
+    /// The y coordinate of the row
+    y: u16,
+    /// The index of the line of text the row was reflowed from
+    line_index: usize,
+    /// Whether this is the first row of the line of text
+    is_first: bool,
+    /// The visible graphemes of the row, with their x coordinate
+    graphemes: &'i mut dyn Iterator<Item = (u16, &'g StyledGrapheme<'a>)>,
+}
+
 impl<'a> Styled for Paragraph<'a> {
     type Item = Self;
 
@@ -777,6 +1308,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_justify() {
+        let text = Line::from(vec![
+            "The quick ".into(),
+            "brown".red(),
+            " fox jumps over the lazy dog".into(),
+        ]);
+        let paragraph = Paragraph::new(vec![text, Line::from("Lorem ipsum")])
+            .wrap(Wrap { trim: true })
+            .justify(true)
+            .centered();
+        let mut expected = Buffer::with_lines([
+            "The  quick brown fox",
+            "jumps  over the lazy",
+            "dog                 ",
+            "Lorem ipsum         ",
+        ]);
+        expected.set_style(Rect::new(11, 0, 5, 1), Style::new().red());
+        test_case(&paragraph, &expected);
+    }
+
     #[test]
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
@@ -802,6 +1354,100 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_horizontal_scroll_of_wide_characters() {
+        let text = Text::from(vec![
+            Line::from(vec!["你好".into(), "世界".red(), "abc".into()]),
+            Line::from("a你好世界"),
+        ]);
+        let paragraph = Paragraph::new(text).scroll((0, 3));
+        let expected = Buffer::with_lines([
+            Line::from(vec![" ".into(), "世界".red(), "abc".into()]),
+            Line::from("好世界  "),
+        ]);
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_line_numbers() {
+        let text: Vec<Line> = (1..=100).map(|i| Line::from(format!("line {i}"))).collect();
+        let paragraph = Paragraph::new(text)
+            .line_numbers(true)
+            .line_number_style(Style::new().dark_gray())
+            .scroll((89, 0));
+        let mut expected = Buffer::with_lines([
+            " 90 line 90",
+            " 91 line 91",
+            " 92 line 92",
+            " 93 line 93",
+            " 94 line 94",
+        ]);
+        expected.set_style(Rect::new(0, 0, 3, 5), Style::new().dark_gray());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_line_numbers_and_wrap() {
+        let paragraph = Paragraph::new("first line\nsecond")
+            .line_numbers(true)
+            .wrap(Wrap { trim: true });
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["1 first", "  line ", "2 secon", "  d    "]),
+        );
+        test_case(
+            &paragraph.scroll((1, 0)),
+            &Buffer::with_lines(["  line ", "2 secon"]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_long_words() {
+        let chunks: Vec<String> = (0..6).map(|i| i.to_string().repeat(20)).collect();
+        let paragraph = Paragraph::new(format!("id {}", chunks.concat())).wrap(Wrap { trim: true });
+        let mut lines = vec!["id                  "];
+        lines.extend(chunks.iter().map(String::as_str));
+        test_case(&paragraph, &Buffer::with_lines(lines));
+        assert_eq!(paragraph.line_count(20), 7);
+
+        let paragraph = paragraph.break_long_words(false);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["id                  ", &chunks[0]]),
+        );
+        assert_eq!(paragraph.line_count(20), 2);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_hyphenation() {
+        let paragraph = Paragraph::new("the in\u{ad}de\u{ad}pend\u{ad}ence day")
+            .wrap(Wrap { trim: true })
+            .hyphenate(true);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["the inde-", "pendence ", "day      "]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_tabs() {
+        let text = Text::from(vec![
+            Line::from(vec!["a\tb".into(), "\tc".red(), " \td".into()]),
+            Line::from("\t \tx"),
+            Line::from("ab\tc").right_aligned(),
+        ]);
+        let paragraph = Paragraph::new(text).tab_width(4);
+        let mut expected =
+            Buffer::with_lines(["a   b   c   d ", "        x     ", "         ab  c"]);
+        expected.set_style(Rect::new(5, 0, 4, 1), Style::new().red());
+        test_case(&paragraph, &expected);
+
+        let paragraph = paragraph.wrap(Wrap { trim: false });
+        let mut expected = Buffer::with_lines(["a   b   c", "  d      ", "        x", "    ab  c"]);
+        expected.set_style(Rect::new(5, 0, 4, 1), Style::new().red());
+        test_case(&paragraph, &expected);
+    }
+
     #[test]
     fn test_render_paragraph_with_zero_width_area() {
         let text = "Hello, world!";
@@ -861,6 +1507,95 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_render_paragraph_with_highlight_range() {
+        let text = Line::from(vec!["Hello, ".red(), "world!".blue()]);
+        let paragraph = Paragraph::new(text)
+            .highlight_range(Some((
+                layout::Position::new(4, 0),
+                layout::Position::new(8, 0),
+            )))
+            .highlight_style(Style::new().bold());
+
+        let mut expected = Buffer::with_lines(["Hello, world!"]);
+        expected.set_style(Rect::new(0, 0, 7, 1), Style::new().red());
+        expected.set_style(Rect::new(7, 0, 6, 1), Style::new().blue());
+        expected.set_style(Rect::new(4, 0, 5, 1), Style::new().bold());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_highlight_range_across_lines() {
+        let text = "This is a\ncool\nmultiline\nparagraph.";
+        // the range is given from the end to the start
+        let paragraph = Paragraph::new(text)
+            .highlight_range(Some((
+                layout::Position::new(4, 2),
+                layout::Position::new(8, 0),
+            )))
+            .highlight_style(Style::new().reversed());
+
+        let mut expected = Buffer::with_lines(["This is a ", "cool      ", "multiline "]);
+        expected.set_style(Rect::new(8, 0, 1, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 2, 5, 1), Style::new().reversed());
+        test_case(&paragraph, &expected);
+
+        // the highlight follows the text when it is scrolled
+        let mut expected = Buffer::with_lines(["cool      ", "multiline "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 1, 5, 1), Style::new().reversed());
+        test_case(&paragraph.scroll((1, 0)), &expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_highlight_range_and_wrap() {
+        let paragraph = Paragraph::new("Hello, world!")
+            .wrap(Wrap { trim: true })
+            .highlight_range(Some((
+                layout::Position::new(5, 0),
+                layout::Position::new(8, 0),
+            )))
+            .highlight_style(Style::new().bold());
+
+        let mut expected = Buffer::with_lines(["Hello,  ", "world!  "]);
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().bold());
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().bold());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_rtl_direction() {
+        let paragraph = Paragraph::new("שלום עולם").direction(TextDirection::Rtl);
+        test_case(&paragraph, &Buffer::with_lines(["   םלוע םולש"]));
+        // the alignment is mirrored
+        test_case(
+            &paragraph.clone().right_aligned(),
+            &Buffer::with_lines(["םלוע םולש   "]),
+        );
+        test_case(
+            &paragraph.clone().centered(),
+            &Buffer::with_lines(["  םלוע םולש "]),
+        );
+        // the end of the lines is truncated
+        test_case(&paragraph, &Buffer::with_lines(["וע םולש"]));
+    }
+
+    #[test]
+    fn test_render_paragraph_with_rtl_direction_and_wrap() {
+        let paragraph = Paragraph::new("שלום עולם\nטוב")
+            .direction(TextDirection::Rtl)
+            .wrap(Wrap { trim: true });
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["  םולש", "  םלוע", "   בוט"]),
+        );
+        test_case(
+            &paragraph.scroll((1, 0)),
+            &Buffer::with_lines(["  םלוע", "   בוט"]),
+        );
+    }
+
     #[test]
     fn test_render_paragraph_with_special_characters() {
         let text = "Hello, <world>!";
@@ -947,6 +1682,20 @@ mod test {
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[test]
+    fn widgets_paragraph_count_rendered_lines_with_line_breaks() {
+        let text = "Hello World\n\n    Hello World";
+        let paragraph = Paragraph::new(text);
+        assert_eq!(paragraph.line_count(11), 3);
+        // the indentation of the last line pushes "World" to another row unless it is trimmed
+        let paragraph = paragraph.wrap(Wrap { trim: false });
+        assert_eq!(paragraph.line_count(11), 4);
+        assert_eq!(paragraph.wrapped_lines(11).len(), 4);
+        let paragraph = paragraph.wrap(Wrap { trim: true });
+        assert_eq!(paragraph.line_count(11), 3);
+        assert_eq!(paragraph.wrapped_lines(11).len(), 3);
+    }
+
     #[test]
     fn widgets_paragraph_line_width() {
         let paragraph = Paragraph::new("Hello World");
@@ -965,6 +1714,107 @@ mod test {
         assert_eq!(paragraph.line_width(), 1200);
     }
 
+    #[test]
+    fn widgets_paragraph_wrapped_lines() {
+        let paragraph = Paragraph::new(Line::from(vec![
+            "The quick brown ".into(),
+            "fox".red(),
+            " jumps over the lazy dog".into(),
+        ]))
+        .style(Style::new().on_blue())
+        .wrap(Wrap { trim: true });
+        assert_eq!(
+            paragraph.wrapped_lines(10),
+            [
+                Line::from("The quick".on_blue()).left_aligned(),
+                Line::from(vec!["brown ".on_blue(), "fox".red().on_blue()]).left_aligned(),
+                Line::from("jumps over".on_blue()).left_aligned(),
+                Line::from("the lazy".on_blue()).left_aligned(),
+                Line::from("dog".on_blue()).left_aligned(),
+            ]
+        );
+        assert_eq!(paragraph.wrapped_lines(10).len(), paragraph.line_count(10));
+
+        let paragraph = Paragraph::new("Hello World").right_aligned();
+        assert_eq!(
+            paragraph.wrapped_lines(8),
+            [Line::from("Hello Wo").right_aligned()]
+        );
+        assert_eq!(paragraph.wrapped_lines(0), []);
+    }
+
+    fn is_link(span: &Span) -> bool {
+        span.style.add_modifier.contains(Modifier::UNDERLINED)
+    }
+
+    #[test]
+    fn rendered_link_areas_match_rendered_cells_after_scrolling() {
+        let text = vec![
+            Line::from("first line"),
+            Line::from(vec!["go to ".into(), "ratatui".underlined(), " now".into()]),
+            Line::from(vec!["and ".into(), "docs".underlined()]),
+        ];
+        let paragraph = Paragraph::new(text).block(Block::bordered()).scroll((1, 0));
+        let area = Rect::new(0, 0, 20, 4);
+        let areas = paragraph.rendered_link_areas(area, is_link);
+        assert_eq!(
+            areas,
+            [
+                ((1, 1), Rect::new(7, 1, 7, 1)),
+                ((2, 1), Rect::new(5, 2, 4, 1)),
+            ]
+        );
+
+        let mut buf = Buffer::empty(area);
+        paragraph.render(area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "┌──────────────────┐",
+            "│go to ratatui now │",
+            "│and docs          │",
+            "└──────────────────┘",
+        ]);
+        for (_, rect) in areas {
+            expected.set_style(rect, Style::new().underlined());
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn rendered_link_areas_of_wrapped_link() {
+        let text = Line::from(vec![
+            "see ".into(),
+            "long link text".underlined(),
+            " ok".into(),
+        ]);
+        let paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let area = Rect::new(2, 3, 9, 2);
+        // the rows are "see long", "link text" and "ok"
+        assert_eq!(
+            paragraph.rendered_link_areas(area, is_link),
+            [
+                ((0, 1), Rect::new(6, 3, 4, 1)),
+                ((0, 1), Rect::new(2, 4, 9, 1)),
+            ]
+        );
+
+        let paragraph = paragraph.scroll((1, 0));
+        assert_eq!(
+            paragraph.rendered_link_areas(area, is_link),
+            [((0, 1), Rect::new(2, 3, 9, 1))]
+        );
+    }
+
+    #[test]
+    fn rendered_link_areas_of_links_with_the_same_content() {
+        let text = Line::from(vec!["a".into(), " a ".into(), "a".underlined()]);
+        let paragraph = Paragraph::new(text).direction(TextDirection::Rtl);
+        // the text is rendered as "a a a" from the right edge of the area
+        assert_eq!(
+            paragraph.rendered_link_areas(Rect::new(0, 0, 6, 1), is_link),
+            [((0, 2), Rect::new(1, 0, 1, 1))]
+        );
+    }
+
     #[test]
     fn left_aligned() {
         let p = Paragraph::new("Hello, world!").left_aligned();
@@ -0,0 +1,110 @@
+use crate::{
+    prelude::*,
+    widgets::{Block, Clear},
+};
+
+/// The highlighted item of a popup list and the first visible item
+///
+/// This is shared by the states of the [`Suggestions`](super::Suggestions) and
+/// [`CommandPalette`](super::CommandPalette) popups.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Selection {
+    pub offset: usize,
+    pub selected: Option<usize>,
+}
+
+impl Selection {
+    /// Sets the index of the highlighted item, resetting the offset when nothing is highlighted
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        if index.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Highlights the next item or the first one if no item is highlighted
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        self.select(Some(next));
+    }
+
+    /// Highlights the previous item or the last one if no item is highlighted
+    ///
+    /// The index is corrected to the last item when rendering.
+    pub fn select_previous(&mut self) {
+        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select(Some(previous));
+    }
+
+    /// Clamps the highlighted item to the last of `len` items
+    pub fn clamp(&mut self, len: usize) {
+        match len {
+            0 => self.select(None),
+            len => self.selected = self.selected.map(|selected| selected.min(len - 1)),
+        }
+    }
+}
+
+/// Clears the area of a popup and draws its style and block
+///
+/// Returns the area inside the block.
+pub fn render_popup(block: Option<&Block>, style: Style, area: Rect, buf: &mut Buffer) -> Rect {
+    Clear.render(area, buf);
+    buf.set_style(area, style);
+    let Some(block) = block else {
+        return area;
+    };
+    block.render_ref(area, buf);
+    block.inner(area)
+}
+
+/// Renders the lines of `len` items, scrolled so that the highlighted item is visible
+///
+/// `line` returns the line of the item at the given index. The highlighted item is styled with
+/// `highlight_style`.
+pub fn render_items<'a>(
+    selection: &mut Selection,
+    len: usize,
+    line: impl Fn(usize) -> Line<'a>,
+    highlight_style: Style,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if area.is_empty() {
+        return;
+    }
+    let visible = usize::from(area.height);
+    if let Some(selected) = selection.selected {
+        selection.offset = selection
+            .offset
+            .clamp((selected + 1).saturating_sub(visible), selected);
+    }
+    selection.offset = selection.offset.min(len.saturating_sub(visible));
+
+    for (row, index) in area.rows().zip(selection.offset..len) {
+        line(index).render(row, buf);
+        if selection.selected == Some(index) {
+            buf.set_style(row, highlight_style);
+        }
+    }
+}
+
+/// Implements [`Styled`] for a popup widget with a `style` field and a `style` setter
+macro_rules! impl_styled {
+    ($popup:ident) => {
+        impl Styled for $popup<'_> {
+            type Item = Self;
+
+            fn style(&self) -> Style {
+                self.style
+            }
+
+            fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+                self.style(style)
+            }
+        }
+    };
+}
+
+pub(super) use impl_styled;
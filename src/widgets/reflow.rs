@@ -1,12 +1,16 @@
-use std::{collections::VecDeque, vec::IntoIter};
+use std::{collections::VecDeque, iter, vec::IntoIter};
 
-use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::{layout::Alignment, text::StyledGrapheme};
+use crate::{
+    layout::Alignment,
+    style::Style,
+    text::{Line, Span, StyledGrapheme},
+};
 
 const NBSP: &str = "\u{00a0}";
 const ZWSP: &str = "\u{200b}";
+const SHY: &str = "\u{00ad}";
 
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
@@ -22,10 +26,13 @@ pub struct WrappedLine<'lend, 'text> {
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
+    /// The index of the input line this line was reflowed from
+    pub line_index: usize,
 }
 
 /// A state machine that wraps lines on word boundaries.
 #[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_excessive_bools)] // independent wrapping options
 pub struct WordWrapper<'a, O, I>
 where
     // Outer iterator providing the individual lines
@@ -39,9 +46,17 @@ where
     max_line_width: u16,
     wrapped_lines: Option<IntoIter<Vec<StyledGrapheme<'a>>>>,
     current_alignment: Alignment,
+    /// Index of the input line being wrapped
+    current_line_index: Option<usize>,
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Stretches the whitespace between words so that wrapped lines fill the max line width
+    justify: bool,
+    /// Continues words wider than the max line width on the next line instead of truncating them
+    break_long_words: bool,
+    /// Breaks words at soft hyphens, showing a hyphen at the end of the line
+    hyphenate: bool,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
@@ -55,10 +70,40 @@ where
             max_line_width,
             wrapped_lines: None,
             current_alignment: Alignment::Left,
+            current_line_index: None,
             current_line: vec![],
             trim,
+            justify: false,
+            break_long_words: true,
+            hyphenate: false,
         }
     }
+
+    /// Distributes extra whitespace between the words of each wrapped line so that both margins
+    /// are flush.
+    ///
+    /// The last line of each input line and lines containing a single word are left as is. All
+    /// lines are reported as left aligned when justifying.
+    pub fn set_justify(&mut self, justify: bool) {
+        self.justify = justify;
+    }
+
+    /// Sets whether words wider than the max line width are broken across lines.
+    ///
+    /// When enabled (the default), such a word is broken at the max line width and continues on
+    /// the next line. When disabled, the word is truncated at the end of its line instead.
+    pub fn set_break_long_words(&mut self, break_long_words: bool) {
+        self.break_long_words = break_long_words;
+    }
+
+    /// Sets whether words can be broken at soft hyphens (`U+00AD`).
+    ///
+    /// When enabled, soft hyphens are not displayed. A word that does not fit on a line is broken
+    /// at its last soft hyphen that fits, and a visible `-` is shown at the end of the line. This
+    /// is preferred over breaking or truncating long words at an arbitrary position.
+    pub fn set_hyphenate(&mut self, hyphenate: bool) {
+        self.hyphenate = hyphenate;
+    }
 }
 
 impl<'a, O, I> LineComposer<'a> for WordWrapper<'a, O, I>
@@ -79,7 +124,11 @@ where
         while current_line.is_none() {
             // Retrieve next preprocessed wrapped line
             if let Some(line_iterator) = &mut self.wrapped_lines {
-                if let Some(line) = line_iterator.next() {
+                if let Some(mut line) = line_iterator.next() {
+                    // The last wrapped line of an input line is never justified
+                    if self.justify && line_iterator.len() > 0 {
+                        line = justify(line, self.max_line_width);
+                    }
                     line_width = line
                         .iter()
                         .map(|grapheme| grapheme.symbol.width())
@@ -94,6 +143,7 @@ where
                 if let Some((line_symbols, line_alignment)) = &mut self.input_lines.next() {
                     // Save the whole line's alignment
                     self.current_alignment = *line_alignment;
+                    self.current_line_index = Some(self.current_line_index.map_or(0, |i| i + 1));
                     let mut wrapped_lines = vec![]; // Saves the wrapped lines
                                                     // Saves the unfinished wrapped line
                     let (mut current_line, mut current_line_width) = (vec![], 0);
@@ -102,6 +152,10 @@ where
                     // Saves the whitespaces of the partially unfinished word
                     let (mut unfinished_whitespaces, mut whitespace_width) =
                         (VecDeque::<StyledGrapheme>::new(), 0);
+                    // Saves the soft hyphens of the partially processed word
+                    let mut hyphens = vec![];
+                    // Whether the rest of the current word is dropped
+                    let mut truncating_word = false;
 
                     let mut has_seen_non_whitespace = false;
                     for StyledGrapheme { symbol, style } in line_symbols {
@@ -112,6 +166,21 @@ where
                         if symbol_width > self.max_line_width {
                             continue;
                         }
+                        if truncating_word {
+                            if !symbol_whitespace {
+                                continue;
+                            }
+                            truncating_word = false;
+                        }
+                        if self.hyphenate && symbol == SHY {
+                            hyphens.push(Hyphen {
+                                index: unfinished_word.len(),
+                                width: word_width,
+                                style,
+                            });
+                            continue;
+                        }
+                        let mut hyphenated = false;
 
                         // Append finished word to current line
                         if has_seen_non_whitespace && symbol_whitespace
@@ -130,14 +199,64 @@ where
                                 );
                                 current_line_width += whitespace_width;
                             }
-                            // Append trimmed word
-                            current_line.append(&mut unfinished_word);
-                            current_line_width += word_width;
+                            // The word does not fit on a line of its own
+                            let word_overflows = !symbol_whitespace
+                                && current_line_width + word_width + symbol_width
+                                    > self.max_line_width;
+                            let hyphen = if word_overflows {
+                                take_hyphen(&mut hyphens, self.max_line_width - current_line_width)
+                            } else {
+                                None
+                            };
+                            if let Some(hyphen) = hyphen {
+                                // Append the word up to the soft hyphen
+                                let rest = unfinished_word.split_off(hyphen.index);
+                                current_line.append(&mut unfinished_word);
+                                current_line.push(StyledGrapheme::new("-", hyphen.style));
+                                current_line_width += hyphen.width + 1;
+                                unfinished_word = rest;
+                                word_width -= hyphen.width;
+                                hyphenated = true;
+                            } else {
+                                // Append trimmed word
+                                current_line.append(&mut unfinished_word);
+                                current_line_width += word_width;
+                                word_width = 0;
+                                hyphens.clear();
+                                truncating_word = word_overflows && !self.break_long_words;
+                            }
 
                             // Clear whitespace buffer
                             unfinished_whitespaces.clear();
                             whitespace_width = 0;
-                            word_width = 0;
+                        }
+                        if truncating_word {
+                            continue;
+                        }
+
+                        // Break the partially processed word at a soft hyphen if it would be too
+                        // long for the current line
+                        if !hyphenated
+                            && !current_line.is_empty()
+                            && current_line_width + whitespace_width + word_width
+                                >= self.max_line_width
+                            && symbol_width > 0
+                        {
+                            if let Some(hyphen) = take_hyphen(
+                                &mut hyphens,
+                                self.max_line_width
+                                    .saturating_sub(current_line_width + whitespace_width),
+                            ) {
+                                let rest = unfinished_word.split_off(hyphen.index);
+                                current_line.extend(std::mem::take(&mut unfinished_whitespaces));
+                                current_line.append(&mut unfinished_word);
+                                current_line.push(StyledGrapheme::new("-", hyphen.style));
+                                current_line_width += whitespace_width + hyphen.width + 1;
+                                unfinished_word = rest;
+                                whitespace_width = 0;
+                                word_width -= hyphen.width;
+                                hyphenated = true;
+                            }
                         }
 
                         // Append the unfinished wrapped line to wrapped lines if it is as wide as
@@ -145,6 +264,8 @@ where
                         if current_line_width >= self.max_line_width
                             // or if it would be too long with the current partially processed word added
                             || current_line_width + whitespace_width + word_width >= self.max_line_width && symbol_width > 0
+                            // or if a word was just broken at a soft hyphen
+                            || hyphenated
                         {
                             let mut remaining_width = (i32::from(self.max_line_width)
                                 - i32::from(current_line_width))
@@ -217,7 +338,12 @@ where
             Some(WrappedLine {
                 line: &self.current_line,
                 width: line_width,
-                alignment: self.current_alignment,
+                alignment: if self.justify {
+                    Alignment::Left
+                } else {
+                    self.current_alignment
+                },
+                line_index: self.current_line_index.unwrap_or_default(),
             })
         } else {
             None
@@ -241,6 +367,8 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
+    /// Index of the next input line
+    next_line_index: usize,
 }
 
 impl<'a, O, I> LineTruncator<'a, O, I>
@@ -254,6 +382,7 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            next_line_index: 0,
         }
     }
 
@@ -281,6 +410,7 @@ where
         if let Some((current_line, alignment)) = &mut self.input_lines.next() {
             lines_exhausted = false;
             current_alignment = *alignment;
+            self.next_line_index += 1;
 
             for StyledGrapheme { symbol, style } in current_line {
                 // Ignore characters wider that the total max width.
@@ -293,19 +423,21 @@ where
                     break;
                 }
 
-                let symbol = if horizontal_offset == 0 || Alignment::Left != *alignment {
-                    symbol
-                } else {
-                    let w = symbol.width();
-                    if w > horizontal_offset {
-                        let t = trim_offset(symbol, horizontal_offset);
-                        horizontal_offset = 0;
-                        t
-                    } else {
-                        horizontal_offset -= w;
-                        ""
+                if horizontal_offset > 0 && Alignment::Left == *alignment {
+                    let width = symbol.width();
+                    if width <= horizontal_offset {
+                        horizontal_offset -= width;
+                        continue;
                     }
-                };
+                    // A wide symbol straddles the left edge, only its visible columns are kept
+                    // and they are blanked as the symbol can't be partially displayed
+                    for _ in 0..width - horizontal_offset {
+                        current_line_width += 1;
+                        self.current_line.push(StyledGrapheme::new(" ", style));
+                    }
+                    horizontal_offset = 0;
+                    continue;
+                }
                 current_line_width += symbol.width() as u16;
                 self.current_line.push(StyledGrapheme { symbol, style });
             }
@@ -318,39 +450,237 @@ where
                 line: &self.current_line,
                 width: current_line_width,
                 alignment: current_alignment,
+                line_index: self.next_line_index - 1,
             })
         }
     }
 }
 
-/// This function will return a str slice which start at specified offset.
-/// As src is a unicode str, start offset has to be calculated with each character.
-fn trim_offset(src: &str, mut offset: usize) -> &str {
-    let mut start = 0;
-    for c in UnicodeSegmentation::graphemes(src, true) {
-        let w = c.width();
-        if w <= offset {
-            offset -= w;
-            start += c.len();
+/// Wraps a line on word boundaries so that each resulting line fits in `width` columns.
+///
+/// This is the word wrapping used by [`Paragraph`](crate::widgets::Paragraph) with
+/// [`Wrap`](crate::widgets::Wrap), made available to custom widgets. Words wider than `width` are
+/// broken across lines. When `trim` is `true`, the leading whitespace of the wrapped lines is
+/// removed.
+///
+/// The style of the spans is kept, and consecutive characters with the same style are merged into
+/// a single span. The wrapped lines have the style and alignment of `line`. No lines are returned
+/// when `width` is 0.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::wrap_line};
+///
+/// let line = Line::from(vec!["Hello ".red(), "wrapped world".blue()]);
+/// let lines = wrap_line(&line, 13, true);
+/// assert_eq!(
+///     lines,
+///     [
+///         Line::from(vec!["Hello ".red(), "wrapped".blue()]),
+///         Line::from("world".blue()),
+///     ]
+/// );
+/// ```
+pub fn wrap_line(line: &Line, width: u16, trim: bool) -> Vec<Line<'static>> {
+    // the style of the line is kept on the wrapped lines, so only the span styles are needed here
+    let graphemes = line
+        .spans
+        .iter()
+        .flat_map(|span| span.styled_graphemes(Style::default()));
+    let alignment = line.alignment.unwrap_or(Alignment::Left);
+    let mut wrapper = WordWrapper::new(iter::once((graphemes, alignment)), width, trim);
+    let mut lines = vec![];
+    while let Some(wrapped) = wrapper.next_line() {
+        let mut spans: Vec<Span<'static>> = vec![];
+        for grapheme in wrapped.line {
+            match spans.last_mut() {
+                Some(span) if span.style == grapheme.style => {
+                    span.content.to_mut().push_str(grapheme.symbol);
+                }
+                _ => spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+            }
+        }
+        lines.push(Line {
+            spans,
+            style: line.style,
+            alignment: line.alignment,
+        });
+    }
+    lines
+}
+
+/// Replaces each tab of a line with spaces up to the next tab stop.
+///
+/// Tab stops are placed every `tab_width` columns from the start of the line, and the spaces use
+/// the style of the tab they replace. Tabs are removed when `tab_width` is 0.
+pub fn expand_tabs<'a>(
+    graphemes: impl Iterator<Item = StyledGrapheme<'a>>,
+    tab_width: u16,
+) -> impl Iterator<Item = StyledGrapheme<'a>> {
+    let tab_width = usize::from(tab_width);
+    let mut column = 0;
+    graphemes.flat_map(move |grapheme| {
+        if grapheme.symbol == "\t" {
+            let spaces = if tab_width == 0 {
+                0
+            } else {
+                tab_width - column % tab_width
+            };
+            column += spaces;
+            iter::repeat(StyledGrapheme::new(" ", grapheme.style)).take(spaces)
         } else {
+            column += grapheme.symbol.width();
+            iter::repeat(grapheme).take(1)
+        }
+    })
+}
+
+/// Truncates a line to `width` columns, replacing its end with an ellipsis (`…`).
+///
+/// The ellipsis takes the style of the first grapheme it replaces. The line is expected to be wider
+/// than `width`.
+pub fn truncate_with_ellipsis(line: &Line, width: u16) -> Line<'static> {
+    let mut spans = vec![];
+    let mut truncated_width = 0;
+    let mut ellipsis_style = Style::default();
+    for grapheme in line.styled_graphemes(Style::default()) {
+        let grapheme_width = grapheme.symbol.width() as u16;
+        ellipsis_style = grapheme.style;
+        // keep a column for the ellipsis
+        if truncated_width + grapheme_width >= width {
             break;
         }
+        spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style));
+        truncated_width += grapheme_width;
+    }
+    if width > 0 {
+        spans.push(Span::styled("…", ellipsis_style));
+    }
+    Line::from(spans)
+}
+
+/// A soft hyphen at which a word can be broken.
+struct Hyphen {
+    /// Index in the word of the grapheme following the soft hyphen
+    index: usize,
+    /// Width of the word before the soft hyphen
+    width: u16,
+    /// Style of the soft hyphen
+    style: Style,
+}
+
+/// Removes and returns the last soft hyphen at which the word can be broken so that the start of
+/// the word and the visible hyphen fit in `available_width`.
+///
+/// The soft hyphens after it are made relative to the rest of the word.
+fn take_hyphen(hyphens: &mut Vec<Hyphen>, available_width: u16) -> Option<Hyphen> {
+    let position = hyphens
+        .iter()
+        .rposition(|hyphen| hyphen.index > 0 && hyphen.width < available_width)?;
+    let hyphen = hyphens.remove(position);
+    hyphens.drain(..position);
+    for rest in hyphens.iter_mut() {
+        rest.index -= hyphen.index;
+        rest.width -= hyphen.width;
+    }
+    Some(hyphen)
+}
+
+/// Returns true if the symbol is a whitespace that words can be wrapped on.
+fn is_breaking_whitespace(symbol: &str) -> bool {
+    symbol == ZWSP || (symbol.chars().all(char::is_whitespace) && symbol != NBSP)
+}
+
+/// Stretches the gaps between the words of a wrapped line so that it is `max_line_width` wide.
+///
+/// Trailing whitespace is removed and leading whitespace (indentation) is kept as is. The extra
+/// space is spread evenly between the gaps, with the leftmost gaps receiving one more space when
+/// it can't be spread evenly. Inserted spaces use the style of the whitespace they extend. Lines
+/// with a single word are returned without the trailing whitespace.
+fn justify(mut line: Vec<StyledGrapheme<'_>>, max_line_width: u16) -> Vec<StyledGrapheme<'_>> {
+    while line
+        .last()
+        .is_some_and(|grapheme| is_breaking_whitespace(grapheme.symbol))
+    {
+        line.pop();
+    }
+    let width: usize = line.iter().map(|grapheme| grapheme.symbol.width()).sum();
+    let extra_width = usize::from(max_line_width).saturating_sub(width);
+
+    // Index of the last whitespace of each gap between two words
+    let indentation = line
+        .iter()
+        .take_while(|grapheme| is_breaking_whitespace(grapheme.symbol))
+        .count();
+    let gaps: Vec<usize> = (indentation + 1..line.len())
+        .filter(|&i| {
+            is_breaking_whitespace(line[i - 1].symbol) && !is_breaking_whitespace(line[i].symbol)
+        })
+        .map(|i| i - 1)
+        .collect();
+    if gaps.is_empty() || extra_width == 0 {
+        return line;
+    }
+
+    let (spaces_per_gap, remainder) = (extra_width / gaps.len(), extra_width % gaps.len());
+    let mut justified = Vec::with_capacity(line.len() + extra_width);
+    let mut gap_index = 0;
+    for (i, grapheme) in line.into_iter().enumerate() {
+        let style = grapheme.style;
+        justified.push(grapheme);
+        if gaps.get(gap_index) == Some(&i) {
+            let spaces = spaces_per_gap + usize::from(gap_index < remainder);
+            justified.extend(iter::repeat(StyledGrapheme::new(" ", style)).take(spaces));
+            gap_index += 1;
+        }
     }
-    #[allow(clippy::string_slice)] // Is safe as it comes from UnicodeSegmentation
-    &src[start..]
+    justified
 }
 
 #[cfg(test)]
 mod test {
+    use unicode_segmentation::UnicodeSegmentation;
+
     use super::*;
     use crate::{
-        style::Style,
-        text::{Line, Text},
+        style::{Style, Stylize},
+        text::{Line, Span, Text},
     };
 
+    #[test]
+    fn wrap_line_keeps_styles_and_alignment() {
+        let line = Line::from(vec!["a ".red(), "b".red(), " long word".blue()])
+            .style(Style::new().italic())
+            .centered();
+        let lines = wrap_line(&line, 6, true);
+        assert_eq!(
+            lines,
+            [
+                Line::from("a b".red())
+                    .style(Style::new().italic())
+                    .centered(),
+                Line::from("long".blue())
+                    .style(Style::new().italic())
+                    .centered(),
+                Line::from("word".blue())
+                    .style(Style::new().italic())
+                    .centered(),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_line_zero_width() {
+        assert_eq!(wrap_line(&Line::from("foo"), 0, true), []);
+    }
+
     #[derive(Clone, Copy)]
     enum Composer {
         WordWrapper { trim: bool },
+        JustifiedWordWrapper { trim: bool },
+        TruncatingWordWrapper { trim: bool },
+        HyphenatingWordWrapper { trim: bool },
         LineTruncator,
     }
 
@@ -372,6 +702,21 @@ mod test {
             Composer::WordWrapper { trim } => {
                 Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
             }
+            Composer::JustifiedWordWrapper { trim } => {
+                let mut wrapper = WordWrapper::new(styled_lines, text_area_width, trim);
+                wrapper.set_justify(true);
+                Box::new(wrapper)
+            }
+            Composer::TruncatingWordWrapper { trim } => {
+                let mut wrapper = WordWrapper::new(styled_lines, text_area_width, trim);
+                wrapper.set_break_long_words(false);
+                Box::new(wrapper)
+            }
+            Composer::HyphenatingWordWrapper { trim } => {
+                let mut wrapper = WordWrapper::new(styled_lines, text_area_width, trim);
+                wrapper.set_hyphenate(true);
+                Box::new(wrapper)
+            }
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
         };
         let mut lines = vec![];
@@ -381,6 +726,7 @@ mod test {
             line: styled,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = styled
@@ -680,6 +1026,31 @@ mod test {
         assert_eq!(line_truncator, vec!["foo\u{200B}"]);
     }
 
+    #[test]
+    fn line_truncator_horizontal_offset_splits_wide_characters() {
+        let line = Line::from("你好世界");
+        let styled = iter::once((line.styled_graphemes(Style::default()), Alignment::Left));
+        let mut composer = LineTruncator::new(styled, 10);
+        composer.set_horizontal_offset(1);
+        let WrappedLine { line, width, .. } = composer.next_line().unwrap();
+        let symbols: String = line.iter().map(|grapheme| grapheme.symbol).collect();
+        assert_eq!(symbols, " 好世界");
+        assert_eq!(width, 7);
+    }
+
+    #[test]
+    fn expand_tabs_to_next_tab_stop() {
+        let line = Line::from("\ta\tbc \t\td\t");
+        let expanded: String = expand_tabs(line.styled_graphemes(Style::default()), 4)
+            .map(|grapheme| grapheme.symbol)
+            .collect();
+        assert_eq!(expanded, "    a   bc      d   ");
+        let expanded: String = expand_tabs(line.styled_graphemes(Style::default()), 0)
+            .map(|grapheme| grapheme.symbol)
+            .collect();
+        assert_eq!(expanded, "abc d");
+    }
+
     #[test]
     fn line_composer_preserves_line_alignment() {
         let width = 20;
@@ -716,4 +1087,115 @@ mod test {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, vec!["foo", "bar"]);
     }
+
+    #[test]
+    fn line_composer_word_wrapper_justify() {
+        let width = 20;
+        let text = "The quick brown fox jumps over the lazy dog and keeps running away";
+        let (word_wrapper, widths, _) =
+            run_composer(Composer::JustifiedWordWrapper { trim: true }, text, width);
+        assert_eq!(
+            word_wrapper,
+            vec![
+                "The  quick brown fox",
+                "jumps  over the lazy",
+                "dog     and    keeps",
+                "running away",
+            ]
+        );
+        assert_eq!(widths, vec![20, 20, 20, 12]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_justify_single_word() {
+        let width = 10;
+        let text = "abcdefgh ijklmnopq rs";
+        let (word_wrapper, _, alignments) =
+            run_composer(Composer::JustifiedWordWrapper { trim: true }, text, width);
+        assert_eq!(word_wrapper, vec!["abcdefgh", "ijklmnopq", "rs"]);
+        assert_eq!(alignments, vec![Alignment::Left; 3]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_justify_keeps_whitespace_style() {
+        let line = Line::from(vec![
+            Span::raw("ab"),
+            Span::styled(" ", Style::new().red()),
+            Span::raw("cd ef"),
+        ]);
+        let styled = iter::once((line.styled_graphemes(Style::default()), Alignment::Left));
+        let mut composer = WordWrapper::new(styled, 7, true);
+        composer.set_justify(true);
+        let wrapped = composer.next_line().unwrap();
+        assert_eq!(
+            wrapped.line,
+            [
+                StyledGrapheme::new("a", Style::new()),
+                StyledGrapheme::new("b", Style::new()),
+                StyledGrapheme::new(" ", Style::new().red()),
+                StyledGrapheme::new(" ", Style::new().red()),
+                StyledGrapheme::new(" ", Style::new().red()),
+                StyledGrapheme::new("c", Style::new()),
+                StyledGrapheme::new("d", Style::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_long_words() {
+        let chunks: Vec<String> = (0..6).map(|i| i.to_string().repeat(20)).collect();
+        let text = format!("hash: {} ok", chunks.concat());
+        let (lines, _, _) = run_composer(Composer::WordWrapper { trim: true }, &*text, 20);
+        let mut expected = vec!["hash:"];
+        expected.extend(chunks.iter().map(String::as_str));
+        expected.push("ok");
+        assert_eq!(lines, expected);
+
+        let (lines, _, _) =
+            run_composer(Composer::TruncatingWordWrapper { trim: true }, &*text, 20);
+        assert_eq!(lines, ["hash:", &chunks[0], "ok"]);
+
+        let (lines, _, _) =
+            run_composer(Composer::TruncatingWordWrapper { trim: false }, &*text, 20);
+        assert_eq!(lines, ["hash:", &chunks[0], "ok"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_hyphenates_at_soft_hyphens() {
+        let token = ["abcdefghi"; 13].join("\u{ad}");
+        let (lines, _, _) =
+            run_composer(Composer::HyphenatingWordWrapper { trim: true }, &*token, 20);
+        assert_eq!(
+            lines,
+            [
+                "abcdefghiabcdefghi-",
+                "abcdefghiabcdefghi-",
+                "abcdefghiabcdefghi-",
+                "abcdefghiabcdefghi-",
+                "abcdefghiabcdefghi-",
+                "abcdefghiabcdefghi-",
+                "abcdefghi",
+            ]
+        );
+
+        let text = "a very in\u{ad}com\u{ad}pre\u{ad}hen\u{ad}si\u{ad}ble word";
+        let (lines, widths, _) =
+            run_composer(Composer::HyphenatingWordWrapper { trim: true }, text, 12);
+        assert_eq!(lines, ["a very in-", "comprehensi-", "ble word"]);
+        assert_eq!(widths, [10, 12, 8]);
+
+        // soft hyphens are kept as is when not hyphenating
+        let (lines, _, _) = run_composer(Composer::WordWrapper { trim: true }, "in\u{ad}to", 12);
+        assert_eq!(lines, ["in\u{ad}to"]);
+    }
+
+    #[test]
+    fn truncate_with_ellipsis() {
+        let line = Line::from(vec!["ab".red(), "cd".blue()]);
+        assert_eq!(
+            super::truncate_with_ellipsis(&line, 3),
+            Line::from(vec!["a".red(), "b".red(), "…".blue()])
+        );
+        assert_eq!(super::truncate_with_ellipsis(&line, 0), Line::default());
+    }
 }
@@ -0,0 +1,349 @@
+use crate::{
+    prelude::*,
+    style::Styled,
+    symbols::line,
+    widgets::{Block, WidgetRef},
+};
+
+/// The width of a ring, including its border
+const RING_WIDTH: u16 = 7;
+/// The height of a ring, including its border
+const RING_HEIGHT: u16 = 3;
+/// The height of a cell of the grid: the ring and its label below it
+const CELL_HEIGHT: u16 = RING_HEIGHT + 1;
+
+/// A widget to display the progress of several tasks as small rings arranged in a grid
+///
+/// Each entry is made of a label and a ratio between `0.0` and `1.0`. The progress of an entry is
+/// drawn as a ring that fills clockwise from its top center, with the percentage in the middle of
+/// the ring and the label below it. The filled part of the ring is drawn with thick lines in the
+/// [ring style](RingGrid::ring_style) and the rest of the ring with rounded lines.
+///
+/// The entries are laid out from left to right and from top to bottom in the given number of
+/// [columns](RingGrid::columns). The columns share the width of the area equally and each ring is
+/// centered in its column. Rows that don't fit in the area are not rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let rings = RingGrid::new([("build", 0.8), ("test", 0.35), ("lint", 1.0), ("docs", 0.0)])
+///     .columns(2)
+///     .ring_style(Style::new().green());
+/// frame.render_widget(rings, area);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingGrid<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The label and ratio of each ring
+    entries: Vec<(Line<'a>, f64)>,
+    /// The number of rings on each row
+    columns: u16,
+    /// Style of the whole widget
+    style: Style,
+    /// Style of the filled part of the rings
+    ring_style: Style,
+    /// Style of the labels
+    label_style: Style,
+}
+
+impl Default for RingGrid<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            entries: Vec::new(),
+            columns: 1,
+            style: Style::default(),
+            ring_style: Style::default(),
+            label_style: Style::default(),
+        }
+    }
+}
+
+impl<'a> RingGrid<'a> {
+    /// Creates a new ring grid from `(label, ratio)` entries
+    ///
+    /// The label can be anything convertible to a [`Line`]. Ratios are clamped between `0.0` and
+    /// `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rings = RingGrid::new([("download", 0.5), ("upload", 0.25)]);
+    /// ```
+    pub fn new<I, L>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (L, f64)>,
+        L: Into<Line<'a>>,
+    {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|(label, ratio)| (label.into(), ratio.clamp(0.0, 1.0)))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `RingGrid` with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the number of rings on each row
+    ///
+    /// The columns share the width of the area equally. A value of `0` is treated as `1`. Defaults
+    /// to `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the style of the whole widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the filled part of the rings
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ring_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.ring_style = style.into();
+        self
+    }
+
+    /// Sets the style of the labels displayed below the rings
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Renders a ring and its label in the given cell of the grid
+    fn render_entry(&self, label: &Line, ratio: f64, cell: Rect, buf: &mut Buffer) {
+        let ring_area = Rect {
+            x: cell.x + cell.width.saturating_sub(RING_WIDTH) / 2,
+            width: RING_WIDTH.min(cell.width),
+            height: RING_HEIGHT.min(cell.height),
+            ..cell
+        };
+
+        let perimeter = ring_perimeter();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let filled = (ratio * perimeter.len() as f64).round() as usize;
+        for (i, (dx, dy)) in perimeter.into_iter().enumerate() {
+            let position = Position::new(ring_area.x + dx, ring_area.y + dy);
+            if !ring_area.contains(position) {
+                continue;
+            }
+            let cell = buf.get_mut(position.x, position.y);
+            if i < filled {
+                cell.set_symbol(ring_symbol(line::THICK, dx, dy))
+                    .set_style(self.ring_style);
+            } else {
+                cell.set_symbol(ring_symbol(line::ROUNDED, dx, dy));
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let percent = (ratio * 100.0).round() as u16;
+        let percent_area = Rect::new(ring_area.x + 1, ring_area.y + 1, RING_WIDTH - 2, 1);
+        Line::from(format!("{percent}%"))
+            .centered()
+            .render(percent_area.intersection(ring_area), buf);
+
+        let label_area = Rect {
+            y: cell.y + RING_HEIGHT,
+            height: 1,
+            ..cell
+        };
+        let label_alignment = label.alignment.unwrap_or(Alignment::Center);
+        label
+            .clone()
+            .alignment(label_alignment)
+            .patch_style(self.label_style)
+            .render(label_area.intersection(cell), buf);
+    }
+}
+
+/// The offsets of the cells on the border of a ring, clockwise from its top center
+fn ring_perimeter() -> Vec<(u16, u16)> {
+    let (right, bottom) = (RING_WIDTH - 1, RING_HEIGHT - 1);
+    let top_center = RING_WIDTH / 2;
+    (top_center..RING_WIDTH)
+        .map(|x| (x, 0))
+        .chain((1..bottom).map(|y| (right, y)))
+        .chain((0..RING_WIDTH).rev().map(|x| (x, bottom)))
+        .chain((1..bottom).rev().map(|y| (0, y)))
+        .chain((0..top_center).map(|x| (x, 0)))
+        .collect()
+}
+
+/// The symbol of the border of a ring at the given offset
+const fn ring_symbol(set: line::Set, dx: u16, dy: u16) -> &'static str {
+    let (right, bottom) = (RING_WIDTH - 1, RING_HEIGHT - 1);
+    match (dx, dy) {
+        (0, 0) => set.top_left,
+        (x, 0) if x == right => set.top_right,
+        (0, y) if y == bottom => set.bottom_left,
+        (x, y) if x == right && y == bottom => set.bottom_right,
+        (_, 0) => set.horizontal,
+        (_, y) if y == bottom => set.horizontal,
+        _ => set.vertical,
+    }
+}
+
+impl Widget for RingGrid<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for RingGrid<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return;
+        }
+
+        let columns = self.columns.max(1);
+        let column_areas = Layout::horizontal(vec![
+            Constraint::Ratio(1, u32::from(columns));
+            columns as usize
+        ])
+        .split(area);
+        let rows = self.entries.chunks(columns as usize);
+        for (y, row) in (area.y..area.bottom())
+            .step_by(CELL_HEIGHT as usize)
+            .zip(rows)
+        {
+            for (column_area, (label, ratio)) in column_areas.iter().zip(row) {
+                let cell = Rect {
+                    y,
+                    height: CELL_HEIGHT,
+                    ..*column_area
+                }
+                .intersection(area);
+                self.render_entry(label, *ratio, cell, buf);
+            }
+        }
+    }
+}
+
+impl Styled for RingGrid<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn perimeter_is_clockwise_from_top_center() {
+        let perimeter = ring_perimeter();
+        assert_eq!(perimeter.len(), 16);
+        assert_eq!(perimeter[0], (3, 0));
+        assert_eq!(perimeter[3], (6, 0));
+        assert_eq!(perimeter[4], (6, 1));
+        assert_eq!(perimeter[15], (2, 0));
+    }
+
+    #[test]
+    fn render_two_by_two_grid() {
+        let rings = RingGrid::new([("a", 0.0), ("b", 0.25), ("c", 0.5), ("d", 1.0)]).columns(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 8));
+        rings.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                " ╭─────╮   ╭──━━━┓  ",
+                " │ 0%  │   │ 25% │  ",
+                " ╰─────╯   ╰─────╯  ",
+                "    a         b     ",
+                " ╭──━━━┓   ┏━━━━━┓  ",
+                " │ 50% ┃   ┃100% ┃  ",
+                " ╰───━━┛   ┗━━━━━┛  ",
+                "    c         d     ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_ring_style_on_filled_part() {
+        let rings = RingGrid::new([("", 0.25)]).ring_style(Style::new().green());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+        rings.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["╭──━━━┓", "│ 25% │", "╰─────╯", "       "]);
+        expected.set_style(Rect::new(3, 0, 4, 1), Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::below_zero(-1.0, ["╭─────╮", "│ 0%  │", "╰─────╯"])]
+    #[case::above_one(2.0, ["┏━━━━━┓", "┃100% ┃", "┗━━━━━┛"])]
+    fn new_clamps_ratios(#[case] ratio: f64, #[case] expected: [&str; 3]) {
+        let rings = RingGrid::new([("", ratio)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        rings.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_skips_rows_outside_area() {
+        let rings = RingGrid::new([("a", 0.0), ("b", 0.0)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+        rings.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["╭─────╮", "│ 0%  │", "╰─────╯", "   a   "])
+        );
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let rings = RingGrid::new([("a", 0.5)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+        rings.render(Rect::new(0, 0, 0, 0), &mut buf);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 7, 4)));
+    }
+}
@@ -85,6 +85,7 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    min_thumb_length: u16,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -195,6 +196,7 @@ impl<'a> Scrollbar<'a> {
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
             end_style: Style::new(),
+            min_thumb_length: 1,
         }
     }
 
@@ -367,6 +369,20 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the minimum length of the thumb.
+    ///
+    /// The length of the thumb is proportional to the viewport length compared to the content
+    /// length, which can make it very small when scrolling over large content. The thumb is never
+    /// shorter than the given length, nor longer than the track. A value of 0 is treated as 1 so
+    /// that the thumb is always visible. The default is 1.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_thumb_length(mut self, min_thumb_length: u16) -> Self {
+        self.min_thumb_length = min_thumb_length;
+        self
+    }
+
     /// Sets the style used for the various parts of the scrollbar from a [`Style`].
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -391,6 +407,84 @@ impl<'a> Scrollbar<'a> {
         self.end_style = style;
         self
     }
+
+    /// Returns the area of the thumb when the scrollbar is rendered in `area` with `state`.
+    ///
+    /// This is the exact area where [`render`](StatefulWidget::render) draws the thumb, which can
+    /// be used to check whether a mouse event starts dragging the thumb. The area is empty when
+    /// the scrollbar is not rendered, i.e. when the content or the track is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+    /// let state = ScrollbarState::new(100).position(50);
+    /// let thumb = scrollbar.thumb_area(Rect::new(0, 0, 10, 10), &state);
+    /// assert_eq!(thumb, Rect::new(9, 5, 1, 1));
+    /// ```
+    #[must_use = "method returns the area of the thumb"]
+    pub fn thumb_area(&self, area: Rect, state: &ScrollbarState) -> Rect {
+        if state.content_length == 0 || self.track_length_excluding_arrow_heads(area) == 0 {
+            return Rect::ZERO;
+        }
+        let (thumb_start, thumb_length, _) = self.part_lengths(area, state);
+        let area = self.scollbar_area(area);
+        // the begin symbol takes a single cell of the scrollbar
+        let thumb_start = u16::from(self.begin_symbol.is_some()) + thumb_start as u16;
+        let thumb_length = thumb_length as u16;
+        if self.orientation.is_vertical() {
+            Rect::new(area.x, area.y + thumb_start, area.width, thumb_length)
+        } else {
+            Rect::new(area.x + thumb_start, area.y, thumb_length, area.height)
+        }
+    }
+
+    /// Returns the scroll position that moves the start of the thumb to the given screen offset.
+    ///
+    /// `offset` is the row (for vertical scrollbars) or the column (for horizontal scrollbars) of
+    /// the screen, e.g. where the mouse was clicked or dragged along the track of the scrollbar
+    /// rendered in `area`. Offsets before or after the track map to the first or last position of
+    /// the content. Setting the returned value as the [position](ScrollbarState::position) of
+    /// `state` renders the thumb starting at `offset`, as far as the size of the thumb and the
+    /// length of the content allow it.
+    ///
+    /// This is a method of the scrollbar rather than of the state, as the placement of the track
+    /// depends on the symbols of the scrollbar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let mut state = ScrollbarState::new(100);
+    /// // the mouse is dragged to the 6th row
+    /// let position = scrollbar.position_from_offset(area, &state, 5);
+    /// state = state.position(position);
+    /// assert_eq!(scrollbar.thumb_area(area, &state).y, 5);
+    /// ```
+    #[must_use = "method returns the scroll position"]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn position_from_offset(&self, area: Rect, state: &ScrollbarState, offset: u16) -> usize {
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        if state.content_length == 0 || track_length == 0 {
+            return 0;
+        }
+        let area = self.scollbar_area(area);
+        let track_start = if self.orientation.is_vertical() {
+            area.top()
+        } else {
+            area.left()
+        } + u16::from(self.begin_symbol.is_some());
+        let thumb_start = offset.saturating_sub(track_start).min(track_length - 1);
+
+        // invert the computation of the start of the thumb in `part_lengths`
+        let max_position = state.content_length.saturating_sub(1) as f64;
+        let max_viewport_position = max_position + self.viewport_length(state, area) as f64;
+        let position = f64::from(thumb_start) * max_viewport_position / f64::from(track_length);
+        position.round().clamp(0.0, max_position) as usize
+    }
 }
 
 impl ScrollbarState {
@@ -473,6 +567,62 @@ impl ScrollbarState {
             }
         }
     }
+
+    /// Decrements the scroll position by a page of `viewport` items, ensuring it doesn't go below
+    /// zero.
+    ///
+    /// `viewport` is usually the number of items visible at once, e.g. the height of the area of
+    /// a vertically scrolled widget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::widgets::ScrollbarState;
+    /// let mut state = ScrollbarState::new(100).position(15);
+    /// state.scroll_page_up(10);
+    /// assert_eq!(state, ScrollbarState::new(100).position(5));
+    /// state.scroll_page_up(10);
+    /// assert_eq!(state, ScrollbarState::new(100).position(0));
+    /// ```
+    pub fn scroll_page_up(&mut self, viewport: usize) {
+        self.position = self.position.saturating_sub(viewport);
+    }
+
+    /// Increments the scroll position by a page of `viewport` items, ensuring it doesn't exceed
+    /// the length of the content.
+    ///
+    /// Like [`ScrollbarState::next`], the position stops at the last item of the content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::widgets::ScrollbarState;
+    /// let mut state = ScrollbarState::new(20).position(5);
+    /// state.scroll_page_down(10);
+    /// assert_eq!(state, ScrollbarState::new(20).position(15));
+    /// state.scroll_page_down(10);
+    /// assert_eq!(state, ScrollbarState::new(20).position(19));
+    /// ```
+    pub fn scroll_page_down(&mut self, viewport: usize) {
+        self.position = self
+            .position
+            .saturating_add(viewport)
+            .min(self.content_length.saturating_sub(1));
+    }
+
+    /// Sets the scroll position to the start of the scrollable content.
+    ///
+    /// This is the same as [`ScrollbarState::first`].
+    pub fn scroll_to_start(&mut self) {
+        self.first();
+    }
+
+    /// Sets the scroll position to the end of the scrollable content.
+    ///
+    /// This is the same as [`ScrollbarState::last`].
+    pub fn scroll_to_end(&mut self) {
+        self.last();
+    }
 }
 
 impl<'a> StatefulWidget for Scrollbar<'a> {
@@ -554,7 +704,10 @@ impl Scrollbar<'_> {
         let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
         let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
 
-        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+        // Grow the thumb to its minimum length, moving it back when it would overflow the track
+        let min_thumb_length = usize::from(self.min_thumb_length).clamp(1, track_length as usize);
+        let thumb_length = thumb_end.saturating_sub(thumb_start).max(min_thumb_length);
+        let thumb_start = thumb_start.min(track_length as usize - thumb_length);
         let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
 
         (thumb_start, thumb_length, track_end_length)
@@ -660,6 +813,107 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::one_page(25, 10, 15)]
+    #[case::clamped_at_start(5, 10, 0)]
+    #[case::at_start(0, 10, 0)]
+    #[case::empty_page(5, 0, 5)]
+    fn scroll_page_up(#[case] position: usize, #[case] viewport: usize, #[case] expected: usize) {
+        let mut state = ScrollbarState::new(30).position(position);
+        state.scroll_page_up(viewport);
+        assert_eq!(state.position, expected);
+    }
+
+    #[rstest]
+    #[case::one_page(5, 10, 15)]
+    #[case::clamped_at_end(25, 10, 29)]
+    #[case::at_end(29, 10, 29)]
+    #[case::huge_page(5, usize::MAX, 29)]
+    fn scroll_page_down(#[case] position: usize, #[case] viewport: usize, #[case] expected: usize) {
+        let mut state = ScrollbarState::new(30).position(position);
+        state.scroll_page_down(viewport);
+        assert_eq!(state.position, expected);
+    }
+
+    #[test]
+    fn scroll_page_down_empty_content() {
+        let mut state = ScrollbarState::new(0);
+        state.scroll_page_down(10);
+        assert_eq!(state.position, 0);
+    }
+
+    #[test]
+    fn scroll_to_start_and_end() {
+        let mut state = ScrollbarState::new(30).position(12);
+        state.scroll_to_end();
+        assert_eq!(state.position, 29);
+        state.scroll_to_start();
+        assert_eq!(state.position, 0);
+    }
+
+    #[rstest]
+    #[case::start(ScrollbarOrientation::VerticalRight, 0, Rect::new(4, 1, 1, 3))]
+    #[case::middle(ScrollbarOrientation::VerticalRight, 10, Rect::new(4, 4, 1, 3))]
+    #[case::end(ScrollbarOrientation::VerticalRight, 19, Rect::new(4, 6, 1, 3))]
+    #[case::horizontal(ScrollbarOrientation::HorizontalBottom, 10, Rect::new(2, 9, 1, 1))]
+    fn thumb_area(
+        #[case] orientation: ScrollbarOrientation,
+        #[case] position: usize,
+        #[case] expected: Rect,
+    ) {
+        let area = Rect::new(0, 0, 5, 10);
+        let scrollbar = Scrollbar::new(orientation);
+        let mut state = ScrollbarState::new(20).position(position);
+        assert_eq!(scrollbar.thumb_area(area, &state), expected);
+
+        // the thumb area matches the rendered thumb
+        let mut buffer = Buffer::empty(area);
+        scrollbar.clone().render(area, &mut buffer, &mut state);
+        for position in expected.positions() {
+            assert_eq!(buffer.get(position.x, position.y).symbol(), "█");
+        }
+    }
+
+    #[test]
+    fn thumb_area_of_empty_content() {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+        let state = ScrollbarState::new(0);
+        assert_eq!(
+            scrollbar.thumb_area(Rect::new(0, 0, 5, 10), &state),
+            Rect::ZERO
+        );
+    }
+
+    #[rstest]
+    #[case::begin_arrow(0, 0)]
+    #[case::track_start(1, 0)]
+    #[case::second_cell(2, 3)]
+    #[case::middle(5, 12)]
+    #[case::track_end(8, 19)]
+    #[case::end_arrow(9, 19)]
+    #[case::below_area(42, 19)]
+    fn position_from_offset(#[case] offset: u16, #[case] expected: usize) {
+        let area = Rect::new(0, 0, 5, 10);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+        let state = ScrollbarState::new(20).viewport_content_length(4);
+        assert_eq!(
+            scrollbar.position_from_offset(area, &state, offset),
+            expected
+        );
+    }
+
+    #[test]
+    fn position_from_offset_moves_thumb_to_offset() {
+        let area = Rect::new(3, 2, 1, 12);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalLeft).begin_symbol(None);
+        let state = ScrollbarState::new(100);
+        for offset in 2..13 {
+            let position = scrollbar.position_from_offset(area, &state, offset);
+            let thumb = scrollbar.thumb_area(area, &state.position(position));
+            assert_eq!(thumb.y, offset, "offset {offset} -> position {position}");
+        }
+    }
+
     #[fixture]
     fn scrollbar_no_arrows() -> Scrollbar<'static> {
         Scrollbar::new(ScrollbarOrientation::HorizontalTop)
@@ -1043,4 +1297,60 @@ mod tests {
         scrollbar_no_arrows.render(buffer.area, &mut buffer, &mut state);
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
+
+    #[rstest]
+    #[case::position_0("###-------", 0)]
+    #[case::position_middle("-----###--", 500_000)]
+    #[case::position_near_end("-------###", 999_000)]
+    #[case::position_last("-------###", 999_999)]
+    #[case::position_out_of_bounds("-------###", 2_000_000)]
+    fn min_thumb_length_on_huge_content(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, expected.width() as u16, 1));
+        let mut state = ScrollbarState::new(1_000_000)
+            .position(position)
+            .viewport_content_length(1);
+        scrollbar_no_arrows
+            .min_thumb_length(3)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn min_thumb_length_applies_to_vertical_scrollbar() {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .min_thumb_length(2);
+        let mut state = ScrollbarState::new(10_000).viewport_content_length(1);
+        for position in (0..10_000).step_by(7) {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 6));
+            let mut state = state.position(position);
+            scrollbar
+                .clone()
+                .render(buffer.area, &mut buffer, &mut state);
+            let thumb_cells = buffer.content.iter().filter(|cell| cell.symbol() == "#");
+            assert_eq!(thumb_cells.count(), 2, "position {position}");
+        }
+        state = state.position(9_999);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 6));
+        scrollbar.render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["-", "-", "-", "-", "#", "#"]));
+    }
+
+    #[test]
+    fn min_thumb_length_is_limited_to_track_length() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let mut state = ScrollbarState::new(100).position(50);
+        Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+            .thumb_symbol("#")
+            .min_thumb_length(10)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["◄###►"]));
+    }
 }
@@ -15,8 +15,15 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 ///
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
+/// - [`Sparkline::data_f64`] defines a dataset of floating point values instead
+/// - [`Sparkline::normalize`] sets the range of floating point values mapped to the bars
+/// - [`Sparkline::min`] sets the minimum value of bars
 /// - [`Sparkline::max`] sets the maximum value of bars
+/// - [`Sparkline::baseline`] sets the value of empty bars
+/// - [`Sparkline::threshold`] sets the style of bars above a value
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::show_current_value`] displays the last value of the dataset next to the bars
+/// - [`Sparkline::value_style`] sets the style of the displayed value
 ///
 /// # Examples
 ///
@@ -30,7 +37,7 @@ use crate::{prelude::*, style::Styled, widgets::Block};
 ///     .direction(RenderDirection::RightToLeft)
 ///     .style(Style::default().red().on_white());
 /// ```
-#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Sparkline<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -38,13 +45,28 @@ pub struct Sparkline<'a> {
     style: Style,
     /// A slice of the data to display
     data: &'a [u64],
+    /// A slice of floating point data to display instead of `data`
+    float_data: Option<&'a [f64]>,
+    /// The range of floating point values mapped to the bars (if nothing is specified, the widget
+    /// uses 0.0 up to the max of the dataset)
+    normalize: Option<(f64, f64)>,
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
+    /// The value at which bars are empty
+    baseline: u64,
+    /// The value above which bars are styled differently, and their style
+    threshold: Option<(u64, Style)>,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set,
     // The direction to render the sparkine, either from left to right, or from right to left
     direction: RenderDirection,
+    /// Whether to display the last value of the dataset next to the bars
+    show_current_value: bool,
+    /// The style of the displayed value
+    value_style: Style,
+    /// Whether values above the baseline are always rendered with at least the lowest bar
+    min_height_for_nonzero: bool,
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -94,19 +116,118 @@ impl<'a> Sparkline<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn data(mut self, data: &'a [u64]) -> Self {
         self.data = data;
+        self.float_data = None;
         self
     }
 
+    /// Sets a dataset of floating point values for the sparkline.
+    ///
+    /// This replaces the dataset set with [`Sparkline::data`]. The values are linearly mapped to
+    /// the height of the bars, from the minimum to the maximum of the [normalization
+    /// range](Sparkline::normalize). `NaN` values are rendered as gaps. [`Sparkline::min`],
+    /// [`Sparkline::max`], [`Sparkline::baseline`] and [`Sparkline::threshold`] only apply to
+    /// integer datasets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let sparkline = Sparkline::default()
+    ///     .data_f64(&[0.25, 0.5, f64::NAN, 0.75])
+    ///     .normalize(0.0, 1.0);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn data_f64(mut self, data: &'a [f64]) -> Self {
+        self.data = &[];
+        self.float_data = Some(data);
+        self
+    }
+
+    /// Sets the range of the values of a [floating point dataset](Sparkline::data_f64).
+    ///
+    /// Values at or below `min` are rendered as empty bars and values at or above `max` as full
+    /// bars. If no range is given, it goes from 0.0 to the maximum of the dataset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let sparkline = Sparkline::default()
+    ///     .data_f64(&[-0.5, 0.0, 0.5])
+    ///     .normalize(-1.0, 1.0);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn normalize(mut self, min: f64, max: f64) -> Self {
+        self.normalize = Some((min, max));
+        self
+    }
+
+    /// Sets the minimum value of bars.
+    ///
+    /// This is an alias of [`Sparkline::baseline`]: values are clamped to the minimum, so bars at
+    /// or below it are empty. Together with [`Sparkline::max`], this pins the scale of the
+    /// sparkline so that bars keep their height when the dataset changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let sparkline = Sparkline::default().data(&[20, 35, 50]).min(10).max(60);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min(self, min: u64) -> Self {
+        self.baseline(min)
+    }
+
     /// Sets the maximum value of bars.
     ///
     /// Every bar will be scaled accordingly. If no max is given, this will be the max in the
-    /// dataset.
+    /// dataset. Values are clamped to the maximum, so bars above it are rendered full.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn max(mut self, max: u64) -> Self {
         self.max = Some(max);
         self
     }
 
+    /// Sets the baseline of the bars.
+    ///
+    /// Bars are empty at the baseline and grow up to the maximum value, so that small variations
+    /// of large values are visible. Values below the baseline are shown as empty bars. The default
+    /// is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let sparkline = Sparkline::default().data(&[95, 98, 97, 100]).baseline(90);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn baseline(mut self, baseline: u64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Sets the style of the bars with a value above the given threshold.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]). It is patched onto the style of the widget
+    /// for the bars whose value is strictly greater than `threshold`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[20, 80, 50, 95])
+    ///     .green()
+    ///     .threshold(75, Color::Red);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn threshold<S: Into<Style>>(mut self, threshold: u64, style: S) -> Self {
+        self.threshold = Some((threshold, style.into()));
+        self
+    }
+
     /// Sets the characters used to display the bars.
     ///
     /// Can be [`symbols::bar::THREE_LEVELS`], [`symbols::bar::NINE_LEVELS`] (default) or a custom
@@ -125,6 +246,59 @@ impl<'a> Sparkline<'a> {
         self.direction = direction;
         self
     }
+
+    /// Displays the most recent (last) value of the dataset next to the bars.
+    ///
+    /// The value is right-aligned at the end of the widget, or at its start with
+    /// [`RenderDirection::RightToLeft`], on the bottom row. The bars are rendered in the remaining
+    /// width, separated from the value by a column. This is useful to show the current value of a
+    /// sparkline embedded in a status bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // renders ` ▄█ 42`
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[0, 21, 42])
+    ///     .show_current_value(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_current_value(mut self, show: bool) -> Self {
+        self.show_current_value = show;
+        self
+    }
+
+    /// Sets the style of the value displayed with [`Sparkline::show_current_value`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.value_style = style.into();
+        self
+    }
+
+    /// Renders at least the lowest bar (`▁`) for any value above the baseline.
+    ///
+    /// By default, values that are tiny compared to the maximum are rounded down to an empty bar,
+    /// which makes them indistinguishable from values at the baseline. With this option, only
+    /// values at the baseline render as empty. The height of the other bars is unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // renders ` ▁█`
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[0, 1, 100])
+    ///     .min_height_for_nonzero(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_height_for_nonzero(mut self, min_height_for_nonzero: bool) -> Self {
+        self.min_height_for_nonzero = min_height_for_nonzero;
+        self
+    }
 }
 
 impl<'a> Styled for Sparkline<'a> {
@@ -149,32 +323,55 @@ impl WidgetRef for Sparkline<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         self.block.render_ref(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_sparkline(inner, buf);
+        let spark_area = self.render_current_value(inner, buf);
+        self.render_sparkline(spark_area, buf);
     }
 }
 
 impl Sparkline<'_> {
+    /// Renders the current value if enabled and returns the area left for the bars
+    fn render_current_value(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        if !self.show_current_value || area.is_empty() {
+            return area;
+        }
+        let value = match self.float_data {
+            Some(data) => data.last().map(ToString::to_string),
+            None => self.data.last().map(ToString::to_string),
+        };
+        let Some(value) = value else {
+            return area;
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let value_width = (value.len() as u16).min(area.width);
+        // the value is separated from the bars by a column
+        let spark_width = area.width.saturating_sub(value_width + 1);
+        let (value_x, spark_x) = match self.direction {
+            RenderDirection::LeftToRight => (area.right() - value_width, area.left()),
+            RenderDirection::RightToLeft => (area.left(), area.right() - spark_width),
+        };
+        buf.set_stringn(
+            value_x,
+            area.bottom() - 1,
+            value,
+            value_width as usize,
+            self.value_style,
+        );
+        Rect {
+            x: spark_x,
+            width: spark_width,
+            ..area
+        }
+    }
+
     fn render_sparkline(&self, spark_area: Rect, buf: &mut Buffer) {
         if spark_area.is_empty() {
             return;
         }
 
-        let max = self
-            .max
-            .unwrap_or_else(|| *self.data.iter().max().unwrap_or(&1));
-        let max_index = min(spark_area.width as usize, self.data.len());
-        let mut data = self
-            .data
-            .iter()
-            .take(max_index)
-            .map(|e| {
-                if max == 0 {
-                    0
-                } else {
-                    e * u64::from(spark_area.height) * 8 / max
-                }
-            })
-            .collect::<Vec<u64>>();
+        let (mut data, styles) = match self.float_data {
+            Some(float_data) => self.float_bars(float_data, spark_area),
+            None => self.bars(spark_area),
+        };
         for j in (0..spark_area.height).rev() {
             for (i, d) in data.iter_mut().enumerate() {
                 let symbol = match *d {
@@ -194,7 +391,7 @@ impl Sparkline<'_> {
                 };
                 buf.get_mut(x, spark_area.top() + j)
                     .set_symbol(symbol)
-                    .set_style(self.style);
+                    .set_style(styles[i]);
 
                 if *d > 8 {
                     *d -= 8;
@@ -204,6 +401,75 @@ impl Sparkline<'_> {
             }
         }
     }
+
+    /// Returns the height in eighths of a cell and the style of the visible bars of the dataset
+    fn bars(&self, spark_area: Rect) -> (Vec<u64>, Vec<Style>) {
+        let max = self
+            .max
+            .unwrap_or_else(|| *self.data.iter().max().unwrap_or(&1));
+        let range = max.saturating_sub(self.baseline);
+        let max_index = min(spark_area.width as usize, self.data.len());
+        let data = self
+            .data
+            .iter()
+            .take(max_index)
+            .map(|e| {
+                if range == 0 {
+                    0
+                } else {
+                    let value = (*e).min(max).saturating_sub(self.baseline);
+                    let height = value * u64::from(spark_area.height) * 8 / range;
+                    self.min_height(height, value > 0)
+                }
+            })
+            .collect::<Vec<u64>>();
+        let styles = self
+            .data
+            .iter()
+            .take(max_index)
+            .map(|&e| match self.threshold {
+                Some((threshold, style)) if e > threshold => self.style.patch(style),
+                _ => self.style,
+            })
+            .collect::<Vec<Style>>();
+        (data, styles)
+    }
+
+    /// Returns the height in eighths of a cell and the style of the visible bars of the floating
+    /// point dataset
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn float_bars(&self, float_data: &[f64], spark_area: Rect) -> (Vec<u64>, Vec<Style>) {
+        let (low, high) = self.normalize.unwrap_or_else(|| {
+            let max = float_data.iter().copied().filter(|v| !v.is_nan());
+            (0.0, max.fold(f64::NEG_INFINITY, f64::max))
+        });
+        let range = high - low;
+        let levels = f64::from(spark_area.height) * 8.0;
+        let data = float_data
+            .iter()
+            .take(spark_area.width as usize)
+            .map(|&value| {
+                if value.is_nan() || range.is_nan() || range <= 0.0 {
+                    0
+                } else {
+                    // truncate like the integer dataset, so that equal data renders equal bars
+                    let height = (((value - low) / range).clamp(0.0, 1.0) * levels).floor();
+                    self.min_height(height as u64, value > low)
+                }
+            })
+            .collect::<Vec<u64>>();
+        let styles = vec![self.style; data.len()];
+        (data, styles)
+    }
+
+    /// Raises the height of a nonzero value to the lowest bar if enabled
+    const fn min_height(&self, height: u64, is_nonzero: bool) -> u64 {
+        if self.min_height_for_nonzero && is_nonzero && height == 0 {
+            1
+        } else {
+            height
+        }
+    }
 }
 
 #[cfg(test)]
@@ -283,6 +549,158 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["xxx█▇▆▅▄▃▂▁ "]));
     }
 
+    #[test]
+    fn it_draws_from_baseline() {
+        let widget = Sparkline::default()
+            .data(&[100, 101, 102, 103, 104, 105, 106, 107, 108, 50])
+            .baseline(100);
+        let buffer = render(widget, 12);
+        assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█ xx"]));
+    }
+
+    #[test]
+    fn it_keeps_bar_heights_with_fixed_scale() {
+        let render_frame = |data| {
+            let widget = Sparkline::default().data(data).min(10).max(18);
+            let area = Rect::new(0, 0, 4, 2);
+            let mut buffer = Buffer::empty(area);
+            widget.render(area, &mut buffer);
+            buffer
+        };
+        let first = render_frame(&[10, 14, 18, 12]);
+        assert_eq!(first, Buffer::with_lines(["  █ ", " ██▄"]));
+        // the same values keep their height when the largest value changes
+        let second = render_frame(&[14, 5, 1000, 12]);
+        assert_eq!(second, Buffer::with_lines(["  █ ", "█ █▄"]));
+    }
+
+    #[test]
+    fn it_draws_float_ramp() {
+        let data = [0.0, 0.125, 0.25, 0.375, 0.5, 0.625, 0.75, 0.875, 1.0];
+        let widget = Sparkline::default().data_f64(&data).normalize(0.0, 1.0);
+        let buffer = render(widget, 12);
+        assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█xxx"]));
+    }
+
+    #[test]
+    fn it_draws_float_ramp_over_two_lines() {
+        let data = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let widget = Sparkline::default().data_f64(&data).normalize(0.0, 1.0);
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["   ▄█", " ▄███"]));
+    }
+
+    #[test]
+    fn it_draws_floats_like_integers() {
+        let widget = Sparkline::default().data(&[1, 2, 3]);
+        let float_widget = Sparkline::default().data_f64(&[1.0, 2.0, 3.0]);
+        let expected = Buffer::with_lines(["▂▅█"]);
+        assert_eq!(render(widget, 3), expected);
+        assert_eq!(render(float_widget, 3), expected);
+    }
+
+    #[test]
+    fn it_normalizes_floats_to_range() {
+        // values are clamped to the range and the range defaults to 0.0 up to the max
+        let widget = Sparkline::default()
+            .data_f64(&[-1.0, 5.0, 10.0, 20.0])
+            .normalize(0.0, 10.0);
+        assert_eq!(render(widget, 5), Buffer::with_lines([" ▄██x"]));
+        let widget = Sparkline::default().data_f64(&[1.0, 2.0, 4.0]);
+        assert_eq!(render(widget, 4), Buffer::with_lines(["▂▄█x"]));
+    }
+
+    #[test]
+    fn it_draws_nan_as_gap() {
+        let widget = Sparkline::default()
+            .data_f64(&[1.0, f64::NAN, 0.5])
+            .normalize(0.0, 1.0);
+        assert_eq!(render(widget, 4), Buffer::with_lines(["█ ▄x"]));
+    }
+
+    #[test]
+    fn it_styles_bars_above_threshold() {
+        let widget = Sparkline::default()
+            .data(&[8, 1, 8, 1, 4])
+            .green()
+            .threshold(4, Color::Red);
+        let mut expected = Buffer::with_lines(["█▁█▁▄x"]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().green());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().red());
+        assert_eq!(render(widget.clone(), 6), expected);
+
+        let widget = widget.direction(RenderDirection::RightToLeft);
+        let mut expected = Buffer::with_lines(["x▄▁█▁█"]);
+        expected.set_style(Rect::new(1, 0, 5, 1), Style::new().green());
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().red());
+        assert_eq!(render(widget, 6), expected);
+    }
+
+    #[test]
+    fn it_shows_current_value() {
+        let widget = Sparkline::default()
+            .data(&[0, 21, 42])
+            .show_current_value(true)
+            .value_style(Color::Yellow);
+        let mut expected = Buffer::with_lines([" ▄█xxxxx42"]);
+        expected.set_style(Rect::new(8, 0, 2, 1), Style::new().yellow());
+        assert_eq!(render(widget.clone(), 10), expected);
+
+        let widget = widget.direction(RenderDirection::RightToLeft);
+        let mut expected = Buffer::with_lines(["42xxxxx█▄ "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().yellow());
+        assert_eq!(render(widget, 10), expected);
+    }
+
+    #[test]
+    fn it_shrinks_bars_to_fit_current_value() {
+        let widget = Sparkline::default()
+            .data(&[1, 2, 3, 4, 5, 6, 7, 8, 100])
+            .max(8)
+            .show_current_value(true);
+        assert_eq!(render(widget, 8), Buffer::with_lines(["▁▂▃▄x100"]));
+    }
+
+    #[test]
+    fn it_shows_current_value_on_bottom_row() {
+        let widget = Sparkline::default()
+            .data(&[0, 4, 8])
+            .show_current_value(true);
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  █  ", " ██ 8"]));
+    }
+
+    #[test]
+    fn it_renders_min_height_for_nonzero_values() {
+        let widget = Sparkline::default().data(&[0, 1, 100]);
+        assert_eq!(render(widget.clone(), 3), Buffer::with_lines(["  █"]));
+
+        let widget = widget.min_height_for_nonzero(true);
+        assert_eq!(render(widget, 3), Buffer::with_lines([" ▁█"]));
+    }
+
+    #[test]
+    fn it_renders_min_height_for_nonzero_float_values() {
+        let widget = Sparkline::default()
+            .data_f64(&[0.0, 0.01, 1.0])
+            .min_height_for_nonzero(true);
+        assert_eq!(render(widget, 3), Buffer::with_lines([" ▁█"]));
+    }
+
+    #[test]
+    fn it_keeps_scaling_with_min_height_for_nonzero() {
+        let widget = Sparkline::default()
+            .data(&[0, 1, 2, 3, 4, 5, 6, 7, 8])
+            .min_height_for_nonzero(true);
+        assert_eq!(render(widget, 9), Buffer::with_lines([" ▁▂▃▄▅▆▇█"]));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
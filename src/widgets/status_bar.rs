@@ -0,0 +1,189 @@
+use crate::{prelude::*, style::Styled};
+
+/// A widget to display a status bar split into left, center and right groups of segments
+///
+/// Each group is a [`Line`], so it can be made of several [`Span`]s with their own styles. The
+/// left group is anchored to the left of the bar, the right group to the right and the center
+/// group is centered in the bar. The [style](StatusBar::style) of the bar is applied to the whole
+/// area, so the background is filled across the bar even where there are no segments.
+///
+/// When there isn't enough space for all the groups, the center group is truncated first and
+/// moved to stay between the left and right groups. If the left and right groups still don't fit,
+/// the right group is truncated.
+///
+/// The groups are rendered on the first row of the area.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let status_bar = StatusBar::new()
+///     .left(vec![" NORMAL ".black().on_green(), " main ".into()])
+///     .center("src/main.rs")
+///     .right(vec![Span::raw("utf-8 "), Span::raw(" 12:4 ").reversed()])
+///     .style(Style::new().on_dark_gray());
+/// frame.render_widget(status_bar, area);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct StatusBar<'a> {
+    /// Segments anchored to the left of the bar
+    left: Line<'a>,
+    /// Segments centered in the bar
+    center: Line<'a>,
+    /// Segments anchored to the right of the bar
+    right: Line<'a>,
+    /// Style of the whole bar
+    style: Style,
+}
+
+impl<'a> StatusBar<'a> {
+    /// Creates a new empty status bar
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the segments anchored to the left of the bar
+    ///
+    /// `segments` can be anything convertible to a [`Line`], e.g. a `Vec<Span>` or a `&str`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn left<T: Into<Line<'a>>>(mut self, segments: T) -> Self {
+        self.left = segments.into();
+        self
+    }
+
+    /// Sets the segments centered in the bar
+    ///
+    /// `segments` can be anything convertible to a [`Line`], e.g. a `Vec<Span>` or a `&str`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn center<T: Into<Line<'a>>>(mut self, segments: T) -> Self {
+        self.center = segments.into();
+        self
+    }
+
+    /// Sets the segments anchored to the right of the bar
+    ///
+    /// `segments` can be anything convertible to a [`Line`], e.g. a `Vec<Span>` or a `&str`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn right<T: Into<Line<'a>>>(mut self, segments: T) -> Self {
+        self.right = segments.into();
+        self
+    }
+
+    /// Sets the style of the whole bar
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for StatusBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for StatusBar<'_> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+        let (y, right) = (area.top(), area.right());
+
+        let (left_end, _) = buf.set_line(area.left(), y, &self.left, area.width);
+
+        let right_width = (self.right.width() as u16).min(right - left_end);
+        let right_start = right - right_width;
+        buf.set_line(right_start, y, &self.right, right_width);
+
+        // Center in the whole bar, but stay in the space between the left and right groups
+        let center_width = self.center.width() as u16;
+        let center_start = (area.left() + area.width.saturating_sub(center_width) / 2)
+            .min(right_start.saturating_sub(center_width))
+            .max(left_end);
+        let available_width = right_start.saturating_sub(center_start);
+        buf.set_line(center_start, y, &self.center, available_width);
+    }
+}
+
+impl Styled for StatusBar<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn status_bar() -> StatusBar<'static> {
+        StatusBar::new()
+            .left(vec![Span::raw("NOR"), Span::raw(" main")])
+            .center("file.rs")
+            .right(vec![Span::raw("utf8"), Span::raw(" 1:1")])
+    }
+
+    #[test]
+    fn render_positions_groups() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 1));
+        status_bar().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["NOR main   file.rs    utf8 1:1"]));
+    }
+
+    #[rstest]
+    #[case::center_moved(24, "NOR mainfile.rs utf8 1:1")]
+    #[case::center_truncated(20, "NOR mainfileutf8 1:1")]
+    #[case::center_hidden(16, "NOR mainutf8 1:1")]
+    #[case::right_truncated(12, "NOR mainutf8")]
+    #[case::left_truncated(5, "NOR m")]
+    fn render_truncates_center_first(#[case] width: u16, #[case] expected: &str) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        status_bar().render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_fills_background() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        StatusBar::new()
+            .left(vec!["A".red(), "B".into()])
+            .right("C")
+            .style(Style::new().on_blue())
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["AB         C", "            "]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        status_bar().render(Rect::new(0, 0, 0, 0), &mut buf);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 10, 1)));
+    }
+}
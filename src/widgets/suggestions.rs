@@ -0,0 +1,436 @@
+use std::borrow::Cow;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{
+        popup::{impl_styled, render_items, render_popup, Selection},
+        Block,
+    },
+};
+
+/// State of the [`Suggestions`] widget
+///
+/// This state holds the index of the highlighted suggestion and the index of the first visible
+/// suggestion. Like for a [`List`](crate::widgets::List), the offset is updated when rendering so
+/// that the highlighted suggestion is always visible.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = SuggestionsState::default();
+/// state.select(Some(1));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuggestionsState {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    selection: Selection,
+}
+
+impl SuggestionsState {
+    /// Sets the index of the highlighted suggestion
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selection.selected = selected;
+        self
+    }
+
+    /// Index of the first visible suggestion
+    pub const fn offset(&self) -> usize {
+        self.selection.offset
+    }
+
+    /// Index of the highlighted suggestion
+    ///
+    /// Returns `None` if no suggestion is highlighted
+    pub const fn selected(&self) -> Option<usize> {
+        self.selection.selected
+    }
+
+    /// Sets the index of the highlighted suggestion
+    ///
+    /// Set to `None` if no suggestion is highlighted. This will also reset the offset to `0`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selection.select(index);
+    }
+
+    /// Highlights the next suggestion or the first one if no suggestion is highlighted
+    ///
+    /// Note: until the suggestions are rendered, their number is not known, so the index will be
+    /// corrected when rendering
+    pub fn select_next(&mut self) {
+        self.selection.select_next();
+    }
+
+    /// Highlights the previous suggestion or the last one if no suggestion is highlighted
+    ///
+    /// Note: until the suggestions are rendered, their number is not known, so the index will be
+    /// corrected when rendering
+    pub fn select_previous(&mut self) {
+        self.selection.select_previous();
+    }
+}
+
+/// A popup showing suggestions to complete a query, e.g. for a command input.
+///
+/// The popup is sized to fit the suggestions and is positioned with its top left corner at the
+/// [anchor](Suggestions::anchor), usually the cell below the cursor. It is moved left and up when
+/// needed so that it stays within the area it is rendered in. The area under the popup is
+/// [cleared](Clear) before the popup is drawn, so the area given to render is usually the whole
+/// frame.
+///
+/// The part of each suggestion matching the [query](Suggestions::query) (ignoring ASCII case) is
+/// styled with the [match style](Suggestions::match_style), and the suggestion highlighted in the
+/// [`SuggestionsState`] is styled with the [highlight style](Suggestions::highlight_style). At most
+/// [`max_height`](Suggestions::max_height) suggestions are visible at once, scrolling to keep the
+/// highlighted suggestion visible.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// let suggestions = Suggestions::new(["quit", "query", "request"])
+///     .query("qu")
+///     .anchor((4, 1))
+///     .highlight_style(Style::new().reversed())
+///     .match_style(Style::new().bold());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = SuggestionsState::default().with_selected(Some(0));
+///
+/// frame.render_stateful_widget(suggestions, frame.size(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Suggestions<'a> {
+    /// The candidates to complete the query with
+    items: Vec<Cow<'a, str>>,
+    /// The text typed so far, highlighted in the suggestions
+    query: Cow<'a, str>,
+    /// Position of the top left corner of the popup
+    anchor: Position,
+    /// Maximum number of visible suggestions
+    max_height: u16,
+    /// Block drawn around the suggestions
+    block: Option<Block<'a>>,
+    /// Base style of the popup
+    style: Style,
+    /// Style of the highlighted suggestion
+    highlight_style: Style,
+    /// Style of the part of the suggestions matching the query
+    match_style: Style,
+}
+
+impl Default for Suggestions<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            query: Cow::Borrowed(""),
+            anchor: Position::default(),
+            max_height: 5,
+            block: Some(Block::bordered()),
+            style: Style::new(),
+            highlight_style: Style::new(),
+            match_style: Style::new(),
+        }
+    }
+}
+
+impl<'a> Suggestions<'a> {
+    /// Creates a new popup with the given suggestions
+    ///
+    /// `items` is a collection of anything convertible to a `Cow<str>`, e.g. `&str` or `String`.
+    pub fn new<I, T>(items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the suggestions
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the query whose occurrences are highlighted in the suggestions
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn query<T: Into<Cow<'a, str>>>(mut self, query: T) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Sets the position of the top left corner of the popup
+    ///
+    /// The position is relative to the buffer, like the area given to render. The popup is moved
+    /// left and up when it would not fit in that area.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn anchor<P: Into<Position>>(mut self, anchor: P) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Sets the maximum number of visible suggestions
+    ///
+    /// The default is 5.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_height(mut self, max_height: u16) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the block drawn around the suggestions
+    ///
+    /// The default is a bordered block. Use `None` to draw the suggestions without a block.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Option<Block<'a>>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the base style of the popup
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the highlighted suggestion
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the part of the suggestions matching the query
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.match_style = style.into();
+        self
+    }
+
+    /// Returns the number of suggestions
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if there are no suggestions
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the area of the popup within the given area
+    fn popup_area(&self, area: Rect) -> Rect {
+        let items_width = self.items.iter().map(|item| item.width()).max();
+        let items_width = u16::try_from(items_width.unwrap_or_default()).unwrap_or(u16::MAX);
+        let items_height = u16::try_from(self.items.len())
+            .unwrap_or(u16::MAX)
+            .min(self.max_height);
+        let (width, height) = self
+            .block
+            .as_ref()
+            .map_or((items_width, items_height), |block| {
+                block.outer_size(items_width, items_height)
+            });
+        let (width, height) = (width.min(area.width), height.min(area.height));
+        let x = self.anchor.x.clamp(area.left(), area.right() - width);
+        let y = self.anchor.y.clamp(area.top(), area.bottom() - height);
+        Rect::new(x, y, width, height)
+    }
+
+    /// Returns the suggestion as a line with the part matching the query styled
+    fn suggestion_line<'b>(&self, item: &'b str) -> Line<'b> {
+        let start = if self.query.is_empty() {
+            None
+        } else {
+            item.to_ascii_lowercase()
+                .find(&self.query.to_ascii_lowercase())
+        };
+        let Some(start) = start else {
+            return Line::from(item);
+        };
+        let (before, rest) = item.split_at(start);
+        let (matched, after) = rest.split_at(self.query.len());
+        Line::from(vec![
+            Span::raw(before),
+            Span::styled(matched, self.match_style),
+            Span::raw(after),
+        ])
+    }
+}
+
+impl Widget for Suggestions<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl WidgetRef for Suggestions<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = SuggestionsState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Suggestions<'_> {
+    type State = SuggestionsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized, the blanket impl is used with the
+// unstable-widget-ref feature
+#[cfg(not(feature = "unstable-widget-ref"))]
+impl StatefulWidget for &Suggestions<'_> {
+    type State = SuggestionsState;
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Suggestions<'_> {
+    type State = SuggestionsState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // If the selected index is out of bounds, set it to the last suggestion
+        state.selection.clamp(self.items.len());
+        if self.items.is_empty() {
+            return;
+        }
+
+        let popup_area = self.popup_area(area);
+        if popup_area.is_empty() {
+            return;
+        }
+        let items_area = render_popup(self.block.as_ref(), self.style, popup_area, buf);
+        render_items(
+            &mut state.selection,
+            self.items.len(),
+            |index| self.suggestion_line(&self.items[index]),
+            self.highlight_style,
+            items_area,
+            buf,
+        );
+    }
+}
+
+impl_styled!(Suggestions);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_anchored_with_highlight() {
+        let suggestions = Suggestions::new(["quit", "Query", "request", "help"])
+            .query("qu")
+            .anchor((2, 1))
+            .max_height(3)
+            .highlight_style(Style::new().reversed())
+            .match_style(Style::new().bold());
+        let mut state = SuggestionsState::default().with_selected(Some(1));
+        let mut buf = Buffer::with_lines(["xxxxxxxxxxxxxx"; 6]);
+        StatefulWidget::render(&suggestions, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "xxxxxxxxxxxxxx",
+            "xx┌───────┐xxx",
+            "xx│quit   │xxx",
+            "xx│Query  │xxx",
+            "xx│request│xxx",
+            "xx└───────┘xxx",
+        ]);
+        expected.set_style(Rect::new(3, 2, 2, 1), Style::new().bold());
+        expected.set_style(Rect::new(3, 3, 7, 1), Style::new().reversed());
+        expected.set_style(Rect::new(3, 3, 2, 1), Style::new().bold().reversed());
+        expected.set_style(Rect::new(5, 4, 2, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn popup_is_clamped_to_area() {
+        let suggestions = Suggestions::new(["one", "two"]).anchor((8, 5));
+        let mut buf = Buffer::with_lines(["xxxxxxxxxx"; 6]);
+        Widget::render(suggestions, buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "xxxxxxxxxx",
+                "xxxxxxxxxx",
+                "xxxxx┌───┐",
+                "xxxxx│one│",
+                "xxxxx│two│",
+                "xxxxx└───┘",
+            ])
+        );
+    }
+
+    #[test]
+    fn scrolls_to_highlighted_suggestion() {
+        let suggestions = Suggestions::new(["a", "b", "c", "d"])
+            .block(None)
+            .max_height(2)
+            .highlight_style(Style::new().reversed());
+        let mut state = SuggestionsState::default();
+        state.select_previous();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        StatefulWidget::render(&suggestions, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["c  ", "d  ", "   "]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+        assert_eq!(state.selected(), Some(3));
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[test]
+    fn empty_suggestions_render_nothing() {
+        let mut state = SuggestionsState::default().with_selected(Some(0));
+        let mut buf = Buffer::with_lines(["xxx"]);
+        StatefulWidget::render(Suggestions::default(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["xxx"]));
+        assert_eq!(state.selected(), None);
+    }
+}
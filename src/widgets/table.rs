@@ -1,12 +1,16 @@
 mod cell;
+mod data_table;
 mod highlight_spacing;
 mod row;
+mod sort_direction;
 #[allow(clippy::module_inception)]
 mod table;
 mod table_state;
 
 pub use cell::*;
+pub use data_table::*;
 pub use highlight_spacing::*;
 pub use row::*;
+pub use sort_direction::*;
 pub use table::*;
 pub use table_state::*;
@@ -1,4 +1,8 @@
-use crate::{prelude::*, style::Styled};
+use crate::{
+    prelude::*,
+    style::Styled,
+    widgets::{Paragraph, Wrap},
+};
 
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
 ///
@@ -42,6 +46,7 @@ use crate::{prelude::*, style::Styled};
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    colspan: u16,
 }
 
 impl<'a> Cell<'a> {
@@ -68,6 +73,7 @@ impl<'a> Cell<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            colspan: 1,
         }
     }
 
@@ -129,9 +135,67 @@ impl<'a> Cell<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set the number of columns this cell spans
+    ///
+    /// The cell occupies its own column and the following `colspan - 1` columns of the [`Table`],
+    /// including the spacing between them, like the HTML `colspan` attribute. The next cell of the
+    /// [`Row`] is placed in the column after the spanned ones. A span exceeding the remaining
+    /// columns is clamped to the last column. A value of 0 is treated as 1, which is the default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let header = Row::new([Cell::new("Name").colspan(2), Cell::new("Age")]);
+    /// ```
+    ///
+    /// [`Row`]: super::Row
+    /// [`Table`]: super::Table
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn colspan(mut self, colspan: u16) -> Self {
+        self.colspan = colspan;
+        self
+    }
 }
 
 impl Cell<'_> {
+    /// Returns the number of columns this cell spans
+    pub(crate) fn column_count(&self) -> usize {
+        usize::from(self.colspan.max(1))
+    }
+
+    /// Returns the width of the widest line of the content of the cell
+    pub(crate) fn width(&self) -> usize {
+        self.content.width()
+    }
+
+    /// Returns the number of lines of the content of the cell
+    pub(crate) fn height(&self) -> usize {
+        self.content.height()
+    }
+
+    /// Returns whether the content of the cell doesn't fit in an area of the given size
+    pub(crate) fn is_truncated(&self, width: u16, height: u16) -> bool {
+        self.content.width() > usize::from(width) || self.height() > usize::from(height)
+    }
+
+    /// Returns a copy of the cell with its content word wrapped to the given width
+    pub(crate) fn wrapped(&self, width: u16) -> Self {
+        let alignment = self.content.alignment.unwrap_or(Alignment::Left);
+        let lines = Paragraph::new(self.content.clone())
+            .alignment(alignment)
+            .wrap(Wrap { trim: true })
+            .wrapped_lines(width);
+        Self {
+            content: Text::from(lines),
+            style: self.style,
+            colspan: self.colspan,
+        }
+    }
+
     pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
         self.content.clone().render(area, buf);
@@ -146,6 +210,7 @@ where
         Self {
             content: content.into(),
             style: Style::default(),
+            colspan: 1,
         }
     }
 }
@@ -0,0 +1,685 @@
+use std::{cmp::Ordering, fmt, rc::Rc};
+
+use super::{Cell, Row, SortDirection, Table, TableState};
+use crate::{prelude::*, style::Styled, widgets::Block};
+
+/// Returns the text displayed in a column for a row of data
+type ValueFn<'a, T> = Rc<dyn Fn(&T) -> String + 'a>;
+
+/// Compares two rows of data to sort them by a column
+type CompareFn<'a, T> = Rc<dyn Fn(&T, &T) -> Ordering + 'a>;
+
+/// Decides whether a row of data is displayed
+type FilterFn<T> = Rc<dyn Fn(&T) -> bool>;
+
+/// A column of a [`DataTable`]
+///
+/// A column has a header and an accessor which returns the text displayed in the column for a
+/// row of data. Rows are sorted by comparing the text returned by the accessor, unless a custom
+/// comparison is set with [`DataColumn::sort_by`] (e.g. to sort numbers by value).
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// struct Planet {
+///     name: &'static str,
+///     moons: u32,
+/// }
+///
+/// let name = DataColumn::new("Name", |planet: &Planet| planet.name.to_string());
+/// let moons = DataColumn::new("Moons", |planet: &Planet| planet.moons.to_string())
+///     .width(Constraint::Length(5))
+///     .sort_by(|a, b| a.moons.cmp(&b.moons));
+/// ```
+pub struct DataColumn<'a, T> {
+    /// The title of the column, displayed in the header
+    header: Line<'a>,
+    /// Width constraint of the column
+    width: Constraint,
+    /// Returns the text displayed in the column for a row
+    value: ValueFn<'a, T>,
+    /// Optional comparison used to sort the rows by this column
+    compare: Option<CompareFn<'a, T>>,
+}
+
+impl<'a, T> DataColumn<'a, T> {
+    /// Creates a new column with the given header and accessor
+    ///
+    /// The column fills the space left by the other columns by default.
+    pub fn new<H, F>(header: H, value: F) -> Self
+    where
+        H: Into<Line<'a>>,
+        F: Fn(&T) -> String + 'a,
+    {
+        Self {
+            header: header.into(),
+            width: Constraint::Fill(1),
+            value: Rc::new(value),
+            compare: None,
+        }
+    }
+
+    /// Set the width constraint of the column
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn width<C: Into<Constraint>>(mut self, width: C) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the comparison used to sort the rows by this column
+    ///
+    /// The comparison orders the rows in ascending order, it is reversed when sorting in
+    /// descending order.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sort_by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'a,
+    {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.compare.as_ref().map_or_else(
+            || (self.value)(a).cmp(&(self.value)(b)),
+            |compare| compare(a, b),
+        )
+    }
+}
+
+impl<T> Clone for DataColumn<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            width: self.width,
+            value: Rc::clone(&self.value),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for DataColumn<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataColumn")
+            .field("header", &self.header)
+            .field("width", &self.width)
+            .field("sortable_by", &self.compare.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// State of a [`DataTable`] widget
+///
+/// This state holds the column the rows are sorted by, the predicate used to filter the rows and
+/// the [`TableState`] of the underlying [`Table`]. The selected row and the offset of the table
+/// are indices into the visible rows, see [`DataTable::visible_rows`] to map them back to the
+/// rows of data.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = DataTableState::<u32>::default().with_filter(|value| value % 2 == 0);
+/// state.toggle_sort(0);
+/// state.table_state_mut().select(Some(0));
+/// ```
+pub struct DataTableState<T> {
+    sort: Option<(usize, SortDirection)>,
+    filter: Option<FilterFn<T>>,
+    table_state: TableState,
+}
+
+impl<T> DataTableState<T> {
+    /// Creates a new [`DataTableState`] without sorting nor filtering
+    pub const fn new() -> Self {
+        Self {
+            sort: None,
+            filter: None,
+            table_state: TableState::new(),
+        }
+    }
+
+    /// Sets the column and direction the rows are sorted by
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    /// Sets the predicate used to filter the rows
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.set_filter(filter);
+        self
+    }
+
+    /// The column and direction the rows are sorted by
+    ///
+    /// Returns `None` if the rows are displayed in their original order
+    pub const fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    /// Sorts the rows by the given column in the given direction
+    ///
+    /// This resets the selection as the order of the visible rows changes.
+    pub fn sort_by(&mut self, column: usize, direction: SortDirection) {
+        self.sort = Some((column, direction));
+        self.table_state.select(None);
+    }
+
+    /// Sorts the rows by the given column
+    ///
+    /// The rows are sorted in ascending order, unless they were already sorted by this column in
+    /// ascending order, in which case they are sorted in descending order. This resets the
+    /// selection as the order of the visible rows changes.
+    pub fn toggle_sort(&mut self, column: usize) {
+        let direction = match self.sort {
+            Some((sorted, direction)) if sorted == column => direction.reversed(),
+            _ => SortDirection::Ascending,
+        };
+        self.sort_by(column, direction);
+    }
+
+    /// Displays the rows in their original order
+    ///
+    /// This resets the selection as the order of the visible rows changes.
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+        self.table_state.select(None);
+    }
+
+    /// Sets the predicate used to filter the rows
+    ///
+    /// Only the rows for which the predicate returns `true` are displayed. This resets the
+    /// selection as the visible rows change.
+    pub fn set_filter<F>(&mut self, filter: F)
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.filter = Some(Rc::new(filter));
+        self.table_state.select(None);
+    }
+
+    /// Displays all the rows
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.table_state.select(None);
+    }
+
+    /// State of the underlying [`Table`]
+    pub const fn table_state(&self) -> &TableState {
+        &self.table_state
+    }
+
+    /// Mutable reference to the state of the underlying [`Table`]
+    pub fn table_state_mut(&mut self) -> &mut TableState {
+        &mut self.table_state
+    }
+}
+
+impl<T> Default for DataTableState<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for DataTableState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sort: self.sort,
+            filter: self.filter.clone(),
+            table_state: self.table_state.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for DataTableState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataTableState")
+            .field("sort", &self.sort)
+            .field("filtered", &self.filter.is_some())
+            .field("table_state", &self.table_state)
+            .finish()
+    }
+}
+
+/// A [`Table`] displaying rows of data that can be sorted and filtered
+///
+/// The data is supplied as a slice of rows of any type, and each [`DataColumn`] extracts the text
+/// it displays from a row. The column the rows are sorted by and the predicate used to filter the
+/// rows are stored in the [`DataTableState`], and the visible rows are derived from the data on
+/// each render. The header of the column the rows are sorted by shows an indicator of the sort
+/// direction.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let planets = [("Mercury", 0), ("Earth", 1), ("Mars", 2)];
+/// let table = DataTable::new(
+///     &planets,
+///     [
+///         DataColumn::new("Name", |planet: &(&str, u32)| planet.0.to_string()),
+///         DataColumn::new("Moons", |planet: &(&str, u32)| planet.1.to_string())
+///             .sort_by(|a, b| a.1.cmp(&b.1)),
+///     ],
+/// )
+/// .header_style(Style::new().bold())
+/// .highlight_style(Style::new().reversed());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = DataTableState::default().with_sort(1, SortDirection::Descending);
+///
+/// frame.render_stateful_widget(table, area, &mut state);
+/// # }
+/// ```
+pub struct DataTable<'a, T> {
+    /// The rows of data
+    rows: &'a [T],
+    /// The columns extracting the displayed text from the rows
+    columns: Vec<DataColumn<'a, T>>,
+    /// Space between each column
+    column_spacing: u16,
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Base style for the widget
+    style: Style,
+    /// Style of the header
+    header_style: Style,
+    /// Style used to render the selected row
+    highlight_style: Style,
+    /// Symbol in front of the selected row
+    highlight_symbol: Text<'a>,
+    /// Symbols of the sort indicator, in ascending and descending order
+    sort_indicator_symbols: (&'a str, &'a str),
+}
+
+impl<'a, T> DataTable<'a, T> {
+    /// Creates a new [`DataTable`] displaying the given rows of data in the given columns
+    pub fn new<C>(rows: &'a [T], columns: C) -> Self
+    where
+        C: IntoIterator<Item = DataColumn<'a, T>>,
+    {
+        Self {
+            rows,
+            columns: columns.into_iter().collect(),
+            column_spacing: 1,
+            block: None,
+            style: Style::new(),
+            header_style: Style::new(),
+            highlight_style: Style::new(),
+            highlight_symbol: Text::default(),
+            sort_indicator_symbols: ("▲", "▼"),
+        }
+    }
+
+    /// Set the spacing between columns
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Wraps the table with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the header
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.header_style = style.into();
+        self
+    }
+
+    /// Set the style of the selected row
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, highlight_style: S) -> Self {
+        self.highlight_style = highlight_style.into();
+        self
+    }
+
+    /// Set the symbol to be displayed in front of the selected row
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol<S: Into<Text<'a>>>(mut self, highlight_symbol: S) -> Self {
+        self.highlight_symbol = highlight_symbol.into();
+        self
+    }
+
+    /// Set the symbols of the sort indicator shown in the header of the column the rows are sorted
+    /// by
+    ///
+    /// See [`Table::sort_indicator_symbols`]. The default symbols are `▲` for ascending order and
+    /// `▼` for descending order.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_indicator_symbols(mut self, ascending: &'a str, descending: &'a str) -> Self {
+        self.sort_indicator_symbols = (ascending, descending);
+        self
+    }
+
+    /// Returns the indices of the rows of data that are displayed, in the order they are displayed
+    ///
+    /// The rows are filtered with the predicate of the state, then sorted by the column of the
+    /// state. Sorting is stable, so rows that compare equal keep their original order. A sort
+    /// column that does not exist is ignored.
+    ///
+    /// The selected row of [`DataTableState::table_state`] is an index into the returned vector.
+    pub fn visible_rows(&self, state: &DataTableState<T>) -> Vec<usize> {
+        let mut indices = (0..self.rows.len())
+            .filter(|&i| state.filter.as_ref().map_or(true, |f| f(&self.rows[i])))
+            .collect::<Vec<_>>();
+        if let Some((column, direction)) = state.sort {
+            if let Some(column) = self.columns.get(column) {
+                indices.sort_by(|&a, &b| {
+                    direction.apply(column.compare(&self.rows[a], &self.rows[b]))
+                });
+            }
+        }
+        indices
+    }
+
+    /// Builds the [`Table`] displaying the visible rows
+    fn table(&self, state: &DataTableState<T>) -> Table<'a> {
+        let header = self
+            .columns
+            .iter()
+            .map(|column| Cell::new(column.header.clone()));
+        let rows = self.visible_rows(state).into_iter().map(|i| {
+            let row = &self.rows[i];
+            Row::new(self.columns.iter().map(|column| (column.value)(row)))
+        });
+        let mut table = Table::new(rows, self.columns.iter().map(|column| column.width))
+            .header(Row::new(header).style(self.header_style))
+            .column_spacing(self.column_spacing)
+            .style(self.style)
+            .highlight_style(self.highlight_style)
+            .highlight_symbol(self.highlight_symbol.clone())
+            .sort_indicator_symbols(self.sort_indicator_symbols.0, self.sort_indicator_symbols.1);
+        if let Some((column, direction)) = state.sort {
+            table = table.sort_indicator(column, direction);
+        }
+        if let Some(block) = &self.block {
+            table = table.block(block.clone());
+        }
+        table
+    }
+}
+
+impl<T> Clone for DataTable<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows,
+            columns: self.columns.clone(),
+            column_spacing: self.column_spacing,
+            block: self.block.clone(),
+            style: self.style,
+            header_style: self.header_style,
+            highlight_style: self.highlight_style,
+            highlight_symbol: self.highlight_symbol.clone(),
+            sort_indicator_symbols: self.sort_indicator_symbols,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DataTable<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataTable")
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .field("column_spacing", &self.column_spacing)
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("header_style", &self.header_style)
+            .field("highlight_style", &self.highlight_style)
+            .field("highlight_symbol", &self.highlight_symbol)
+            .field("sort_indicator_symbols", &self.sort_indicator_symbols)
+            .finish()
+    }
+}
+
+impl<T> Widget for DataTable<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl<T> WidgetRef for DataTable<'_, T> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = DataTableState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl<T> StatefulWidget for DataTable<'_, T> {
+    type State = DataTableState<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized, the blanket impl is used with the
+// unstable-widget-ref feature
+#[cfg(not(feature = "unstable-widget-ref"))]
+impl<T> StatefulWidget for &DataTable<'_, T> {
+    type State = DataTableState<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl<T> StatefulWidgetRef for DataTable<'_, T> {
+    type State = DataTableState<T>;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let table = self.table(state);
+        StatefulWidget::render(table, area, buf, &mut state.table_state);
+    }
+}
+
+impl<T> Styled for DataTable<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLANETS: [(&str, u32); 4] = [("Mercury", 0), ("Venus", 0), ("Earth", 1), ("Mars", 2)];
+
+    fn planets_table() -> DataTable<'static, (&'static str, u32)> {
+        DataTable::new(
+            &PLANETS,
+            [
+                DataColumn::new("Name", |planet: &(&str, u32)| planet.0.to_string())
+                    .width(Constraint::Length(9)),
+                DataColumn::new("Moons", |planet: &(&str, u32)| planet.1.to_string())
+                    .width(Constraint::Length(7))
+                    .sort_by(|a, b| a.1.cmp(&b.1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn visible_rows_in_original_order() {
+        let table = planets_table();
+        let state = DataTableState::default();
+        assert_eq!(table.visible_rows(&state), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn visible_rows_sorted_by_text() {
+        let table = planets_table();
+        let state = DataTableState::default().with_sort(0, SortDirection::Ascending);
+        assert_eq!(table.visible_rows(&state), [2, 3, 0, 1]);
+    }
+
+    #[test]
+    fn visible_rows_sorted_with_custom_comparison_is_stable() {
+        let table = planets_table();
+        let state = DataTableState::default().with_sort(1, SortDirection::Descending);
+        assert_eq!(table.visible_rows(&state), [3, 2, 0, 1]);
+    }
+
+    #[test]
+    fn visible_rows_ignores_unknown_sort_column() {
+        let table = planets_table();
+        let state = DataTableState::default().with_sort(5, SortDirection::Ascending);
+        assert_eq!(table.visible_rows(&state), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn visible_rows_filtered() {
+        let table = planets_table();
+        let state = DataTableState::default()
+            .with_filter(|planet: &(&str, u32)| planet.1 > 0)
+            .with_sort(0, SortDirection::Descending);
+        assert_eq!(table.visible_rows(&state), [3, 2]);
+    }
+
+    #[test]
+    fn toggle_sort() {
+        let mut state = DataTableState::<u32>::default();
+        state.toggle_sort(1);
+        assert_eq!(state.sort(), Some((1, SortDirection::Ascending)));
+        state.toggle_sort(1);
+        assert_eq!(state.sort(), Some((1, SortDirection::Descending)));
+        state.toggle_sort(0);
+        assert_eq!(state.sort(), Some((0, SortDirection::Ascending)));
+        state.clear_sort();
+        assert_eq!(state.sort(), None);
+    }
+
+    #[test]
+    fn sort_resets_selection() {
+        let mut state = DataTableState::<u32>::default();
+        state.table_state_mut().select(Some(2));
+        state.sort_by(0, SortDirection::Descending);
+        assert_eq!(state.table_state().selected(), None);
+
+        state.table_state_mut().select(Some(2));
+        state.toggle_sort(0);
+        assert_eq!(state.table_state().selected(), None);
+
+        state.table_state_mut().select(Some(2));
+        state.clear_sort();
+        assert_eq!(state.table_state().selected(), None);
+    }
+
+    #[test]
+    fn set_filter_resets_selection() {
+        let mut state = DataTableState::<u32>::default();
+        state.table_state_mut().select(Some(2));
+        state.set_filter(|value| *value > 1);
+        assert_eq!(state.table_state().selected(), None);
+    }
+
+    #[test]
+    fn render_sorted_by_column() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 17, 5));
+        let mut state = DataTableState::default();
+        state.toggle_sort(0);
+        StatefulWidget::render(planets_table(), buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "Name    ▲ Moons  ",
+                "Earth     1      ",
+                "Mars      2      ",
+                "Mercury   0      ",
+                "Venus     0      ",
+            ])
+        );
+
+        state.toggle_sort(0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 17, 5));
+        StatefulWidget::render(planets_table(), buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "Name    ▼ Moons  ",
+                "Venus     0      ",
+                "Mercury   0      ",
+                "Mars      2      ",
+                "Earth     1      ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_with_filter_and_selection() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 17, 3));
+        let table = planets_table()
+            .header_style(Style::new().bold())
+            .highlight_style(Style::new().reversed());
+        let mut state = DataTableState::default()
+            .with_filter(|planet: &(&str, u32)| planet.1 > 0)
+            .with_sort(1, SortDirection::Descending);
+        state.table_state_mut().select(Some(1));
+        StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "Name      Moons ▼",
+            "Mars      2      ",
+            "Earth     1      ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 17, 1), Style::new().bold());
+        expected.set_style(Rect::new(0, 2, 17, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+}
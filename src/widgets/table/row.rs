@@ -70,6 +70,7 @@ pub struct Row<'a> {
     pub(crate) top_margin: u16,
     pub(crate) bottom_margin: u16,
     pub(crate) style: Style,
+    pub(crate) detail: Option<Text<'a>>,
 }
 
 impl<'a> Row<'a> {
@@ -219,16 +220,46 @@ impl<'a> Row<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set the detail [`Text`] of the [`Row`]
+    ///
+    /// The detail is only rendered when the row is one of the
+    /// [expanded rows](super::Table::expanded_rows) of the table. It is displayed below the cells,
+    /// spanning the full width of the table, and increases the height of the row by the number of
+    /// lines of the detail. The detail is part of the row, so it is selected along with it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let row = Row::new(vec!["Cell 1", "Cell 2"]).detail(Some("More about this row"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn detail<T: Into<Text<'a>>>(mut self, detail: Option<T>) -> Self {
+        self.detail = detail.map(Into::into);
+        self
+    }
 }
 
 // private methods for rendering
 impl Row<'_> {
     /// Returns the total height of the row.
-    pub(crate) const fn height_with_margin(&self) -> u16 {
+    pub(crate) fn height_with_margin(&self, expanded: bool) -> u16 {
         self.height
+            .saturating_add(self.detail_height(expanded))
             .saturating_add(self.top_margin)
             .saturating_add(self.bottom_margin)
     }
+
+    /// Returns the number of lines of the detail of the row, which is only shown when expanded.
+    pub(crate) fn detail_height(&self, expanded: bool) -> u16 {
+        match self.detail {
+            Some(ref detail) if expanded => detail.height() as u16,
+            _ => 0,
+        }
+    }
 }
 
 impl<'a> Styled for Row<'a> {
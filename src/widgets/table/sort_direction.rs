@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+use strum::{Display, EnumString};
+
+/// The direction in which the rows of a table are sorted
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum SortDirection {
+    /// Sort from the smallest to the largest value
+    #[default]
+    Ascending,
+
+    /// Sort from the largest to the smallest value
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    /// Applies the direction to an ordering computed for ascending order
+    pub(crate) const fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        assert_eq!(
+            SortDirection::Ascending.to_string(),
+            "Ascending".to_string()
+        );
+        assert_eq!(
+            SortDirection::Descending.to_string(),
+            "Descending".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "Ascending".parse::<SortDirection>(),
+            Ok(SortDirection::Ascending)
+        );
+        assert_eq!(
+            "Descending".parse::<SortDirection>(),
+            Ok(SortDirection::Descending)
+        );
+        assert_eq!(
+            "".parse::<SortDirection>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn reversed() {
+        assert_eq!(
+            SortDirection::Ascending.reversed(),
+            SortDirection::Descending
+        );
+        assert_eq!(
+            SortDirection::Descending.reversed(),
+            SortDirection::Ascending
+        );
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(
+            SortDirection::Ascending.apply(Ordering::Less),
+            Ordering::Less
+        );
+        assert_eq!(
+            SortDirection::Descending.apply(Ordering::Less),
+            Ordering::Greater
+        );
+    }
+}
@@ -1,9 +1,15 @@
+use std::{borrow::Cow, collections::BTreeSet};
+
 use itertools::Itertools;
+use unicode_width::UnicodeWidthStr;
 
-#[allow(unused_imports)] // `Cell` is used in the doc comment but not the code
-use super::Cell;
-use super::{HighlightSpacing, Row, TableState};
-use crate::{layout::Flex, prelude::*, style::Styled, widgets::Block};
+use super::{Cell, HighlightSpacing, Row, SortDirection, TableState};
+use crate::{
+    layout::Flex,
+    prelude::*,
+    style::Styled,
+    widgets::{empty_text::render_empty_text, Block},
+};
 
 /// A widget to display data in formatted columns.
 ///
@@ -46,8 +52,12 @@ use crate::{layout::Flex, prelude::*, style::Styled, widgets::Block};
 /// - [`Table::footer`] sets the footer row of the [`Table`].
 /// - [`Table::widths`] sets the width constraints of each column.
 /// - [`Table::column_spacing`] sets the spacing between each column.
+/// - [`Table::wrap_cells`] wraps the content of the cells to the width of their column.
+/// - [`Table::frozen_columns`] keeps the leading columns visible when scrolling horizontally.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
 /// - [`Table::style`] sets the base style of the widget.
+/// - [`Table::column_styles`] sets the style of each column.
+/// - [`Table::alternating_row_styles`] sets the styles of even and odd rows.
 /// - [`Table::highlight_style`] sets the style of the selected row.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
@@ -207,15 +217,30 @@ pub struct Table<'a> {
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
+    /// Whether the columns are sized to their content instead of using `widths`
+    auto_widths: bool,
+
     /// Space between each column
     column_spacing: u16,
 
+    /// Whether the content of the cells is wrapped to the width of their column
+    wrap_cells: bool,
+
+    /// Number of leading columns that are not scrolled horizontally
+    frozen_columns: usize,
+
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
 
     /// Base style for the widget
     style: Style,
 
+    /// Style of each column
+    column_styles: Vec<Style>,
+
+    /// Styles of even and odd rows that don't have their own style
+    alternating_row_styles: Option<(Style, Style)>,
+
     /// Style used to render the selected row
     highlight_style: Style,
 
@@ -227,6 +252,18 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// The column whose header shows a sort indicator, and the direction of the sort
+    sort_indicator: Option<(usize, SortDirection)>,
+
+    /// Symbols of the sort indicator, in ascending and descending order
+    sort_indicator_symbols: (&'a str, &'a str),
+
+    /// Indices of the rows whose detail is displayed
+    expanded_rows: BTreeSet<usize>,
+
+    /// Placeholder rendered when the table has no rows
+    empty_text: Option<Text<'a>>,
 }
 
 impl<'a> Default for Table<'a> {
@@ -236,13 +273,22 @@ impl<'a> Default for Table<'a> {
             header: None,
             footer: None,
             widths: Vec::new(),
+            auto_widths: false,
             column_spacing: 1,
+            wrap_cells: false,
+            frozen_columns: 0,
             block: None,
             style: Style::new(),
+            column_styles: Vec::new(),
+            alternating_row_styles: None,
             highlight_style: Style::new(),
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            sort_indicator: None,
+            sort_indicator_symbols: ("▲", "▼"),
+            expanded_rows: BTreeSet::new(),
+            empty_text: None,
         }
     }
 }
@@ -344,6 +390,10 @@ impl<'a> Table<'a> {
     ///
     /// The `footer` parameter is a [`Row`] which will be displayed at the bottom of the [`Table`]
     ///
+    /// The footer is not selectable and stays visible regardless of the scroll offset, the rows are
+    /// scrolled in the space left above it. Like for the header, the footer is styled with
+    /// [`Row::style`] and spaced from the rows with [`Row::top_margin`].
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     ///
     /// # Examples
@@ -362,6 +412,102 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the column whose header shows a sort indicator
+    ///
+    /// The table doesn't sort its rows, this only shows in the header which column the rows were
+    /// sorted by and in which direction. The [symbol](Table::sort_indicator_symbols) of the
+    /// direction is rendered over the right end of the header of the column. Only one column shows
+    /// the indicator, so calling this method again moves it to another column. The indicator is
+    /// not displayed when the column doesn't exist or when the table has no header.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default()
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sort_indicator(1, SortDirection::Descending);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_indicator(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort_indicator = Some((column, direction));
+        self
+    }
+
+    /// Set the symbols of the [sort indicator](Table::sort_indicator)
+    ///
+    /// The default symbols are `▲` for ascending order and `▼` for descending order.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default()
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sort_indicator(0, SortDirection::Ascending)
+    ///     .sort_indicator_symbols("↑", "↓");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_indicator_symbols(mut self, ascending: &'a str, descending: &'a str) -> Self {
+        self.sort_indicator_symbols = (ascending, descending);
+        self
+    }
+
+    /// Set the rows whose [detail](Row::detail) is displayed
+    ///
+    /// `rows` accepts any iterator of row indices, such as a [`HashSet<usize>`]. The detail of an
+    /// expanded row is rendered below its cells and spans the full width of the table. The extra
+    /// height is taken into account when scrolling to the selected row. Rows without a detail are
+    /// not affected.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`HashSet<usize>`]: std::collections::HashSet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [
+    ///     Row::new(["Cell1", "Cell2"]).detail(Some("Details of the first row")),
+    ///     Row::new(["Cell3", "Cell4"]).detail(Some("Details of the second row")),
+    /// ];
+    /// let table = Table::new(rows, [5, 5]).expanded_rows(HashSet::from([1]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expanded_rows<I: IntoIterator<Item = usize>>(mut self, rows: I) -> Self {
+        self.expanded_rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Set a placeholder text rendered when the table has no rows
+    ///
+    /// `text` accepts any type that is convertible to [`Text`], so its style and alignment can be
+    /// set on the text itself. The placeholder is vertically centered in the area of the rows,
+    /// between the header and the footer, and horizontally centered unless the text or its lines
+    /// have their own alignment.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::new(Vec::<Row>::new(), [5, 5])
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .empty_text("No files".italic());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn empty_text<T: Into<Text<'a>>>(mut self, text: T) -> Self {
+        self.empty_text = Some(text.into());
+        self
+    }
+
     /// Set the widths of the columns.
     ///
     /// The `widths` parameter accepts any type that implements `IntoIterator<Item =
@@ -396,6 +542,30 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set whether the columns are sized to their content
+    ///
+    /// When enabled, the [width constraints](Table::widths) are ignored and each column is as wide
+    /// as its widest cell, including the header and footer. Cells spanning several columns are not
+    /// taken into account. If the columns don't fit in the available width, they are shrunk
+    /// proportionally to their width. The number of columns is the length of the widths if set, or
+    /// the number of cells of the widest row otherwise.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(["Apple", "3"]), Row::new(["Banana", "12"])];
+    /// // the columns are 6 and 2 cells wide
+    /// let table = Table::default().rows(rows).auto_widths(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_widths(mut self, auto_widths: bool) -> Self {
+        self.auto_widths = auto_widths;
+        self
+    }
+
     /// Set the spacing between columns
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -414,6 +584,53 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set whether the content of the cells is wrapped to the width of their column
+    ///
+    /// By default, the content of a cell that is wider than its column is truncated. When wrapping
+    /// is enabled, the content of the cells of each row is word wrapped to the width of the
+    /// columns they span, and the height of the row grows to fit its tallest cell. A row is never
+    /// made shorter than its [height](Row::height). The header and footer are not wrapped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Name", "A long description"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(10)];
+    /// let table = Table::new(rows, widths).wrap_cells(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_cells(mut self, wrap_cells: bool) -> Self {
+        self.wrap_cells = wrap_cells;
+        self
+    }
+
+    /// Set the number of leading columns that stay visible when the table is scrolled horizontally
+    ///
+    /// The table is scrolled horizontally by changing [`TableState::offset_x`], which skips that
+    /// many of the columns following the frozen ones. The remaining columns are laid out in the
+    /// space left after the frozen columns and clipped to the width of the table.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Name", "Cell2", "Cell3"])];
+    /// # let widths = [Constraint::Length(5); 3];
+    /// let table = Table::new(rows, widths).frozen_columns(1);
+    /// let mut state = TableState::default();
+    /// state.scroll_right(); // "Name" stays in place, "Cell2" is skipped
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
     /// Wraps the table with a custom [`Block`] widget.
     ///
     /// The `block` parameter is of type [`Block`]. This holds the specified block to be
@@ -470,6 +687,68 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the style of each column
+    ///
+    /// The `styles` parameter accepts any type that implements `IntoIterator<Item =
+    /// Into<Style>>`, with one style per column starting from the first column. Columns without a
+    /// style are not styled.
+    ///
+    /// The style of a column is applied to the cells of that column in every row, including the
+    /// header and footer. Styles are applied in the following order, each one patching the
+    /// previous one: the style of the [`Row`], the style of the column, the style of the
+    /// [`Cell`], and finally the [highlight style](Table::highlight_style) for the selected row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).column_styles([Style::new(), Style::new().on_blue()]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_styles<I>(mut self, styles: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Style>,
+    {
+        self.column_styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the styles of even and odd rows, to make dense tables easier to read
+    ///
+    /// `even` and `odd` accept any type that is convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// Rows are counted from 0 in the order they were given, so the stripes don't change when the
+    /// table is scrolled. A row spans its whole height, so all the lines of a [`Row`] with a
+    /// [height](Row::height) greater than 1 share the same style. Rows that have their own
+    /// [style](Row::style) are not striped, and the [highlight style](Table::highlight_style) is
+    /// still applied over the stripes for the selected row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).alternating_row_styles(Style::new(), Style::new().on_black());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alternating_row_styles<E: Into<Style>, O: Into<Style>>(
+        mut self,
+        even: E,
+        odd: O,
+    ) -> Self {
+        self.alternating_row_styles = Some((even.into(), odd.into()));
+        self
+    }
+
     /// Set the style of the selected row
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -594,7 +873,9 @@ impl StatefulWidget for Table<'_> {
     }
 }
 
-// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+// Note: remove this when StatefulWidgetRef is stabilized, the blanket impl is used with the
+// unstable-widget-ref feature
+#[cfg(not(feature = "unstable-widget-ref"))]
 impl StatefulWidget for &Table<'_> {
     type State = TableState;
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
@@ -606,6 +887,7 @@ impl StatefulWidgetRef for Table<'_> {
     type State = TableState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.truncated_cells.clear();
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let table_area = self.block.inner_if_some(area);
@@ -614,12 +896,24 @@ impl StatefulWidgetRef for Table<'_> {
         }
 
         let selection_width = self.selection_width(state);
-        let columns_widths = self.get_columns_widths(table_area.width, selection_width);
+        state.offset_x = state.offset_x.min(self.max_offset_x());
+        let columns_widths =
+            self.get_scrolled_columns_widths(table_area.width, selection_width, state.offset_x);
         let (header_area, rows_area, footer_area) = self.layout(table_area);
 
         self.render_header(header_area, buf, &columns_widths);
 
+        if self.rows.is_empty() {
+            render_empty_text(self.empty_text.as_ref(), rows_area, buf);
+        }
+
+        let rows = if self.wrap_cells {
+            Cow::Owned(self.wrapped_rows(&columns_widths))
+        } else {
+            Cow::Borrowed(self.rows.as_slice())
+        };
         self.render_rows(
+            &rows,
             rows_area,
             buf,
             state,
@@ -659,23 +953,47 @@ impl Table<'_> {
     fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
-            }
+            self.render_cells(&header.cells, area, buf, column_widths);
+            self.render_sort_indicator(area, buf, column_widths);
         }
     }
 
+    /// Renders the sort indicator right-aligned in the header of its column
+    fn render_sort_indicator(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+        let Some((column, direction)) = self.sort_indicator else {
+            return;
+        };
+        let Some(&(x, width)) = column_widths.get(column) else {
+            return;
+        };
+        if width == 0 || area.is_empty() {
+            return;
+        }
+        let symbol = match direction {
+            SortDirection::Ascending => self.sort_indicator_symbols.0,
+            SortDirection::Descending => self.sort_indicator_symbols.1,
+        };
+        let symbol_width = (symbol.width() as u16).min(width);
+        buf.set_stringn(
+            area.x + x + width - symbol_width,
+            area.y,
+            symbol,
+            symbol_width as usize,
+            Style::new(),
+        );
+    }
+
     fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
-            }
+            self.render_cells(&footer.cells, area, buf, column_widths);
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_rows(
         &self,
+        rows: &[Row],
         area: Rect,
         buf: &mut Buffer,
         state: &mut TableState,
@@ -683,29 +1001,34 @@ impl Table<'_> {
         highlight_symbol: &Text<'_>,
         columns_widths: &[(u16, u16)],
     ) {
-        if self.rows.is_empty() {
+        if rows.is_empty() {
             return;
         }
 
-        let (start_index, end_index) =
-            self.get_row_bounds(state.selected, state.offset, area.height);
+        let (start_index, end_index) = get_row_bounds(
+            rows,
+            &self.expanded_rows,
+            state.selected,
+            state.offset,
+            area.height,
+        );
         state.offset = start_index;
 
         let mut y_offset = 0;
-        for (i, row) in self
-            .rows
+        for (i, row) in rows
             .iter()
             .enumerate()
             .skip(state.offset)
             .take(end_index - start_index)
         {
+            let expanded = self.expanded_rows.contains(&i);
             let row_area = Rect::new(
                 area.x,
                 area.y + y_offset + row.top_margin,
                 area.width,
-                row.height_with_margin() - row.top_margin,
+                row.height_with_margin(expanded) - row.top_margin,
             );
-            buf.set_style(row_area, row.style);
+            buf.set_style(row_area, self.row_style(i, row));
 
             let is_selected = state.selected().is_some_and(|index| index == i);
             if selection_width > 0 && is_selected {
@@ -713,97 +1036,271 @@ impl Table<'_> {
                     width: selection_width,
                     ..row_area
                 };
-                buf.set_style(selection_area, row.style);
+                buf.set_style(selection_area, self.row_style(i, row));
                 highlight_symbol.clone().render(selection_area, buf);
             };
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
+            let detail = row.detail.as_ref().filter(|_| expanded);
+            let cells_area = if detail.is_some() {
+                Rect {
+                    height: row.height,
+                    ..row_area
+                }
+            } else {
+                row_area
+            };
+            self.render_cells(&row.cells, cells_area, buf, columns_widths);
+            if let Some(detail) = detail {
+                let detail_area = Rect::new(
+                    row_area.x + selection_width,
+                    area.y + y_offset + row.top_margin + row.height,
+                    row_area.width.saturating_sub(selection_width),
+                    row.detail_height(expanded),
+                )
+                .intersection(area);
+                detail.render(detail_area, buf);
             }
+            state.truncated_cells.extend(
+                cell_columns(&row.cells, columns_widths)
+                    .enumerate()
+                    .filter(|(_, (cell, _, width))| {
+                        *width > 0 && cell.is_truncated(*width, cells_area.height)
+                    })
+                    .map(|(column, _)| (i, column)),
+            );
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
             }
-            y_offset += row.height_with_margin();
+            y_offset += row.height_with_margin(expanded);
+        }
+    }
+
+    /// Returns the style of a row, which is the stripe style for rows that don't have their own
+    fn row_style(&self, index: usize, row: &Row) -> Style {
+        match self.alternating_row_styles {
+            Some((even, odd)) if row.style == Style::default() => {
+                if index % 2 == 0 {
+                    even
+                } else {
+                    odd
+                }
+            }
+            _ => row.style,
+        }
+    }
+
+    /// Renders the cells of a row in their columns, taking column spans into account
+    fn render_cells(
+        &self,
+        cells: &[Cell],
+        area: Rect,
+        buf: &mut Buffer,
+        columns_widths: &[(u16, u16)],
+    ) {
+        for (&(x, width), style) in columns_widths.iter().zip(&self.column_styles) {
+            buf.set_style(Rect::new(area.x + x, area.y, width, area.height), *style);
+        }
+        for (cell, x, width) in cell_columns(cells, columns_widths) {
+            cell.render(Rect::new(area.x + x, area.y, width, area.height), buf);
         }
     }
 
+    /// Returns the rows with the content of their cells wrapped to the width of their columns
+    ///
+    /// The height of each row grows to fit its tallest cell.
+    fn wrapped_rows(&self, columns_widths: &[(u16, u16)]) -> Vec<Row<'_>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let cells = cell_columns(&row.cells, columns_widths)
+                    .map(|(cell, _, width)| cell.wrapped(width))
+                    .collect_vec();
+                let height = cells.iter().map(Cell::height).max().unwrap_or_default();
+                Row {
+                    cells,
+                    height: row.height.max(height as u16),
+                    ..row.clone()
+                }
+            })
+            .collect()
+    }
+
     /// Get all offsets and widths of all user specified columns.
     ///
     /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
     /// and a default of equal widths is returned.
     fn get_columns_widths(&self, max_width: u16, selection_width: u16) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
-            let col_count = self
-                .rows
-                .iter()
-                .chain(self.header.iter())
-                .chain(self.footer.iter())
-                .map(|r| r.cells.len())
-                .max()
-                .unwrap_or(0);
+        self.layout_columns(
+            self.column_constraints(max_width),
+            max_width,
+            selection_width,
+            false,
+        )
+    }
+
+    /// Get all offsets and widths of the columns when scrolled horizontally by `offset_x` columns.
+    ///
+    /// The frozen columns are laid out first, followed by the scrollable columns that remain after
+    /// skipping `offset_x` of them. Skipped columns are returned with a width of 0 so that the
+    /// cells of each row still line up with their columns.
+    fn get_scrolled_columns_widths(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        offset_x: usize,
+    ) -> Vec<(u16, u16)> {
+        let skipped = offset_x.min(self.max_offset_x());
+        if skipped == 0 {
+            return self.get_columns_widths(max_width, selection_width);
+        }
+        let widths = self.column_constraints(max_width);
+        let frozen = self.frozen_columns.min(widths.len());
+        let visible_widths = widths[..frozen]
+            .iter()
+            .chain(&widths[frozen + skipped..])
+            .copied()
+            .collect_vec();
+        let mut columns = self.layout_columns(visible_widths, max_width, selection_width, true);
+        for (x, width) in &mut columns {
+            *x = (*x).min(max_width);
+            *width = (*width).min(max_width - *x);
+        }
+        let hidden_x = columns.get(frozen).map_or(max_width, |&(x, _)| x);
+        columns.splice(
+            frozen..frozen,
+            std::iter::repeat((hidden_x, 0)).take(skipped),
+        );
+        columns
+    }
+
+    /// Returns the width constraint of each column
+    fn column_constraints(&self, max_width: u16) -> Vec<Constraint> {
+        if self.auto_widths {
+            self.content_widths()
+                .into_iter()
+                .map(Constraint::Length)
+                .collect()
+        } else if self.widths.is_empty() {
+            let col_count = self.column_count();
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
-        };
+        }
+    }
+
+    /// Lays out the columns with the given constraints after the selection column
+    ///
+    /// When `keep_widths` is set, the columns are not squeezed to fit in `max_width`: they keep
+    /// their minimum width and the columns past `max_width` are to be clipped by the caller.
+    ///
+    /// Returns (x, width) of each column.
+    fn layout_columns(
+        &self,
+        mut widths: Vec<Constraint>,
+        max_width: u16,
+        selection_width: u16,
+        keep_widths: bool,
+    ) -> Vec<(u16, u16)> {
         // this will always allocate a selection area
-        let [_selection_area, columns_area] =
+        let [_selection_area, mut columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
                 .areas(Rect::new(0, 0, max_width, 1));
-        let rects = Layout::horizontal(widths)
+        if keep_widths {
+            let spacing = self
+                .column_spacing
+                .saturating_mul((widths.len() as u16).saturating_sub(1));
+            let min_width = widths
+                .iter()
+                .map(|constraint| match constraint {
+                    Constraint::Length(width) | Constraint::Min(width) => *width,
+                    _ => 0,
+                })
+                .fold(spacing, u16::saturating_add);
+            columns_area.width = columns_area
+                .width
+                .max(min_width.min(u16::MAX - columns_area.x));
+        }
+        if self.auto_widths && !keep_widths {
+            self.shrink_to_fit(&mut widths, columns_area.width);
+        }
+        Layout::horizontal(widths)
             .flex(self.flex)
             .spacing(self.column_spacing)
-            .split(columns_area);
-        rects.iter().map(|c| (c.x, c.width)).collect()
+            .split(columns_area)
+            .iter()
+            .map(|c| (c.x, c.width))
+            .collect()
     }
 
-    fn get_row_bounds(
-        &self,
-        selected: Option<usize>,
-        offset: usize,
-        max_height: u16,
-    ) -> (usize, usize) {
-        let offset = offset.min(self.rows.len().saturating_sub(1));
-        let mut start = offset;
-        let mut end = offset;
-        let mut height = 0;
-        for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
-                break;
+    /// Returns the width of the widest cell of each column, including the header and footer
+    ///
+    /// Cells that span several columns are ignored.
+    fn content_widths(&self) -> Vec<u16> {
+        let mut widths = vec![0; self.column_count()];
+        for row in self.rows.iter().chain(&self.header).chain(&self.footer) {
+            let mut column = 0;
+            for cell in &row.cells {
+                if let (1, Some(width)) = (cell.column_count(), widths.get_mut(column)) {
+                    *width = (*width).max(cell.width() as u16);
+                }
+                column += cell.column_count();
             }
-            height += item.height_with_margin();
-            end += 1;
         }
+        widths
+    }
 
-        let Some(selected) = selected else {
-            return (start, end);
-        };
-
-        // clamp the selected row to the last row
-        let selected = selected.min(self.rows.len() - 1);
-
-        // scroll down until the selected row is visible
-        while selected >= end {
-            height = height.saturating_add(self.rows[end].height_with_margin());
-            end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.rows[start].height_with_margin());
-                start += 1;
+    /// Shrinks the `Length` widths proportionally so that the columns fit in `available_width`
+    fn shrink_to_fit(&self, widths: &mut [Constraint], available_width: u16) {
+        let spacing = self
+            .column_spacing
+            .saturating_mul((widths.len() as u16).saturating_sub(1));
+        let available_width = u32::from(available_width.saturating_sub(spacing));
+        let lengths = widths
+            .iter()
+            .map(|c| match c {
+                Constraint::Length(length) => u32::from(*length),
+                _ => 0,
+            })
+            .collect_vec();
+        let total_width = lengths.iter().sum::<u32>();
+        if total_width <= available_width {
+            return;
+        }
+        let mut remaining = available_width;
+        for (width, length) in widths.iter_mut().zip(&lengths) {
+            let shrunk = length * available_width / total_width;
+            remaining -= shrunk;
+            *width = Constraint::Length(shrunk as u16);
+        }
+        // give the width lost to rounding to the columns that lost the most
+        let by_rounding_loss = (0..widths.len())
+            .sorted_by_key(|&i| std::cmp::Reverse(lengths[i] * available_width % total_width));
+        for i in by_rounding_loss.take(remaining as usize) {
+            if let Constraint::Length(length) = &mut widths[i] {
+                *length += 1;
             }
         }
+    }
 
-        // scroll up until the selected row is visible
-        while selected < start {
-            start -= 1;
-            height = height.saturating_add(self.rows[start].height_with_margin());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.rows[end].height_with_margin());
-            }
+    /// Returns the number of columns, taking column spans into account when no widths are set
+    fn column_count(&self) -> usize {
+        if !self.widths.is_empty() {
+            return self.widths.len();
         }
-        (start, end)
+        self.rows
+            .iter()
+            .chain(self.header.iter())
+            .chain(self.footer.iter())
+            .map(|r| r.cells.iter().map(Cell::column_count).sum::<usize>())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the largest horizontal offset that still leaves one scrollable column visible
+    fn max_offset_x(&self) -> usize {
+        self.column_count()
+            .saturating_sub(self.frozen_columns)
+            .saturating_sub(1)
     }
 
     /// Returns the width of the selection column if a row is selected, or the `highlight_spacing`
@@ -818,6 +1315,73 @@ impl Table<'_> {
     }
 }
 
+fn get_row_bounds(
+    rows: &[Row],
+    expanded_rows: &BTreeSet<usize>,
+    selected: Option<usize>,
+    offset: usize,
+    max_height: u16,
+) -> (usize, usize) {
+    let height_with_margin = |i: usize| rows[i].height_with_margin(expanded_rows.contains(&i));
+    let offset = offset.min(rows.len().saturating_sub(1));
+    let mut start = offset;
+    let mut end = offset;
+    let mut height = 0;
+    for (i, item) in rows.iter().enumerate().skip(offset) {
+        let expanded = expanded_rows.contains(&i);
+        if height + item.height + item.detail_height(expanded) > max_height {
+            break;
+        }
+        height += item.height_with_margin(expanded);
+        end += 1;
+    }
+
+    let Some(selected) = selected else {
+        return (start, end);
+    };
+
+    // clamp the selected row to the last row
+    let selected = selected.min(rows.len() - 1);
+
+    // scroll down until the selected row is visible
+    while selected >= end {
+        height = height.saturating_add(height_with_margin(end));
+        end += 1;
+        while height > max_height {
+            height = height.saturating_sub(height_with_margin(start));
+            start += 1;
+        }
+    }
+
+    // scroll up until the selected row is visible
+    while selected < start {
+        start -= 1;
+        height = height.saturating_add(height_with_margin(start));
+        while height > max_height {
+            end -= 1;
+            height = height.saturating_sub(height_with_margin(end));
+        }
+    }
+    (start, end)
+}
+
+/// Pairs each cell with the offset and width of the columns it spans
+///
+/// Cells that don't fit in the columns are skipped.
+fn cell_columns<'c, 'a>(
+    cells: &'c [Cell<'a>],
+    columns_widths: &'c [(u16, u16)],
+) -> impl Iterator<Item = (&'c Cell<'a>, u16, u16)> {
+    let mut columns = columns_widths;
+    cells.iter().map_while(move |cell| {
+        let &(x, _) = columns.first()?;
+        let span = cell.column_count().min(columns.len());
+        let (last_x, last_width) = columns[span - 1];
+        columns = &columns[span..];
+        Some((cell, x, last_x + last_width - x))
+    })
+}
+
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     for w in widths {
         if let Constraint::Percentage(p) = w {
@@ -1039,6 +1603,53 @@ mod tests {
             assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 15, 3)));
         }
 
+        #[rstest]
+        #[case::fits(15, ["Name  Qty      ", "Apple 3        ", "Kiwi  12       "])]
+        #[case::shrunk(7, ["Name Qt", "Appl 3 ", "Kiwi 12"])]
+        fn render_auto_widths(#[case] width: u16, #[case] expected: [&str; 3]) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 3));
+            let table = Table::default()
+                .header(Row::new(["Name", "Qty"]))
+                .rows([Row::new(["Apple", "3"]), Row::new(["Kiwi", "12"])])
+                .auto_widths(true);
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn render_sort_indicator() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 2));
+            let table = Table::new([Row::new(["Kiwi", "12"])], [Constraint::Length(6); 2])
+                .header(Row::new(["Name", "Qty"]).bold())
+                .sort_indicator(1, SortDirection::Ascending);
+            Widget::render(table, buf.area, &mut buf);
+            let mut expected = Buffer::with_lines(["Name   Qty  ▲", "Kiwi   12    "]);
+            expected.set_style(Rect::new(0, 0, 13, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_sort_indicator_symbols() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(5); 2])
+                .header(Row::new(["Name", "Qty"]))
+                .sort_indicator(0, SortDirection::Descending)
+                .sort_indicator_symbols("↑", "↓");
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Name↓ Qty   "]));
+        }
+
+        #[test]
+        fn render_sort_indicator_out_of_range() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(5); 2])
+                .header(Row::new(["Name", "Qty"]))
+                .sort_indicator(2, SortDirection::Ascending)
+                .sort_indicator_symbols("↑", "↓");
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Name  Qty   "]));
+        }
+
         #[test]
         fn render_with_block() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1077,6 +1688,227 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_colspan() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let header = Row::new([Cell::new("Name").colspan(2).on_blue(), Cell::new("Age")]);
+            let rows = vec![Row::new(vec!["Ann", "Lee", "42"])];
+            let table = Table::new(rows, [Constraint::Length(4); 3]).header(header);
+            Widget::render(table, Rect::new(0, 0, 15, 2), &mut buf);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Name      Age  ",
+                "Ann  Lee  42   ",
+            ]);
+            // the spanned cell covers both columns and the spacing between them
+            expected.set_style(Rect::new(0, 0, 9, 1), Style::new().on_blue());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_colspan_exceeding_columns() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![
+                Row::new([
+                    Cell::new("A"),
+                    Cell::new("Wide").colspan(5).on_blue(),
+                    Cell::new("C"),
+                ]),
+                Row::new(vec!["1", "2", "3"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(4); 3]);
+            Widget::render(table, Rect::new(0, 0, 15, 2), &mut buf);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "A    Wide      ",
+                "1    2    3    ",
+            ]);
+            expected.set_style(Rect::new(5, 0, 9, 1), Style::new().on_blue());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let header = Row::new(vec!["Head1", "Head2"]).italic();
+            let rows = vec![
+                Row::new(vec![Cell::new("Cell1"), Cell::new("Cell2").red()]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(header)
+                .column_styles([Style::new(), Style::new().blue()])
+                .highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected(Some(1));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Head1 Head2    ",
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+            ]);
+            expected.set_style(Rect::new(0, 0, 15, 1), Style::new().italic());
+            expected.set_style(Rect::new(6, 0, 5, 3), Style::new().blue());
+            // the cell style is applied over the column style
+            expected.set_style(Rect::new(6, 1, 5, 1), Style::new().red());
+            // and the highlight style over both
+            expected.set_style(Rect::new(0, 2, 15, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        #[case::not_scrolled(0, ["Nam A   B   C  ", "Row a1  b1  c1 "])]
+        #[case::scrolled_one_column(1, ["Name B    C    ", "Row1 b1   c1   "])]
+        #[case::scrolled_to_last_column(2, ["Name C         ", "Row1 c1        "])]
+        #[case::scrolled_past_last_column(5, ["Name C         ", "Row1 c1        "])]
+        fn render_with_frozen_columns(#[case] offset_x: usize, #[case] expected: [&str; 2]) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let header = Row::new(vec!["Name", "A", "B", "C"]);
+            let rows = vec![Row::new(vec!["Row1", "a1", "b1", "c1"])];
+            let table = Table::new(rows, [Constraint::Length(4); 4])
+                .header(header)
+                .frozen_columns(1);
+            let mut state = TableState::new().with_offset_x(offset_x);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(expected));
+            assert_eq!(state.offset_x(), offset_x.min(2));
+        }
+
+        #[test]
+        fn render_scrolled_right_without_frozen_columns() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 2));
+            let header = Row::new(vec!["A", "B", "C", "D", "E", "F"]);
+            let rows = vec![Row::new(vec!["a1", "b1", "c1", "d1", "e1", "f1"])];
+            let table = Table::new(rows, [Constraint::Length(3); 6])
+                .header(header)
+                .highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected(Some(0));
+            state.horizontal_offset(2);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            // columns A and B are skipped and the last visible column is clipped
+            let mut expected = Buffer::with_lines(["C   D   E   F", "c1  d1  e1  f"]);
+            expected.set_style(Rect::new(0, 1, 13, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_frozen_columns_and_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![
+                Row::new(vec!["Row1", "a1", "b1", "c1"]),
+                Row::new(vec!["Row2", "a2", "b2", "c2"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(4); 4])
+                .frozen_columns(1)
+                .highlight_symbol(">>")
+                .highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected(Some(1)).with_offset_x(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["  Row1 b1   c1 ", ">>Row2 b2   c2 "]);
+            expected.set_style(Rect::new(0, 1, 15, 1), Style::new().bold());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_alternating_row_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 6));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]).height(2),
+                Row::new(vec!["Cell5", "Cell6"]).red(),
+                Row::new(vec!["Cell7", "Cell8"]),
+                Row::new(vec!["Cell9", "Cell0"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .alternating_row_styles(Style::new().on_black(), Style::new().on_blue())
+                .highlight_style(Style::new().on_yellow());
+            let mut state = TableState::new().with_selected(Some(4));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 6), &mut buf, &mut state);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+                "               ",
+                "Cell5 Cell6    ",
+                "Cell7 Cell8    ",
+                "Cell9 Cell0    ",
+            ]);
+            expected.set_style(Rect::new(0, 0, 15, 1), Style::new().on_black());
+            // both lines of the tall row share the same stripe
+            expected.set_style(Rect::new(0, 1, 15, 2), Style::new().on_blue());
+            // the row style wins over the stripe
+            expected.set_style(Rect::new(0, 3, 15, 1), Style::new().red());
+            expected.set_style(Rect::new(0, 4, 15, 1), Style::new().on_blue());
+            // the highlight style is applied over the stripe
+            expected.set_style(Rect::new(0, 5, 15, 1), Style::new().on_yellow());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_reports_truncated_cells() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![
+                Row::new(vec!["Name", "Short"]),
+                Row::new(vec!["Description", "Long value"]),
+                Row::new(vec![Cell::from("Two\nlines"), Cell::from("x")]),
+                Row::new(vec!["Hidden", "Too wide"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(6), Constraint::Length(8)]);
+            let mut state = TableState::new();
+            StatefulWidget::render(&table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            // the last row isn't rendered, so it isn't reported
+            assert_eq!(state.truncated_cells(), &[(1, 0), (1, 1), (2, 0)]);
+
+            // rendering again replaces the previous truncated cells
+            let table = table.widths([Constraint::Length(11), Constraint::Length(3)]);
+            StatefulWidget::render(&table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            assert_eq!(state.truncated_cells(), &[(0, 1), (1, 1), (2, 0)]);
+        }
+
+        #[test]
+        fn render_with_wrapped_cells() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 5));
+            let rows = vec![
+                Row::new(vec!["Name", "a cell that wraps"]),
+                Row::new(vec!["Next", "short"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5), Constraint::Length(7)])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .wrap_cells(true);
+            Widget::render(table, Rect::new(0, 0, 15, 5), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Head1 Head2    ",
+                "Name  a cell   ",
+                "      that     ",
+                "      wraps    ",
+                "Next  short    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_wrapped_cells_scrolls_to_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+            let rows = vec![
+                Row::new(vec!["1", "one two three"]),
+                Row::new(vec!["2", "four"]),
+                Row::new(vec!["3", "five six"]),
+            ];
+            let table =
+                Table::new(rows, [Constraint::Length(1), Constraint::Length(5)]).wrap_cells(true);
+            let mut state = TableState::new().with_selected(Some(2));
+            StatefulWidget::render(table, Rect::new(0, 0, 12, 3), &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "2 four      ",
+                "3 five      ",
+                "  six       ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset(), 1);
+        }
+
         #[test]
         fn render_with_footer() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1096,6 +1928,25 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_footer_pinned_while_scrolling() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = (1..=5).map(|i| Row::new(vec![format!("Row{i}"), format!("{i}")]));
+            let footer = Row::new(vec!["Total", "15"]).top_margin(1);
+            let table = Table::new(rows, [Constraint::Length(5); 2]).footer(footer);
+            let mut state = TableState::new().with_selected(Some(3));
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 4), &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Row3  3        ",
+                "Row4  4        ",
+                "               ",
+                "Total 15       ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset(), 2);
+        }
+
         #[test]
         fn render_with_header_and_footer() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1240,6 +2091,68 @@ mod tests {
             assert_eq!(buf, Buffer::with_lines(expected_items));
             assert_eq!(state.offset, expected_offset);
         }
+
+        #[test]
+        fn render_empty_text_between_header_and_footer() {
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(5); 2])
+                .header(Row::new(["Name", "Size"]))
+                .footer(Row::new(["Total", "0"]))
+                .empty_text("No files".red());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 5));
+            Widget::render(table, buf.area, &mut buf);
+            let mut expected = Buffer::with_lines([
+                "Name  Size ",
+                "           ",
+                " No files  ",
+                "           ",
+                "Total 0    ",
+            ]);
+            expected.set_style(Rect::new(1, 2, 8, 1), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_empty_text_is_ignored_with_rows() {
+            let table = Table::new([Row::new(["a", "b"])], [Constraint::Length(5); 2])
+                .empty_text("No files");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["a     b    ", "           "]));
+        }
+
+        #[test]
+        fn render_expanded_row_detail() {
+            let rows = [
+                Row::new(["a", "b", "c"]).detail(Some("detail of row 0")),
+                Row::new(["d", "e", "f"]).detail(Some("detail of row 1")),
+                Row::new(["g", "h", "i"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(4); 3])
+                .expanded_rows(std::collections::HashSet::from([1]))
+                .highlight_symbol(">>");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 16, 4));
+            let mut state = TableState::new().with_selected(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "  a    b    c   ",
+                ">>d    e    f   ",
+                "  detail of row ",
+                "  g    h    i   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_expanded_row_scrolls_to_selection() {
+            let rows = (0..4).map(|i| Row::new([i.to_string()]).detail(Some("x\ny")));
+            let table = Table::new(rows, [Constraint::Length(1)]).expanded_rows([0, 3]);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+            let mut state = TableState::new().with_selected(3);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["2", "3", "x", "y"]));
+            assert_eq!(state.offset, 2);
+        }
     }
 
     // test how constraints interact with table column width allocation
@@ -1403,6 +2316,63 @@ mod tests {
             );
         }
 
+        #[test]
+        fn auto_widths_size_columns_to_content() {
+            let table = Table::default()
+                .header(Row::new(["Name", "Qty"]))
+                .rows([Row::new(["Apple", "3"]), Row::new(["Kiwi", "12"])])
+                .footer(Row::new(["", "Total"]))
+                .widths([Constraint::Length(1); 2])
+                .auto_widths(true);
+            assert_eq!(table.get_columns_widths(20, 0), [(0, 5), (6, 5)]);
+            assert_eq!(table.get_columns_widths(20, 3), [(3, 5), (9, 5)]);
+        }
+
+        #[test]
+        fn auto_widths_ignore_spanning_cells() {
+            let table = Table::default()
+                .rows([
+                    Row::new(["a", "bb"]),
+                    Row::new([Cell::new("a very long cell").colspan(2)]),
+                ])
+                .auto_widths(true);
+            assert_eq!(table.get_columns_widths(20, 0), [(0, 1), (2, 2)]);
+        }
+
+        #[test]
+        fn auto_widths_with_huge_column_spacing() {
+            let table = Table::default()
+                .rows([Row::new(["a", "b", "c"])])
+                .column_spacing(u16::MAX)
+                .auto_widths(true);
+            assert_eq!(table.get_columns_widths(10, 0), [(0, 0), (10, 0), (10, 0)]);
+        }
+
+        #[test]
+        fn auto_widths_shrink_proportionally() {
+            let table = Table::default()
+                .rows([Row::new(["aaaaaaaaaa", "bbbbb", "ccccc"])])
+                .auto_widths(true);
+            // 10 columns are available for the 20 columns of content after the spacing
+            assert_eq!(table.get_columns_widths(12, 0), [(0, 5), (6, 3), (10, 2)]);
+        }
+
+        #[test]
+        fn frozen_columns_with_offset_x() {
+            let table = Table::default()
+                .widths([Constraint::Length(4); 4])
+                .frozen_columns(1);
+            assert_eq!(
+                table.get_scrolled_columns_widths(20, 0, 0),
+                [(0, 4), (5, 4), (10, 4), (15, 4)]
+            );
+            // skipped columns have no width and are placed at the first scrolled column
+            assert_eq!(
+                table.get_scrolled_columns_widths(20, 0, 2),
+                [(0, 4), (5, 0), (5, 0), (5, 4)]
+            );
+        }
+
         #[test]
         fn no_constraint_with_rows() {
             let table = Table::default()
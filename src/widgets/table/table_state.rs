@@ -1,3 +1,5 @@
+use std::hash::{Hash, Hasher};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -5,11 +7,13 @@
 /// shifted to ensure that the selected row is visible. This will modify the [`TableState`] object
 /// passed to the [`Frame::render_stateful_widget`] method.
 ///
-/// The state consists of two fields:
+/// The state consists of three fields:
 /// - [`offset`]: the index of the first row to be displayed
+/// - [`offset_x`]: the number of scrollable columns skipped when scrolled horizontally
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 ///
 /// [`offset`]: TableState::offset()
+/// [`offset_x`]: TableState::offset_x()
 /// [`selected`]: TableState::selected()
 ///
 /// See the `table`` example and the `recipe`` and `traceroute`` tabs in the demo2 example in the
@@ -44,11 +48,34 @@
 /// [`Table`]: crate::widgets::Table
 /// [`Table::widths`]: crate::widgets::Table::widths
 /// [`Frame::render_stateful_widget`]: crate::Frame::render_stateful_widget
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) offset_x: usize,
     pub(crate) selected: Option<usize>,
+    /// Output of the last render, which is not part of the identity of the state
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) truncated_cells: Vec<(usize, usize)>,
+}
+
+impl PartialEq for TableState {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.offset_x == other.offset_x
+            && self.selected == other.selected
+    }
+}
+
+impl Eq for TableState {}
+
+impl Hash for TableState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.offset_x.hash(state);
+        self.selected.hash(state);
+    }
 }
 
 impl TableState {
@@ -63,7 +90,9 @@ impl TableState {
     pub const fn new() -> Self {
         Self {
             offset: 0,
+            offset_x: 0,
             selected: None,
+            truncated_cells: Vec::new(),
         }
     }
 
@@ -83,6 +112,22 @@ impl TableState {
         self
     }
 
+    /// Sets the number of scrollable columns to skip when rendering
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_offset_x(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset_x(mut self, offset_x: usize) -> Self {
+        self.offset_x = offset_x;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -128,6 +173,112 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Number of scrollable columns skipped when rendering
+    ///
+    /// Columns frozen with [`Table::frozen_columns`] are never skipped. The offset is clamped
+    /// during rendering so that at least one scrollable column stays visible.
+    ///
+    /// [`Table::frozen_columns`]: crate::widgets::Table::frozen_columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.offset_x(), 0);
+    /// ```
+    pub const fn offset_x(&self) -> usize {
+        self.offset_x
+    }
+
+    /// Mutable reference to the number of scrollable columns skipped when rendering
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// *state.offset_x_mut() = 1;
+    /// ```
+    pub fn offset_x_mut(&mut self) -> &mut usize {
+        &mut self.offset_x
+    }
+
+    /// Scrolls the table horizontally so that `offset_x` scrollable columns are skipped
+    ///
+    /// This is independent of the vertical [offset](TableState::offset). Columns frozen with
+    /// [`Table::frozen_columns`] are never skipped, and the offset is clamped during rendering so
+    /// that at least one scrollable column stays visible.
+    ///
+    /// [`Table::frozen_columns`]: crate::widgets::Table::frozen_columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.horizontal_offset(2);
+    /// assert_eq!(state.offset_x(), 2);
+    /// ```
+    pub fn horizontal_offset(&mut self, offset_x: usize) {
+        self.offset_x = offset_x;
+    }
+
+    /// Scrolls the table one column to the right
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.scroll_right();
+    /// assert_eq!(state.offset_x(), 1);
+    /// ```
+    pub fn scroll_right(&mut self) {
+        self.offset_x = self.offset_x.saturating_add(1);
+    }
+
+    /// Scrolls the table one column to the left
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default().with_offset_x(1);
+    /// state.scroll_left();
+    /// assert_eq!(state.offset_x(), 0);
+    /// ```
+    pub fn scroll_left(&mut self) {
+        self.offset_x = self.offset_x.saturating_sub(1);
+    }
+
+    /// The `(row, column)` indices of the cells whose content was clipped during the last render
+    ///
+    /// A cell is clipped when its content is wider than its column or taller than its row. Only
+    /// the rows and columns that were visible are checked, and `column` is the index of the cell in
+    /// its [`Row`]. This can be used to show the full content of a cell in a tooltip.
+    ///
+    /// The cells are listed row by row, in the order they were rendered. They are not taken into
+    /// account when comparing or hashing states.
+    ///
+    /// [`Row`]: crate::widgets::Row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let area = Rect::new(0, 0, 10, 2);
+    /// # let mut buf = Buffer::empty(area);
+    /// let rows = [Row::new(["short", "a long cell"])];
+    /// let table = Table::new(rows, [Constraint::Length(5), Constraint::Length(4)]);
+    /// let mut state = TableState::default();
+    /// StatefulWidget::render(&table, area, &mut buf, &mut state);
+    /// assert_eq!(state.truncated_cells(), &[(0, 1)]);
+    /// ```
+    pub fn truncated_cells(&self) -> &[(usize, usize)] {
+        &self.truncated_cells
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -194,6 +345,12 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn with_offset_x() {
+        let state = TableState::new().with_offset_x(2);
+        assert_eq!(state.offset_x, 2);
+    }
+
     #[test]
     fn with_selected() {
         let state = TableState::new().with_selected(Some(1));
@@ -213,6 +370,41 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn offset_x_mut() {
+        let mut state = TableState::new();
+        *state.offset_x_mut() = 1;
+        assert_eq!(state.offset_x(), 1);
+    }
+
+    #[test]
+    fn truncated_cells_are_not_compared() {
+        let mut state = TableState::new().with_selected(Some(1));
+        state.truncated_cells.push((0, 1));
+        assert_eq!(state, TableState::new().with_selected(Some(1)));
+    }
+
+    #[test]
+    fn horizontal_offset() {
+        let mut state = TableState::new().with_offset(3);
+        state.horizontal_offset(2);
+        assert_eq!(state.offset_x, 2);
+        assert_eq!(state.offset, 3);
+    }
+
+    #[test]
+    fn scroll_right_and_left() {
+        let mut state = TableState::new();
+        state.scroll_right();
+        state.scroll_right();
+        assert_eq!(state.offset_x, 2);
+        state.scroll_left();
+        assert_eq!(state.offset_x, 1);
+        state.scroll_left();
+        state.scroll_left();
+        assert_eq!(state.offset_x, 0);
+    }
+
     #[test]
     fn selected() {
         let state = TableState::new();
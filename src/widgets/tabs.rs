@@ -1,13 +1,38 @@
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthStr;
+
 use crate::{prelude::*, style::Styled, widgets::Block};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
 
+/// Drawn on the left of the tabs when some tabs are hidden on that side
+const OVERFLOW_LEFT: &str = "‹";
+/// Drawn on the right of the tabs when some tabs are hidden on that side
+const OVERFLOW_RIGHT: &str = "›";
+/// Drawn after the title of each tab when the tabs are closeable
+const DEFAULT_CLOSE_SYMBOL: &str = "✕";
+
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
 /// Each tab title is stored as a [`Line`] which can be individually styled. The selected tab is set
 /// using [`Tabs::select`] and styled using [`Tabs::highlight_style`]. The divider can be customized
 /// with [`Tabs::divider`]. Padding can be set with [`Tabs::padding`] or [`Tabs::padding_left`] and
-/// [`Tabs::padding_right`].
+/// [`Tabs::padding_right`]. The tabs can be stacked vertically, e.g. for a sidebar, with
+/// [`Tabs::direction`].
+///
+/// When the tabs are wider than the area, they are scrolled so that the selected tab is visible,
+/// and `‹` / `›` indicators are drawn on the sides where tabs are hidden. The indicators can be
+/// styled with [`Tabs::overflow_style`].
+///
+/// The selected tab can be underlined with a `━` bar on the row below the titles with
+/// [`Tabs::underline_selected`].
+///
+/// Tabs can show a close symbol after their title with [`Tabs::closeable`]. The position of the
+/// symbols can be retrieved with [`Tabs::tab_close_areas`] to handle mouse clicks on them.
+///
+/// Titles with an icon can be created with [`Tab`], which places a space between the icon and the
+/// title so that icons of different widths are spaced consistently.
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
@@ -33,7 +58,7 @@ const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVER
 ///
 /// (0..5).map(|i| format!("Tab{i}")).collect::<Tabs>();
 /// ```
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Tabs<'a> {
     /// A block to wrap this widget in if necessary
     block: Option<Block<'a>>,
@@ -51,6 +76,39 @@ pub struct Tabs<'a> {
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// Whether the tabs are laid out in a row or in a column
+    direction: Direction,
+    /// Style of the indicators shown when some tabs are hidden
+    overflow_style: Style,
+    /// Whether a close symbol is drawn after each title
+    closeable: bool,
+    /// The symbol drawn after each title when the tabs are closeable
+    close_symbol: &'a str,
+    /// Whether the selected tab is underlined on the row below the titles
+    underline_selected: bool,
+    /// Style of the underline of the selected tab
+    underline_style: Style,
+}
+
+impl Default for Tabs<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            titles: Vec::new(),
+            selected: 0,
+            style: Style::default(),
+            highlight_style: Style::default(),
+            divider: Span::default(),
+            padding_left: Line::default(),
+            padding_right: Line::default(),
+            direction: Direction::Horizontal,
+            overflow_style: Style::default(),
+            closeable: false,
+            close_symbol: DEFAULT_CLOSE_SYMBOL,
+            underline_selected: false,
+            underline_style: Style::default(),
+        }
+    }
 }
 
 impl<'a> Tabs<'a> {
@@ -98,6 +156,12 @@ impl<'a> Tabs<'a> {
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            direction: Direction::Horizontal,
+            overflow_style: Style::default(),
+            closeable: false,
+            close_symbol: DEFAULT_CLOSE_SYMBOL,
+            underline_selected: false,
+            underline_style: Style::default(),
         }
     }
 
@@ -118,6 +182,26 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Sets whether the tabs are laid out in a row or in a column.
+    ///
+    /// The default is [`Direction::Horizontal`], which draws the tabs on a single row separated by
+    /// the [divider](Tabs::divider). With [`Direction::Vertical`], each tab is drawn on its own row
+    /// with its padding, the divider is omitted and the highlight style spans the whole row. Titles
+    /// wider than the area are truncated, so the area should be as wide as the longest title and
+    /// its padding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let sidebar = Tabs::new(vec!["Home", "Settings", "About"]).direction(Direction::Vertical);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the style of the tabs.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -144,6 +228,137 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Sets the style of the indicators shown when some tabs are hidden.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// When the tabs don't fit in the area, they are scrolled to show the selected tab and a `‹`
+    /// or `›` indicator is drawn on each side where some tabs are hidden.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overflow_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.overflow_style = style.into();
+        self
+    }
+
+    /// Underlines the selected tab with a `━` bar on the row below the titles.
+    ///
+    /// The underline spans the width of the selected title and is styled with
+    /// [`Tabs::underline_style`]. The tabs reserve the row below the titles for it, so the area
+    /// should be at least two rows tall. When the area is a single row, only the
+    /// [highlight style](Tabs::highlight_style) marks the selected tab. The underline is only drawn
+    /// for [horizontal](Direction::Horizontal) tabs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"])
+    ///     .highlight_style(Style::new())
+    ///     .underline_selected(true)
+    ///     .underline_style(Style::new().cyan());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn underline_selected(mut self, underline_selected: bool) -> Self {
+        self.underline_selected = underline_selected;
+        self
+    }
+
+    /// Sets the style of the underline drawn below the selected tab.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The underline is only drawn when [`Tabs::underline_selected`] is enabled.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn underline_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.underline_style = style.into();
+        self
+    }
+
+    /// Shows a close symbol after the title of each tab.
+    ///
+    /// The symbol is separated from the title by a space and defaults to `✕`. It can be changed
+    /// with [`Tabs::close_symbol`]. The widget doesn't handle mouse input itself, use
+    /// [`Tabs::tab_close_areas`] to know where the symbols are drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["main.rs", "lib.rs"]).closeable(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn closeable(mut self, closeable: bool) -> Self {
+        self.closeable = closeable;
+        self
+    }
+
+    /// Sets the symbol drawn after each title when the tabs are [closeable](Tabs::closeable).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["main.rs", "lib.rs"])
+    ///     .closeable(true)
+    ///     .close_symbol("x");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn close_symbol(mut self, symbol: &'a str) -> Self {
+        self.close_symbol = symbol;
+        self
+    }
+
+    /// Returns the areas where the close symbol of each tab is drawn when rendered in `area`.
+    ///
+    /// The returned vector contains one [`Rect`] per tab, in the same order as the titles, so the
+    /// index of the rect containing a mouse click is the index of the tab to close. The rect is
+    /// empty when the tabs are not [closeable](Tabs::closeable) or when the close symbol of the
+    /// tab is not visible (e.g. it is scrolled out of view or truncated).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["main.rs", "lib.rs"]).closeable(true);
+    /// let area = Rect::new(0, 0, 30, 1);
+    /// let (column, row) = (9, 0);
+    /// let clicked = tabs
+    ///     .tab_close_areas(area)
+    ///     .iter()
+    ///     .position(|rect| rect.contains(Position::new(column, row)));
+    /// assert_eq!(clicked, Some(0));
+    /// ```
+    pub fn tab_close_areas(&self, area: Rect) -> Vec<Rect> {
+        let mut areas = vec![Rect::default(); self.titles.len()];
+        let tabs_area = self.block.inner_if_some(area);
+        if !self.closeable || tabs_area.is_empty() {
+            return areas;
+        }
+        let symbol_width = self.close_symbol.width() as u16;
+        match self.direction {
+            Direction::Horizontal => {
+                let (titles_area, visible) = self.horizontal_layout(tabs_area);
+                let mut x = titles_area.x;
+                for i in visible {
+                    let symbol_x = x.saturating_add(self.close_offset(i));
+                    let symbol = Rect::new(symbol_x, titles_area.y, symbol_width, 1);
+                    areas[i] = Self::visible_close_area(symbol, titles_area);
+                    x = x.saturating_add((self.tab_width(i) + self.divider.width()) as u16);
+                }
+            }
+            Direction::Vertical => {
+                for (i, row) in tabs_area.rows().take(self.titles.len()).enumerate() {
+                    let symbol_x = row.x.saturating_add(self.close_offset(i));
+                    let symbol = Rect::new(symbol_x, row.y, symbol_width, 1);
+                    areas[i] = Self::visible_close_area(symbol, row);
+                }
+            }
+        }
+        areas
+    }
+
     /// Sets the string to use as tab divider.
     ///
     /// By default, the divider is a pipe (`|`).
@@ -269,11 +484,170 @@ impl Tabs<'_> {
         if tabs_area.is_empty() {
             return;
         }
+        match self.direction {
+            Direction::Horizontal => self.render_horizontal_tabs(tabs_area, buf),
+            Direction::Vertical => self.render_vertical_tabs(tabs_area, buf),
+        }
+    }
+
+    fn render_vertical_tabs(&self, tabs_area: Rect, buf: &mut Buffer) {
+        for (i, (title, row)) in self.titles.iter().zip(tabs_area.rows()).enumerate() {
+            let (x, _) = buf.set_line(row.x, row.y, &self.padding_left, row.width);
+            let (x, _) = buf.set_line(x, row.y, title, row.right().saturating_sub(x));
+            let x = self.render_close_symbol(x, row, buf);
+            buf.set_line(x, row.y, &self.padding_right, row.right().saturating_sub(x));
+            if i == self.selected {
+                buf.set_style(row, self.highlight_style);
+            }
+        }
+    }
+
+    fn render_horizontal_tabs(&self, tabs_area: Rect, buf: &mut Buffer) {
+        let (titles_area, visible) = self.horizontal_layout(tabs_area);
+        if titles_area != tabs_area {
+            if visible.start > 0 {
+                buf.set_stringn(
+                    tabs_area.x,
+                    tabs_area.y,
+                    OVERFLOW_LEFT,
+                    1,
+                    self.overflow_style,
+                );
+            }
+            if visible.end < self.titles.len() {
+                let x = tabs_area.right() - 1;
+                buf.set_stringn(x, tabs_area.y, OVERFLOW_RIGHT, 1, self.overflow_style);
+            }
+        }
+        let selected = self.render_titles(titles_area, buf, visible);
+        if let Some(title) = selected {
+            self.render_underline(title, tabs_area, buf);
+        }
+    }
+
+    /// Draws the underline below the area of the selected title if there is room for it
+    fn render_underline(&self, title: Rect, tabs_area: Rect, buf: &mut Buffer) {
+        if !self.underline_selected || tabs_area.height < 2 {
+            return;
+        }
+        let underline = symbols::line::THICK_HORIZONTAL.repeat(usize::from(title.width));
+        buf.set_stringn(
+            title.x,
+            title.y + 1,
+            underline,
+            usize::from(title.width),
+            self.underline_style,
+        );
+    }
+
+    /// Returns the area of the titles and the range of tabs shown in it
+    ///
+    /// When not all the tabs fit, one column is kept on each side for the overflow indicators and
+    /// the tabs are scrolled to the selected one.
+    fn horizontal_layout(&self, tabs_area: Rect) -> (Rect, Range<usize>) {
+        // there is no room for the indicators in very narrow areas, so the tabs are just truncated
+        if tabs_area.width < 3 || self.titles_width() <= usize::from(tabs_area.width) {
+            return (tabs_area, 0..self.titles.len());
+        }
+        let [_, titles_area, _] = Layout::horizontal([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(tabs_area);
+        (titles_area, self.visible_titles(titles_area.width))
+    }
+
+    /// Returns the offset of the close symbol from the start of a tab
+    fn close_offset(&self, index: usize) -> u16 {
+        (self.padding_left.width() + self.titles[index].width() + 1) as u16
+    }
+
+    /// Returns the close symbol area if it is fully inside `area`, or an empty rect otherwise
+    fn visible_close_area(symbol: Rect, area: Rect) -> Rect {
+        if symbol.right() <= area.right() {
+            symbol
+        } else {
+            Rect::default()
+        }
+    }
+
+    /// Renders the close symbol of a tab if the tabs are closeable and returns the next x position
+    fn render_close_symbol(&self, x: u16, area: Rect, buf: &mut Buffer) -> u16 {
+        if !self.closeable {
+            return x;
+        }
+        let width = area.right().saturating_sub(x);
+        let (x, _) = buf.set_stringn(x, area.y, " ", width.into(), Style::default());
+        let width = area.right().saturating_sub(x);
+        let (x, _) = buf.set_stringn(x, area.y, self.close_symbol, width.into(), Style::default());
+        x
+    }
+
+    /// Returns the width of a tab including its padding and close symbol
+    fn tab_width(&self, index: usize) -> usize {
+        let close_width = if self.closeable {
+            1 + self.close_symbol.width()
+        } else {
+            0
+        };
+        self.padding_left.width()
+            + self.titles[index].width()
+            + close_width
+            + self.padding_right.width()
+    }
 
+    /// Returns the width of all the tabs and the dividers between them
+    fn titles_width(&self) -> usize {
+        let dividers = self.titles.len().saturating_sub(1) * self.divider.width();
+        (0..self.titles.len())
+            .map(|i| self.tab_width(i))
+            .sum::<usize>()
+            + dividers
+    }
+
+    /// Returns the range of tabs to show in the given width so that the selected tab is visible
+    ///
+    /// Tabs after the selected one are added first, then the ones before it.
+    fn visible_titles(&self, width: u16) -> Range<usize> {
+        let width = usize::from(width);
+        let divider = self.divider.width();
+        let selected = self.selected.min(self.titles.len().saturating_sub(1));
+        let mut visible = selected..selected + 1;
+        let mut used = self.tab_width(selected);
+        while visible.end < self.titles.len()
+            && used + divider + self.tab_width(visible.end) <= width
+        {
+            used += divider + self.tab_width(visible.end);
+            visible.end += 1;
+        }
+        while visible.start > 0 && used + divider + self.tab_width(visible.start - 1) <= width {
+            used += divider + self.tab_width(visible.start - 1);
+            visible.start -= 1;
+        }
+        visible
+    }
+
+    /// Renders the given range of tabs from the left of the area
+    ///
+    /// Returns the area of the selected title if it is visible.
+    fn render_titles(
+        &self,
+        tabs_area: Rect,
+        buf: &mut Buffer,
+        range: Range<usize>,
+    ) -> Option<Rect> {
+        let mut selected = None;
         let mut x = tabs_area.left();
-        let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
-            let last_title = titles_length - 1 == i;
+        let last_index = range.end.saturating_sub(1);
+        for (i, title) in self
+            .titles
+            .iter()
+            .enumerate()
+            .take(range.end)
+            .skip(range.start)
+        {
+            let last_title = last_index == i;
             let remaining_width = tabs_area.right().saturating_sub(x);
 
             if remaining_width == 0 {
@@ -291,17 +665,16 @@ impl Tabs<'_> {
             // Title
             let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
             if i == self.selected {
-                buf.set_style(
-                    Rect {
-                        x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
-                );
+                let title_area = Rect {
+                    x,
+                    y: tabs_area.top(),
+                    width: pos.0.saturating_sub(x),
+                    height: 1,
+                };
+                buf.set_style(title_area, self.highlight_style);
+                selected = Some(title_area);
             }
-            x = pos.0;
+            x = self.render_close_symbol(pos.0, tabs_area, buf);
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 {
                 break;
@@ -318,6 +691,66 @@ impl Tabs<'_> {
             let pos = buf.set_span(x, tabs_area.top(), &self.divider, remaining_width);
             x = pos.0;
         }
+        selected
+    }
+}
+
+/// A tab title with an optional icon, for use with [`Tabs`]
+///
+/// The icon is drawn before the title and separated from it by a single space. A `Tab` converts
+/// into a [`Line`], so the icon is part of the tab when computing widths, placing dividers and
+/// highlighting the selected tab.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// Tabs::new([
+///     Tab::new("Files").icon("📁"),
+///     Tab::new("Search").icon("🔍".yellow()),
+///     Tab::new("Settings"),
+/// ]);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Tab<'a> {
+    /// The icon drawn before the title
+    icon: Option<Span<'a>>,
+    /// The title of the tab
+    title: Line<'a>,
+}
+
+impl<'a> Tab<'a> {
+    /// Creates a new `Tab` without an icon
+    pub fn new<T>(title: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            icon: None,
+            title: title.into(),
+        }
+    }
+
+    /// Sets the icon drawn before the title
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn icon<T>(mut self, icon: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+impl<'a> From<Tab<'a>> for Line<'a> {
+    fn from(tab: Tab<'a>) -> Self {
+        let Some(icon) = tab.icon else {
+            return tab.title;
+        };
+        let mut spans = vec![icon, Span::raw(" ")];
+        spans.extend(tab.title.spans);
+        Line { spans, ..tab.title }
     }
 }
 
@@ -332,6 +765,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -354,6 +789,12 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                direction: Direction::Horizontal,
+                overflow_style: Style::default(),
+                closeable: false,
+                close_symbol: DEFAULT_CLOSE_SYMBOL,
+                underline_selected: false,
+                underline_style: Style::default(),
             }
         );
     }
@@ -394,6 +835,76 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn tab_into_line() {
+        assert_eq!(Line::from(Tab::new("Files")), Line::from("Files"));
+        assert_eq!(
+            Line::from(Tab::new("Files".red()).icon("📁")),
+            Line::from(vec!["📁".into(), " ".into(), "Files".red()]),
+        );
+        assert_eq!(
+            Line::from(Tab::new(Line::from("Files").centered()).icon("📁")),
+            Line::from(vec!["📁".into(), " ".into(), "Files".into()]).centered(),
+        );
+    }
+
+    #[test]
+    fn render_tab_icons() {
+        let tabs = Tabs::new([
+            Tab::new("Crab").icon("🦀"),
+            Tab::new("Git").icon("\u{e0a0}"),
+            Tab::new("None"),
+        ])
+        .select(0);
+        let mut expected = Buffer::with_lines([" 🦀 Crab │ \u{e0a0} Git │ None       "]);
+        // the highlight covers the icon
+        expected.set_style(Rect::new(1, 0, 7, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone(), Rect::new(0, 0, 30, 1), &expected);
+
+        let mut expected = Buffer::with_lines([" 🦀 Crab │ \u{e0a0} Git │ None       "]);
+        expected.set_style(Rect::new(11, 0, 5, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.select(1), Rect::new(0, 0, 30, 1), &expected);
+    }
+
+    #[test]
+    fn render_tab_icons_overflow() {
+        let tabs = Tabs::new([
+            Tab::new("Crab").icon("🦀"),
+            Tab::new("Git").icon("\u{e0a0}"),
+            Tab::new("None"),
+        ])
+        .select(2);
+        // the icons are included in the width used to scroll the tabs, so the first tab is hidden
+        // and no divider is drawn before the first visible tab
+        let mut expected = Buffer::with_lines(["‹ \u{e0a0} Git │ None  "]);
+        expected.set_style(Rect::new(10, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 16, 1), &expected);
+    }
+
+    #[rstest]
+    #[case::first(0, Rect::new(1, 1, 3, 1))]
+    #[case::second(1, Rect::new(7, 1, 6, 1))]
+    fn render_underline_selected(#[case] selected: usize, #[case] underline: Rect) {
+        let tabs = Tabs::new(vec!["One", "Second", "Third"])
+            .highlight_style(Style::new())
+            .underline_selected(true)
+            .underline_style(Style::new().cyan())
+            .select(selected);
+        let mut expected = Buffer::with_lines([" One │ Second │ Third    ", ""]);
+        for x in underline.left()..underline.right() {
+            expected.get_mut(x, 1).set_symbol("━").set_fg(Color::Cyan);
+        }
+        test_case(tabs, Rect::new(0, 0, 25, 2), &expected);
+    }
+
+    #[test]
+    fn render_underline_selected_single_row() {
+        let tabs = Tabs::new(vec!["One", "Two"]).underline_selected(true);
+        let mut expected = Buffer::with_lines([" One │ Two  "]);
+        expected.set_style(Rect::new(1, 0, 3, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 12, 1), &expected);
+    }
+
     #[test]
     fn render_no_padding() {
         let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).padding("", "");
@@ -406,12 +917,128 @@ mod tests {
     #[test]
     fn render_more_padding() {
         let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).padding("---", "++");
-        let mut expected = Buffer::with_lines(["---Tab1++│---Tab2++│---Tab3++│"]);
+        // the tabs overflow, so only the ones that fit are shown
+        let mut expected = Buffer::with_lines([" ---Tab1++│---Tab2++         ›"]);
         // first tab selected
-        expected.set_style(Rect::new(3, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        expected.set_style(Rect::new(4, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[rstest]
+    #[case::first(0, "  Tab0 │ Tab1 │ Tab2   ›", 2)]
+    #[case::middle(5, "‹ Tab5 │ Tab6 │ Tab7   ›", 2)]
+    #[case::last(9, "‹ Tab7 │ Tab8 │ Tab9    ", 16)]
+    fn render_overflow_scrolls_to_selected(
+        #[case] selected: usize,
+        #[case] line: &str,
+        #[case] highlight_x: u16,
+    ) {
+        let titles = (0..10).map(|i| format!("Tab{i}")).collect::<Vec<_>>();
+        let tabs = Tabs::new(titles).select(selected);
+        let mut expected = Buffer::with_lines([line]);
+        expected.set_style(Rect::new(highlight_x, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 24, 1), &expected);
+    }
+
+    #[test]
+    fn render_overflow_style() {
+        let titles = (0..10).map(|i| format!("Tab{i}")).collect::<Vec<_>>();
+        let tabs = Tabs::new(titles).select(5).overflow_style(Color::Red);
+        let mut expected = Buffer::with_lines(["‹ Tab5 │ Tab6 │ Tab7   ›"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        expected.set_style(Rect::new(0, 0, 1, 1), Color::Red);
+        expected.set_style(Rect::new(23, 0, 1, 1), Color::Red);
+        test_case(tabs, Rect::new(0, 0, 24, 1), &expected);
+    }
+
+    #[test]
+    fn render_vertical() {
+        let tabs = Tabs::new(vec!["Home", "Settings", "About"])
+            .direction(Direction::Vertical)
+            .select(1);
+        let mut expected =
+            Buffer::with_lines([" Home      ", " Settings  ", " About     ", "           "]);
+        expected.set_style(Rect::new(0, 1, 11, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 11, 4), &expected);
+    }
+
+    #[test]
+    fn render_vertical_truncates_to_area() {
+        let tabs = Tabs::new(vec!["Home", "Settings", "About"])
+            .direction(Direction::Vertical)
+            .select(2);
+        let expected = Buffer::with_lines([" Home ", " Setti"]);
+        test_case(tabs, Rect::new(0, 0, 6, 2), &expected);
+    }
+
+    #[test]
+    fn render_closeable() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).closeable(true);
+        let mut expected = Buffer::with_lines([" Tab1 ✕ │ Tab2 ✕      "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 22, 1), &expected);
+    }
+
+    #[test]
+    fn render_close_symbol() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .closeable(true)
+            .close_symbol("x");
+        let mut expected = Buffer::with_lines([" Tab1 x │ Tab2 x      "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 22, 1), &expected);
+    }
+
+    #[rstest]
+    #[case::horizontal(Direction::Horizontal, None, Rect::new(0, 0, 30, 1))]
+    #[case::vertical(Direction::Vertical, None, Rect::new(0, 0, 12, 3))]
+    #[case::block(Direction::Horizontal, Some(Block::bordered()), Rect::new(0, 0, 30, 3))]
+    #[case::overflow(Direction::Horizontal, None, Rect::new(0, 0, 18, 1))]
+    fn tab_close_areas_match_rendered_symbols(
+        #[case] direction: Direction,
+        #[case] block: Option<Block>,
+        #[case] area: Rect,
+    ) {
+        let mut tabs = Tabs::new(vec!["Tab1", "Tab22", "Tab3"])
+            .closeable(true)
+            .direction(direction)
+            .select(1);
+        tabs.block = block;
+        let mut buf = Buffer::empty(area);
+        tabs.render_ref(area, &mut buf);
+        let close_areas = tabs.tab_close_areas(area);
+        assert_eq!(close_areas.len(), 3);
+        let close_symbols: Vec<Position> = area
+            .positions()
+            .filter(|position| buf.get(position.x, position.y).symbol() == DEFAULT_CLOSE_SYMBOL)
+            .collect();
+        let visible_areas: Vec<Position> = close_areas
+            .iter()
+            .filter(|rect| !rect.is_empty())
+            .map(|rect| rect.as_position())
+            .collect();
+        assert!(!visible_areas.is_empty());
+        assert_eq!(visible_areas, close_symbols);
+    }
+
+    #[test]
+    fn tab_close_areas_hidden_tabs_are_empty() {
+        let titles = (0..10).map(|i| format!("Tab{i}")).collect::<Vec<_>>();
+        let tabs = Tabs::new(titles).closeable(true).select(5);
+        let areas = tabs.tab_close_areas(Rect::new(0, 0, 24, 1));
+        assert_eq!(areas[4], Rect::default());
+        assert_eq!(areas[5], Rect::new(7, 0, 1, 1));
+        assert_eq!(areas[6], Rect::new(16, 0, 1, 1));
+        assert_eq!(areas[7], Rect::default());
+    }
+
+    #[test]
+    fn tab_close_areas_not_closeable() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]);
+        let areas = tabs.tab_close_areas(Rect::new(0, 0, 30, 1));
+        assert_eq!(areas, vec![Rect::default(); 2]);
+    }
+
     #[test]
     fn render_with_block() {
         let tabs =
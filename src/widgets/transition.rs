@@ -0,0 +1,197 @@
+//! Helpers to animate the transition between two rendered [`Buffer`]s.
+//!
+//! Each helper takes the buffer of the screen being left (`from`), the buffer of the screen being
+//! entered (`to`) and the `progress` of the transition between `0.0` (only `from` is visible) and
+//! `1.0` (only `to` is visible), and returns the composed frame. Apps render both screens to
+//! buffers, call a helper with an increasing progress on each frame and draw the result.
+//!
+//! The available transitions are:
+//!
+//! - [`wipe`]: `to` is revealed from one edge of the area, moving in a [`WipeDirection`]
+//! - [`fade`]: the colors of `from` are blended into the colors of `to`
+//!
+//! The result covers the area of `to`. Cells of `from` that are outside of that area are ignored
+//! and cells of `to` that are outside of the area of `from` are treated as if `from` was empty
+//! there.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{
+//!     prelude::*,
+//!     widgets::transition::{self, WipeDirection},
+//! };
+//!
+//! let from = Buffer::with_lines(["aaaa"]);
+//! let to = Buffer::with_lines(["bbbb"]);
+//! let frame = transition::wipe(&from, &to, 0.5, WipeDirection::LeftToRight);
+//! assert_eq!(frame, Buffer::with_lines(["bbaa"]));
+//! ```
+use strum::{Display, EnumString};
+
+use crate::{buffer::Cell, prelude::*};
+
+/// The direction in which the new buffer is revealed by a [`wipe`] transition
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WipeDirection {
+    /// Reveal from the left edge to the right edge
+    #[default]
+    LeftToRight,
+    /// Reveal from the right edge to the left edge
+    RightToLeft,
+    /// Reveal from the top edge to the bottom edge
+    TopToBottom,
+    /// Reveal from the bottom edge to the top edge
+    BottomToTop,
+}
+
+/// Reveals `to` over `from` from one edge of the area
+///
+/// At a given `progress`, the cells of `to` are shown in the leading part of the area (e.g. the
+/// left half at `0.5` when wiping [`LeftToRight`](WipeDirection::LeftToRight)) and the cells of
+/// `from` in the rest. `progress` is clamped between `0.0` and `1.0`.
+pub fn wipe(from: &Buffer, to: &Buffer, progress: f64, direction: WipeDirection) -> Buffer {
+    let area = to.area;
+    let progress = progress.clamp(0.0, 1.0);
+    let revealed = |length: u16| (f64::from(length) * progress).round() as u16;
+    let mut buf = to.clone();
+    for position in area.positions() {
+        let is_revealed = match direction {
+            WipeDirection::LeftToRight => position.x - area.left() < revealed(area.width),
+            WipeDirection::RightToLeft => area.right() - position.x <= revealed(area.width),
+            WipeDirection::TopToBottom => position.y - area.top() < revealed(area.height),
+            WipeDirection::BottomToTop => area.bottom() - position.y <= revealed(area.height),
+        };
+        if !is_revealed {
+            *buf.get_mut(position.x, position.y) = cell_at(from, position);
+        }
+    }
+    buf
+}
+
+/// Blends the colors of `from` into the colors of `to`
+///
+/// The foreground and background colors of each cell are interpolated when they are both
+/// [`Color::Rgb`] colors in `from` and `to`. Other colors, the symbols and the modifiers switch
+/// from `from` to `to` halfway through the transition. `progress` is clamped between `0.0` and
+/// `1.0`.
+pub fn fade(from: &Buffer, to: &Buffer, progress: f64) -> Buffer {
+    let area = to.area;
+    let progress = progress.clamp(0.0, 1.0);
+    let mut buf = to.clone();
+    for position in area.positions() {
+        let from_cell = cell_at(from, position);
+        let cell = buf.get_mut(position.x, position.y);
+        let (fg, bg) = (cell.fg, cell.bg);
+        if progress < 0.5 {
+            *cell = from_cell.clone();
+        }
+        cell.fg = blend(from_cell.fg, fg, progress);
+        cell.bg = blend(from_cell.bg, bg, progress);
+    }
+    buf
+}
+
+/// The cell of `buf` at the given position, or an empty cell if it is outside of the buffer
+fn cell_at(buf: &Buffer, position: Position) -> Cell {
+    if buf.area.contains(position) {
+        buf.get(position.x, position.y).clone()
+    } else {
+        Cell::EMPTY
+    }
+}
+
+/// Interpolates between two colors, switching halfway when they are not both rgb colors
+fn blend(from: Color, to: Color, progress: f64) -> Color {
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => Color::Rgb(
+            blend_channel(r1, r2, progress),
+            blend_channel(g1, g2, progress),
+            blend_channel(b1, b2, progress),
+        ),
+        _ if progress < 0.5 => from,
+        _ => to,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn blend_channel(from: u8, to: u8, progress: f64) -> u8 {
+    (f64::from(from) + (f64::from(to) - f64::from(from)) * progress).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::left_to_right(WipeDirection::LeftToRight, ["TTTTfrom", "TTTTfrom"])]
+    #[case::right_to_left(WipeDirection::RightToLeft, ["fromTTTT", "fromTTTT"])]
+    fn wipe_half_horizontal(#[case] direction: WipeDirection, #[case] expected: [&str; 2]) {
+        let from = Buffer::with_lines(["fromfrom", "fromfrom"]);
+        let to = Buffer::with_lines(["TTTTTTTT", "TTTTTTTT"]);
+        let buf = wipe(&from, &to, 0.5, direction);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::top_to_bottom(WipeDirection::TopToBottom, ["to", "to", "fr", "fr"])]
+    #[case::bottom_to_top(WipeDirection::BottomToTop, ["fr", "fr", "to", "to"])]
+    fn wipe_half_vertical(#[case] direction: WipeDirection, #[case] expected: [&str; 4]) {
+        let from = Buffer::with_lines(["fr"; 4]);
+        let to = Buffer::with_lines(["to"; 4]);
+        let buf = wipe(&from, &to, 0.5, direction);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[rstest]
+    #[case::start(0.0, "from")]
+    #[case::before_start(-1.0, "from")]
+    #[case::end(1.0, "TTTT")]
+    #[case::after_end(2.0, "TTTT")]
+    fn wipe_clamps_progress(#[case] progress: f64, #[case] expected: &str) {
+        let from = Buffer::with_lines(["from"]);
+        let to = Buffer::with_lines(["TTTT"]);
+        let buf = wipe(&from, &to, progress, WipeDirection::LeftToRight);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn wipe_smaller_from_buffer() {
+        let from = Buffer::with_lines(["ab"]);
+        let to = Buffer::with_lines(["TTTT"]);
+        let buf = wipe(&from, &to, 0.25, WipeDirection::LeftToRight);
+        assert_eq!(buf, Buffer::with_lines(["Tb  "]));
+    }
+
+    #[test]
+    fn fade_blends_rgb_colors() {
+        let mut from = Buffer::with_lines(["ab"]);
+        from.set_style(
+            from.area,
+            Style::new().fg(Color::Rgb(0, 0, 0)).bg(Color::Red),
+        );
+        let mut to = Buffer::with_lines(["cd"]);
+        to.set_style(
+            to.area,
+            Style::new().fg(Color::Rgb(200, 100, 50)).bg(Color::Blue),
+        );
+
+        let buf = fade(&from, &to, 0.25);
+        let mut expected = Buffer::with_lines(["ab"]);
+        expected.set_style(
+            expected.area,
+            Style::new().fg(Color::Rgb(50, 25, 13)).bg(Color::Red),
+        );
+        assert_eq!(buf, expected);
+
+        let buf = fade(&from, &to, 0.75);
+        let mut expected = Buffer::with_lines(["cd"]);
+        expected.set_style(
+            expected.area,
+            Style::new().fg(Color::Rgb(150, 75, 38)).bg(Color::Blue),
+        );
+        assert_eq!(buf, expected);
+    }
+}
@@ -94,10 +94,12 @@ const DEFAULT_STATE_BUFFER: [&str; 5] = [
 const DEFAULT_STATE_REPR: &str = r#"{
   "list": {
     "offset": 0,
-    "selected": null
+    "selected": null,
+    "item_horizontal_offset": 0
   },
   "table": {
     "offset": 0,
+    "offset_x": 0,
     "selected": null
   },
   "scrollbar": {
@@ -131,10 +133,12 @@ const SELECTED_STATE_BUFFER: [&str; 5] = [
 const SELECTED_STATE_REPR: &str = r#"{
   "list": {
     "offset": 0,
-    "selected": 1
+    "selected": 1,
+    "item_horizontal_offset": 0
   },
   "table": {
     "offset": 0,
+    "offset_x": 0,
     "selected": 1
   },
   "scrollbar": {
@@ -170,10 +174,12 @@ const SCROLLED_STATE_BUFFER: [&str; 5] = [
 const SCROLLED_STATE_REPR: &str = r#"{
   "list": {
     "offset": 4,
-    "selected": 8
+    "selected": 8,
+    "item_horizontal_offset": 0
   },
   "table": {
     "offset": 4,
+    "offset_x": 0,
     "selected": 8
   },
   "scrollbar": {
@@ -197,3 +203,26 @@ fn scrolled_state_deserialize() {
     let mut state: AppState = serde_json::from_str(SCROLLED_STATE_REPR).unwrap();
     assert_buffer(&mut state, SCROLLED_STATE_BUFFER);
 }
+
+/// State serialized before the horizontal offsets were added to the list and table states
+const LEGACY_SELECTED_STATE_REPR: &str = r#"{
+  "list": {
+    "offset": 0,
+    "selected": 1
+  },
+  "table": {
+    "offset": 0,
+    "selected": 1
+  },
+  "scrollbar": {
+    "content_length": 10,
+    "position": 1,
+    "viewport_content_length": 0
+  }
+}"#;
+
+#[test]
+fn legacy_state_deserialize() {
+    let mut state: AppState = serde_json::from_str(LEGACY_SELECTED_STATE_REPR).unwrap();
+    assert_buffer(&mut state, SELECTED_STATE_BUFFER);
+}
@@ -2,6 +2,7 @@ use std::error::Error;
 
 use ratatui::{
     backend::{Backend, TestBackend},
+    buffer::Cell,
     layout::Rect,
     widgets::{Paragraph, Widget},
     Terminal, TerminalOptions, Viewport,
@@ -28,6 +29,29 @@ fn swap_buffer_clears_prev_buffer() {
     assert_eq!(terminal.current_buffer_mut().content()[0].symbol(), " ");
 }
 
+#[test]
+fn terminal_draw_after_clear_writes_all_cells() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 2);
+    let mut terminal = Terminal::new(backend)?;
+    let draw = |f: &mut ratatui::Frame| f.render_widget(Paragraph::new("Hello"), f.size());
+    terminal.draw(draw)?;
+    terminal.clear()?;
+
+    // simulate output from another program corrupting the screen after the clear
+    let junk = Cell::new("x");
+    let corrupted = [(0, 0, &junk), (5, 0, &junk), (3, 1, &junk)];
+    terminal.backend_mut().draw(corrupted.into_iter())?;
+
+    terminal.draw(draw)?;
+    terminal.backend().assert_buffer_lines(["Hello ", "      "]);
+
+    // only the difference is written once the screen has been redrawn
+    terminal.backend_mut().draw(corrupted.into_iter())?;
+    terminal.draw(draw)?;
+    terminal.backend().assert_buffer_lines(["xellox", "   x  "]);
+    Ok(())
+}
+
 #[test]
 fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     let backend = TestBackend::new(10, 10);
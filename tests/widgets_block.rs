@@ -55,27 +55,27 @@ fn widgets_block_titles_overlap() {
         terminal.backend().assert_buffer_lines(expected);
     }
 
-    // Left overrides the center
+    // Left and right titles take precedence over the center
     test_case(
         Block::new()
             .title(Title::from("aaaaa").alignment(Alignment::Left))
             .title(Title::from("bbb").alignment(Alignment::Center))
             .title(Title::from("ccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 10, 1),
-        ["aaaaab ccc"],
+        ["aaaaa  ccc"],
     );
 
-    // Left alignment overrides the center alignment which overrides the right alignment
+    // The center is truncated to fit between the left and the right
     test_case(
         Block::new()
             .title(Title::from("aaaaa").alignment(Alignment::Left))
             .title(Title::from("bbbbb").alignment(Alignment::Center))
             .title(Title::from("ccccc").alignment(Alignment::Right)),
-        Rect::new(0, 0, 11, 1),
-        ["aaaaabbbccc"],
+        Rect::new(0, 0, 15, 1),
+        ["aaaaa bbb ccccc"],
     );
 
-    // Multiple left alignment overrides the center alignment and the right alignment
+    // Multiple left alignment take precedence over the center alignment and the right alignment
     test_case(
         Block::new()
             .title(Title::from("aaaaa").alignment(Alignment::Left))
@@ -83,16 +83,16 @@ fn widgets_block_titles_overlap() {
             .title(Title::from("bbbbb").alignment(Alignment::Center))
             .title(Title::from("ccccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 11, 1),
-        ["aaaaabaaaaa"],
+        ["aaaaa aaaaa"],
     );
 
-    // The right alignment doesn't override the center alignment, but pierces through it
+    // The right alignment takes precedence over the center alignment
     test_case(
         Block::new()
             .title(Title::from("bbbbb").alignment(Alignment::Center))
             .title(Title::from("ccccccccccc").alignment(Alignment::Right)),
         Rect::new(0, 0, 11, 1),
-        ["cccbbbbbccc"],
+        ["ccccccccccc"],
     );
 }
 
@@ -3,7 +3,6 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Style, Stylize},
-    symbols,
     widgets::Tabs,
     Terminal,
 };
@@ -30,7 +29,7 @@ fn widgets_tabs_should_not_panic_on_narrow_areas() {
 }
 
 #[test]
-fn widgets_tabs_should_truncate_the_last_item() {
+fn widgets_tabs_should_indicate_the_hidden_items() {
     let backend = TestBackend::new(10, 1);
     let mut terminal = Terminal::new(backend).unwrap();
     terminal
@@ -47,7 +46,7 @@ fn widgets_tabs_should_truncate_the_last_item() {
             );
         })
         .unwrap();
-    let mut expected = Buffer::with_lines([format!(" Tab1 {} T ", symbols::line::VERTICAL)]);
-    expected.set_style(Rect::new(1, 0, 4, 1), Style::new().reversed());
+    let mut expected = Buffer::with_lines(["  Tab1  › "]);
+    expected.set_style(Rect::new(2, 0, 4, 1), Style::new().reversed());
     terminal.backend().assert_buffer(&expected);
 }